@@ -0,0 +1,261 @@
+//! Compares peeking at the best transactions of the pool with extracting them,
+//! inserting executable transactions one by one with inserting them in bulk,
+//! and copying the dependents of a transaction from a slice with collecting them.
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    BatchSize,
+    Criterion,
+};
+use fuel_core_txpool::{
+    selection_algorithm::{
+        RatioTipGasSelection,
+        SelectionAlgorithm,
+        SelectionAlgorithmStorage,
+        StorageData,
+    },
+    Constraints,
+};
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        ConsensusParameters,
+        Finalizable,
+        TransactionBuilder,
+    },
+    fuel_vm::checked_transaction::IntoChecked,
+    services::txpool::{
+        Metadata,
+        PoolTransaction,
+    },
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::SystemTime,
+};
+
+const POOL_SIZE: usize = 10_000;
+const K: usize = 100;
+const DEPENDENTS: usize = 1_000;
+
+/// The storage without dependencies between transactions,
+/// so all of them are executable.
+#[derive(Clone, Default)]
+struct FlatStorage {
+    entries: HashMap<usize, StorageData>,
+}
+
+impl SelectionAlgorithmStorage for FlatStorage {
+    type StorageIndex = usize;
+
+    fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData> {
+        self.entries.get(index)
+    }
+
+    fn dependents_slice(&self, _: &Self::StorageIndex) -> &[Self::StorageIndex] {
+        &[]
+    }
+
+    fn get_dependencies(
+        &self,
+        _: &Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex> {
+        std::iter::empty()
+    }
+
+    fn has_dependencies(&self, _: &Self::StorageIndex) -> bool {
+        false
+    }
+
+    fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData> {
+        self.entries.remove(index)
+    }
+}
+
+/// The storage where the transaction `0` has `DEPENDENTS` direct dependents.
+struct FanOutStorage {
+    dependents: Vec<usize>,
+}
+
+impl SelectionAlgorithmStorage for FanOutStorage {
+    type StorageIndex = usize;
+
+    fn get(&self, _: &Self::StorageIndex) -> Option<&StorageData> {
+        None
+    }
+
+    fn dependents_slice(&self, index: &Self::StorageIndex) -> &[Self::StorageIndex] {
+        if *index == 0 {
+            &self.dependents
+        } else {
+            &[]
+        }
+    }
+
+    fn get_dependencies(
+        &self,
+        _: &Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex> {
+        std::iter::empty()
+    }
+
+    fn has_dependencies(&self, index: &Self::StorageIndex) -> bool {
+        *index != 0
+    }
+
+    fn remove(&mut self, _: &Self::StorageIndex) -> Option<StorageData> {
+        None
+    }
+}
+
+fn storage_data(rng: &mut StdRng, consensus_params: &ConsensusParameters) -> StorageData {
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10000)
+        .tip(rng.gen_range(1..1000))
+        .max_fee_limit(1000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1000,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize()
+        .into_checked_basic(Default::default(), consensus_params)
+        .expect("Transaction should be valid");
+    let transaction = Arc::new(PoolTransaction::Script(
+        tx,
+        Metadata::new_test(0, None, None),
+    ));
+
+    StorageData {
+        dependents_cumulative_tip: transaction.tip(),
+        dependents_cumulative_gas: transaction.max_gas(),
+        dependents_cumulative_bytes_size: transaction.metered_bytes_size(),
+        number_dependents_in_chain: 0,
+        creation_instant: SystemTime::now(),
+        replacement_count: 0,
+        transaction,
+    }
+}
+
+fn populate(storage: &FlatStorage) -> RatioTipGasSelection<FlatStorage> {
+    let mut selection = RatioTipGasSelection::new();
+    for (storage_id, store_entry) in storage.entries.iter() {
+        selection.new_executable_transaction(*storage_id, store_entry);
+    }
+    selection
+}
+
+fn constraints() -> Constraints {
+    Constraints::new(u64::MAX).with_max_txs(K as u16)
+}
+
+fn top_k_vs_gather_best_txs(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let consensus_params = ConsensusParameters::standard();
+    let storage = FlatStorage {
+        entries: (0..POOL_SIZE)
+            .map(|storage_id| (storage_id, storage_data(&mut rng, &consensus_params)))
+            .collect(),
+    };
+
+    let mut group = c.benchmark_group("txpool_selection");
+
+    let selection = populate(&storage);
+    let constraints = constraints();
+    group.bench_function(format!("top_k({K}) of {POOL_SIZE}"), |b| {
+        b.iter(|| selection.top_k(K, &constraints, &storage))
+    });
+
+    group.bench_function(format!("gather_best_txs({K}) of {POOL_SIZE}"), |b| {
+        b.iter_batched(
+            || (populate(&storage), storage.clone()),
+            |(mut selection, mut storage)| {
+                selection.gather_best_txs(constraints(), &mut storage)
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn single_vs_bulk_insert(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let consensus_params = ConsensusParameters::standard();
+    let storage = FlatStorage {
+        entries: (0..POOL_SIZE)
+            .map(|storage_id| (storage_id, storage_data(&mut rng, &consensus_params)))
+            .collect(),
+    };
+    let ids: Vec<_> = storage.entries.keys().copied().collect();
+
+    let mut group = c.benchmark_group("txpool_selection");
+
+    group.bench_function(format!("new_executable_transaction x {POOL_SIZE}"), |b| {
+        b.iter_batched(
+            RatioTipGasSelection::<FlatStorage>::new,
+            |mut selection| {
+                for storage_id in ids.iter() {
+                    let store_entry = storage.get(storage_id).expect("Entry exists");
+                    selection.new_executable_transaction(*storage_id, store_entry);
+                }
+                selection
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function(format!("new_executable_transactions({POOL_SIZE})"), |b| {
+        b.iter_batched(
+            || (RatioTipGasSelection::<FlatStorage>::new(), ids.clone()),
+            |(mut selection, ids)| {
+                selection
+                    .new_executable_transactions(ids, &storage)
+                    .expect("All entries exist");
+                selection
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn dependents_slice_vs_iterator(c: &mut Criterion) {
+    let storage = FanOutStorage {
+        dependents: (1..=DEPENDENTS).collect(),
+    };
+
+    let mut group = c.benchmark_group("txpool_selection");
+
+    group.bench_function(format!("dependents_slice of {DEPENDENTS}"), |b| {
+        b.iter(|| {
+            let mut dependents = Vec::with_capacity(DEPENDENTS);
+            dependents.extend_from_slice(storage.dependents_slice(&0));
+            dependents
+        })
+    });
+
+    group.bench_function(format!("get_dependents of {DEPENDENTS}"), |b| {
+        b.iter(|| storage.get_dependents(&0).collect::<Vec<_>>())
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    top_k_vs_gather_best_txs,
+    single_vs_bulk_insert,
+    dependents_slice_vs_iterator
+);
+criterion_main!(benches);
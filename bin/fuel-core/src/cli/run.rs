@@ -35,8 +35,10 @@ use fuel_core::{
         VMConfig,
     },
     txpool::config::{
+        AllowList,
         BlackList,
         Config as TxPoolConfig,
+        EvictionWeights,
         HeavyWorkConfig,
         PoolLimits,
         ServiceChannelLimits,
@@ -57,6 +59,7 @@ use fuel_core_metrics::config::{
 };
 use fuel_core_poa::signer::SignMode;
 use fuel_core_types::blockchain::header::StateTransitionBytecodeVersion;
+use num_rational::Ratio;
 use pyroscope::{
     pyroscope::PyroscopeAgentRunning,
     PyroscopeAgent,
@@ -461,19 +464,28 @@ impl Command {
             tx_ttl_check_interval,
             tx_max_number,
             tx_max_total_bytes,
+            tx_max_blob_tx_bytes,
+            tx_allow_priority_overrides,
+            tx_min_fee_bump_percent,
             tx_max_total_gas,
             tx_max_chain_count,
+            tx_max_dependent_depth,
             tx_number_active_subscriptions,
             tx_blacklist_addresses,
             tx_blacklist_coins,
             tx_blacklist_messages,
             tx_blacklist_contracts,
+            tx_blacklist_assets,
+            tx_allowlist_addresses,
             tx_number_threads_to_verify_transactions,
             tx_size_of_verification_queue,
             tx_number_threads_p2p_sync,
             tx_size_of_p2p_sync_queue,
             tx_max_pending_read_requests,
             tx_max_pending_write_requests,
+            tx_selection_algorithm,
+            tx_eviction_tip_per_gas_weight,
+            tx_eviction_tip_per_byte_weight,
         } = tx_pool;
 
         let black_list = BlackList::new(
@@ -481,6 +493,10 @@ impl Command {
             tx_blacklist_coins,
             tx_blacklist_messages,
             tx_blacklist_contracts,
+            tx_blacklist_assets,
+        );
+        let allow_list = AllowList::new(
+            (!tx_allowlist_addresses.is_empty()).then_some(tx_allowlist_addresses),
         );
 
         let pool_limits = PoolLimits {
@@ -517,6 +533,7 @@ impl Command {
                 request_body_bytes_limit: graphql.graphql_request_body_bytes_limit,
                 api_request_timeout: graphql.api_request_timeout.into(),
                 query_log_threshold_time: graphql.query_log_threshold_time.into(),
+                max_message_proof_age_blocks: graphql.max_message_proof_age_blocks,
                 costs: Costs {
                     balance_query: graphql.costs.balance_query,
                     coins_to_spend: graphql.costs.coins_to_spend,
@@ -554,14 +571,28 @@ impl Command {
             },
             txpool: TxPoolConfig {
                 max_txs_chain_count: tx_max_chain_count,
+                max_dependent_depth: tx_max_dependent_depth,
                 max_txs_ttl: tx_pool_ttl.into(),
                 ttl_check_interval: tx_ttl_check_interval.into(),
                 utxo_validation,
                 max_tx_update_subscriptions: tx_number_active_subscriptions,
                 black_list,
+                allow_list,
+                selection_algorithm: tx_selection_algorithm.into(),
+                eviction_weights: EvictionWeights {
+                    tip_per_gas: tx_eviction_tip_per_gas_weight,
+                    tip_per_byte: tx_eviction_tip_per_byte_weight,
+                },
                 pool_limits,
+                max_blob_tx_bytes: tx_max_blob_tx_bytes,
+                allow_priority_overrides: tx_allow_priority_overrides,
+                min_fee_bump_ratio: Ratio::new(
+                    tx_min_fee_bump_percent.saturating_add(100),
+                    100,
+                ),
                 heavy_work: pool_heavy_work_config,
                 service_channel_limits,
+                metrics: metrics.is_enabled(Module::TxPool),
             },
             block_producer: ProducerConfig {
                 coinbase_recipient,
@@ -607,6 +638,13 @@ pub async fn get_service_with_shutdown_listeners(
     // log fuel-core version
     info!("Fuel Core version v{}", env!("CARGO_PKG_VERSION"));
     trace!("Initializing in TRACE mode.");
+    // label the metrics before the databases register them
+    let chain_id = config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .chain_id();
+    fuel_core_metrics::set_chain_id(chain_id.into());
     // initialize the server
     let combined_database = CombinedDatabase::from_config(&config.combined_db_config)?;
 
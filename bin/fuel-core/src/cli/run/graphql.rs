@@ -66,6 +66,11 @@ pub struct GraphQLArgs {
     #[clap(long = "api-request-timeout", default_value = "30s", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The max number of blocks the commit block of a message proof may lag behind
+    /// the latest block. If not set, proofs against any commit block are served.
+    #[clap(long = "max-message-proof-age-blocks", env)]
+    pub max_message_proof_age_blocks: Option<u32>,
+
     #[clap(flatten)]
     pub costs: QueryCosts,
 }
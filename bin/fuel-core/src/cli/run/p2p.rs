@@ -190,6 +190,25 @@ pub struct P2PArgs {
     #[clap(long = "heartbeat-max-time-since-last", default_value = "40", env)]
     pub heartbeat_max_time_since_last: u64,
 
+    /// For peer reputations, the interval at which the reputation of all connected peers decays.
+    /// Cannot be zero.
+    #[clap(
+        long = "reputation-decay-interval",
+        default_value = "1s",
+        value_parser = parse_non_zero_duration,
+        env
+    )]
+    pub reputation_decay_interval: humantime::Duration,
+
+    /// For peer reputations, the score below which the peer is greylisted
+    #[clap(long = "greylist-app-score", default_value = "-25", env)]
+    pub greylist_app_score: f64,
+
+    /// For peer reputations, the time during which inbound connections
+    /// from a greylisted peer are refused
+    #[clap(long = "greylist-duration", default_value = "60s", env)]
+    pub greylist_duration: humantime::Duration,
+
     /// Number of threads to read from the database.
     #[clap(long = "p2p-database-read-threads", default_value = "2", env)]
     pub database_read_threads: usize,
@@ -240,6 +259,14 @@ impl KeypairArg {
     }
 }
 
+fn parse_non_zero_duration(s: &str) -> anyhow::Result<humantime::Duration> {
+    let duration = humantime::Duration::from_str(s)?;
+    if duration.is_zero() {
+        return Err(anyhow!("the duration cannot be zero"))
+    }
+    Ok(duration)
+}
+
 impl From<SyncArgs> for fuel_core::sync::Config {
     fn from(value: SyncArgs) -> Self {
         Self {
@@ -340,6 +367,9 @@ impl P2PArgs {
             heartbeat_max_time_since_last: Duration::from_secs(
                 self.heartbeat_max_time_since_last,
             ),
+            reputation_decay_interval: self.reputation_decay_interval.into(),
+            greylist_app_score: self.greylist_app_score,
+            greylist_duration: self.greylist_duration.into(),
             info_interval: Some(Duration::from_secs(self.info_interval)),
             identify_interval: Some(Duration::from_secs(self.identify_interval)),
             metrics,
@@ -368,4 +398,16 @@ mod tests {
             .to_string()
             .contains("does not exist for keypair argument"));
     }
+
+    #[test]
+    fn parse_non_zero_duration_rejects_zero() {
+        assert!(parse_non_zero_duration("0s").is_err());
+        assert!(parse_non_zero_duration("0ms").is_err());
+    }
+
+    #[test]
+    fn parse_non_zero_duration_accepts_sub_second_duration() {
+        let duration = parse_non_zero_duration("500ms").unwrap();
+        assert_eq!(Duration::from(duration), Duration::from_millis(500));
+    }
 }
@@ -1,8 +1,10 @@
 //! Clap configuration related to TxPool service.
 
+use fuel_core::txpool::config::SelectionAlgorithmKind;
 use fuel_core_types::{
     fuel_tx::{
         Address,
+        AssetId,
         ContractId,
         UtxoId,
     },
@@ -31,10 +33,30 @@ pub struct TxPoolArgs {
     #[clap(long = "tx-max-total-bytes", default_value = "131072000", env)]
     pub tx_max_total_bytes: usize,
 
+    /// The max number of bytes of a single blob transaction accepted by the `TxPool`.
+    #[clap(long = "tx-max-blob-tx-bytes", default_value = "1048576", env)]
+    pub tx_max_blob_tx_bytes: usize,
+
+    /// Allows inserting operator-designated priority transactions that bypass
+    /// the limits of the `TxPool`.
+    #[clap(long = "tx-allow-priority-overrides", env)]
+    pub tx_allow_priority_overrides: bool,
+
+    /// The minimal increase in percent of the tip/gas ratio required to replace
+    /// a transaction of the `TxPool` with its fee bumped version.
+    #[clap(long = "tx-min-fee-bump-percent", default_value = "10", env)]
+    pub tx_min_fee_bump_percent: u64,
+
     /// The max number of tx in a chain of dependent transactions that supported by the `TxPool`.
     #[clap(long = "tx-max-depth", default_value = "32", env)]
     pub tx_max_chain_count: usize,
 
+    /// The max number of hops from a transaction to the farthest executable
+    /// transaction it depends on, supported by the `TxPool`.
+    /// Executable transactions have depth 0.
+    #[clap(long = "tx-max-dependent-depth", default_value = "5", env)]
+    pub tx_max_dependent_depth: usize,
+
     /// The maximum number of active subscriptions that supported by the `TxPool`.
     #[clap(long = "tx-number-active-subscriptions", default_value = "4064", env)]
     pub tx_number_active_subscriptions: usize,
@@ -55,6 +77,15 @@ pub struct TxPoolArgs {
     #[clap(long = "tx-blacklist-contracts", value_delimiter = ',', env)]
     pub tx_blacklist_contracts: Vec<ContractId>,
 
+    /// The list of banned assets ignored by the `TxPool`.
+    #[clap(long = "tx-blacklist-assets", value_delimiter = ',', env)]
+    pub tx_blacklist_assets: Vec<AssetId>,
+
+    /// The list of addresses allowed to send transactions to the `TxPool`.
+    /// If empty, all addresses are allowed.
+    #[clap(long = "tx-allowlist-addresses", value_delimiter = ',', env)]
+    pub tx_allowlist_addresses: Vec<Address>,
+
     /// Number of threads for managing verifications/insertions.
     #[clap(
         long = "tx-number-threads-to-verify-transactions",
@@ -82,6 +113,44 @@ pub struct TxPoolArgs {
     /// Maximum number of pending read requests in the service.
     #[clap(long = "tx-max-pending-read-requests", default_value = "1000", env)]
     pub tx_max_pending_read_requests: usize,
+
+    /// The algorithm used to select transactions from the pool for the block.
+    #[clap(
+        long = "tx-selection-algorithm",
+        default_value = "ratio-tip-gas",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub tx_selection_algorithm: SelectionAlgorithm,
+
+    /// The weight of the tip/gas ratio when deciding which transactions to evict
+    /// from the full `TxPool`.
+    #[clap(long = "tx-eviction-tip-per-gas-weight", default_value = "1", env)]
+    pub tx_eviction_tip_per_gas_weight: u64,
+
+    /// The weight of the tip/byte ratio when deciding which transactions to evict
+    /// from the full `TxPool`. Increase it to favor small transactions when
+    /// the space of the `TxPool` is scarcer than gas.
+    #[clap(long = "tx-eviction-tip-per-byte-weight", default_value = "0", env)]
+    pub tx_eviction_tip_per_byte_weight: u64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum SelectionAlgorithm {
+    /// Prioritizes transactions with the highest tip/gas ratio.
+    RatioTipGas,
+    /// Prioritizes transactions that were inserted into the pool earlier.
+    Fifo,
+}
+
+impl From<SelectionAlgorithm> for SelectionAlgorithmKind {
+    fn from(value: SelectionAlgorithm) -> Self {
+        match value {
+            SelectionAlgorithm::RatioTipGas => SelectionAlgorithmKind::RatioTipGas,
+            SelectionAlgorithm::Fifo => SelectionAlgorithmKind::Fifo,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -102,31 +171,37 @@ mod tests {
         c: Vec<UtxoId>,
         m: Vec<Nonce>,
         ct: Vec<ContractId>,
+        at: Vec<AssetId>,
     ) -> BlackList {
-        BlackList::new(a, c, m, ct)
+        BlackList::new(a, c, m, ct, at)
     }
 
-    #[test_case(&[""] => Ok(blacklist(vec![], vec![], vec![], vec![])); "defaults works")]
+    #[test_case(&[""] => Ok(blacklist(vec![], vec![], vec![], vec![], vec![])); "defaults works")]
     #[test_case(&["", "--tx-blacklist-addresses=\
             0x0000000000000000000000000000000000000000000000000000000000000000,\
             0101010101010101010101010101010101010101010101010101010101010101"
         ]
-        => Ok(blacklist(vec![[0; 32].into(), [1; 32].into()], vec![], vec![], vec![])); "addresses works")]
+        => Ok(blacklist(vec![[0; 32].into(), [1; 32].into()], vec![], vec![], vec![], vec![])); "addresses works")]
     #[test_case(&["", "--tx-blacklist-coins=\
             0x00000000000000000000000000000000000000000000000000000000000000000002,\
             01010101010101010101010101010101010101010101010101010101010101010003"
     ]
-    => Ok(blacklist(vec![], vec![UtxoId::new([0; 32].into(), 2), UtxoId::new([1; 32].into(), 3)], vec![], vec![])); "coins works")]
+    => Ok(blacklist(vec![], vec![UtxoId::new([0; 32].into(), 2), UtxoId::new([1; 32].into(), 3)], vec![], vec![], vec![])); "coins works")]
     #[test_case(&["", "--tx-blacklist-messages=\
             0x0000000000000000000000000000000000000000000000000000000000000000,\
             0101010101010101010101010101010101010101010101010101010101010101"
     ]
-    => Ok(blacklist(vec![], vec![], vec![[0; 32].into(), [1; 32].into()], vec![])); "messages works")]
+    => Ok(blacklist(vec![], vec![], vec![[0; 32].into(), [1; 32].into()], vec![], vec![])); "messages works")]
     #[test_case(&["", "--tx-blacklist-contracts=\
             0x0000000000000000000000000000000000000000000000000000000000000000,\
             0101010101010101010101010101010101010101010101010101010101010101"
     ]
-    => Ok(blacklist(vec![], vec![], vec![], vec![[0; 32].into(), [1; 32].into()])); "contracts works")]
+    => Ok(blacklist(vec![], vec![], vec![], vec![[0; 32].into(), [1; 32].into()], vec![])); "contracts works")]
+    #[test_case(&["", "--tx-blacklist-assets=\
+            0x0000000000000000000000000000000000000000000000000000000000000000,\
+            0101010101010101010101010101010101010101010101010101010101010101"
+    ]
+    => Ok(blacklist(vec![], vec![], vec![], vec![], vec![[0; 32].into(), [1; 32].into()])); "assets works")]
     fn parse(args: &[&str]) -> Result<BlackList, String> {
         let command: Command =
             Command::try_parse_from(args).map_err(|e| e.to_string())?;
@@ -137,6 +212,7 @@ mod tests {
             args.tx_blacklist_coins,
             args.tx_blacklist_messages,
             args.tx_blacklist_contracts,
+            args.tx_blacklist_assets,
         );
 
         Ok(blacklist)
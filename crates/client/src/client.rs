@@ -8,7 +8,10 @@ use crate::client::{
             SpendQueryElementInput,
         },
         contract::ContractBalanceQueryArgs,
-        gas_price::EstimateGasPrice,
+        gas_price::{
+            EncodedTransactionArgs,
+            EstimateGasPrice,
+        },
         message::MessageStatusArgs,
         relayed_tx::RelayedTransactionStatusArgs,
         tx::DryRunArg,
@@ -16,7 +19,10 @@ use crate::client::{
         TransactionId,
     },
     types::{
-        gas_price::LatestGasPrice,
+        gas_price::{
+            EstimatedFee,
+            LatestGasPrice,
+        },
         message::MessageStatus,
         primitives::{
             Address,
@@ -391,6 +397,14 @@ impl FuelClient {
         self.query(query).await.map(|r| r.estimate_gas_price)
     }
 
+    /// Estimate the fee of the transaction for the next block.
+    pub async fn estimated_fee(&self, tx: &Transaction) -> io::Result<EstimatedFee> {
+        let query = schema::gas_price::QueryEstimatedFee::build(EncodedTransactionArgs {
+            encoded_transaction: HexString(Bytes(tx.to_bytes())),
+        });
+        self.query(query).await.map(|r| r.estimated_fee.into())
+    }
+
     #[cfg(feature = "std")]
     pub async fn connected_peers_info(
         &self,
@@ -1,5 +1,6 @@
 use crate::client::schema::{
     schema,
+    HexString,
     U32,
     U64,
 };
@@ -47,6 +48,30 @@ pub struct QueryEstimateGasPrice {
     pub estimate_gas_price: EstimateGasPrice,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct EstimatedFee {
+    pub min_fee: U64,
+    pub max_fee: U64,
+    pub gas_used: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct EncodedTransactionArgs {
+    pub encoded_transaction: HexString,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "EncodedTransactionArgs"
+)]
+pub struct QueryEstimatedFee {
+    #[arguments(encodedTransaction: $encoded_transaction)]
+    pub estimated_fee: EstimatedFee,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,4 +90,14 @@ mod tests {
         let operation = QueryEstimateGasPrice::build(arbitrary_horizon.into());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn estimated_fee_query_gql_output() {
+        use crate::client::schema::Bytes;
+        use cynic::QueryBuilder;
+        let operation = QueryEstimatedFee::build(EncodedTransactionArgs {
+            encoded_transaction: HexString(Bytes(vec![])),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
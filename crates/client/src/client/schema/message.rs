@@ -57,8 +57,10 @@ pub struct MessageStatus {
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub enum MessageState {
     Unspent,
+    UnspentUnfinalized,
     Spent,
     NotFound,
+    RelayPending,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -189,6 +189,7 @@ pub enum TransactionStatus {
     SuccessStatus(SuccessStatus),
     SqueezedOutStatus(SqueezedOutStatus),
     FailureStatus(FailureStatus),
+    ReplacedStatus(ReplacedStatus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -204,6 +205,7 @@ pub enum StatusWithTransaction {
     SuccessStatus(SuccessStatusWithTransaction),
     SqueezedOutStatus(SqueezedOutStatus),
     FailureStatus(FailureStatusWithTransaction),
+    ReplacedStatus(ReplacedStatus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -268,6 +270,12 @@ pub struct SqueezedOutStatus {
     pub reason: String,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ReplacedStatus {
+    pub by: TransactionId,
+}
+
 #[allow(clippy::enum_variant_names)]
 #[derive(cynic::InlineFragments, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
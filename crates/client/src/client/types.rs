@@ -52,6 +52,7 @@ use fuel_core_types::{
     fuel_tx::{
         Receipt,
         Transaction,
+        TxId,
     },
     fuel_types::{
         canonical::Deserialize,
@@ -116,6 +117,9 @@ pub enum TransactionStatus {
     SqueezedOut {
         reason: String,
     },
+    Replaced {
+        by: TxId,
+    },
     Failure {
         block_height: BlockHeight,
         time: Tai64,
@@ -163,6 +167,9 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
             SchemaTxStatus::SqueezedOutStatus(s) => {
                 TransactionStatus::SqueezedOut { reason: s.reason }
             }
+            SchemaTxStatus::ReplacedStatus(s) => {
+                TransactionStatus::Replaced { by: s.by.into() }
+            }
             SchemaTxStatus::Unknown => {
                 return Err(Self::Error::UnknownVariant("SchemaTxStatus"))
             }
@@ -187,6 +194,9 @@ pub enum StatusWithTransaction {
     SqueezedOut {
         reason: String,
     },
+    Replaced {
+        by: TxId,
+    },
     Failure {
         transaction: Transaction,
         block_height: BlockHeight,
@@ -237,6 +247,9 @@ impl TryFrom<SchemaStatusWithTx> for StatusWithTransaction {
             SchemaStatusWithTx::SqueezedOutStatus(s) => {
                 StatusWithTransaction::SqueezedOut { reason: s.reason }
             }
+            SchemaStatusWithTx::ReplacedStatus(s) => {
+                StatusWithTransaction::Replaced { by: s.by.into() }
+            }
             SchemaStatusWithTx::Unknown => {
                 return Err(Self::Error::UnknownVariant("SchemaTxStatus"))
             }
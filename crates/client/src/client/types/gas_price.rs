@@ -28,3 +28,20 @@ impl From<schema::gas_price::EstimateGasPrice> for EstimateGasPrice {
         }
     }
 }
+
+#[derive(Debug, Copy, Clone)]
+pub struct EstimatedFee {
+    pub min_fee: u64,
+    pub max_fee: u64,
+    pub gas_used: u64,
+}
+
+impl From<schema::gas_price::EstimatedFee> for EstimatedFee {
+    fn from(value: schema::gas_price::EstimatedFee) -> Self {
+        Self {
+            min_fee: value.min_fee.into(),
+            max_fee: value.max_fee.into(),
+            gas_used: value.gas_used.into(),
+        }
+    }
+}
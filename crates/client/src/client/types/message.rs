@@ -50,16 +50,20 @@ pub struct MessageProof {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MessageStatus {
     Unspent,
+    UnspentUnfinalized,
     Spent,
     NotFound,
+    RelayPending,
 }
 
 impl From<schema::message::MessageStatus> for MessageStatus {
     fn from(value: schema::message::MessageStatus) -> Self {
         match value.state {
             schema::message::MessageState::Unspent => Self::Unspent,
+            schema::message::MessageState::UnspentUnfinalized => Self::UnspentUnfinalized,
             schema::message::MessageState::Spent => Self::Spent,
             schema::message::MessageState::NotFound => Self::NotFound,
+            schema::message::MessageState::RelayPending => Self::RelayPending,
         }
     }
 }
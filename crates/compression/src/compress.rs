@@ -13,7 +13,9 @@ use crate::{
         TemporalRegistryAll,
     },
     CompressedBlockPayloadV0,
+    CompressedTransactionPayloadV0,
     VersionedCompressedBlock,
+    VersionedCompressedTransaction,
 };
 use anyhow::Context;
 use fuel_core_types::{
@@ -27,6 +29,7 @@ use fuel_core_types::{
         input::PredicateCode,
         CompressedUtxoId,
         ScriptCode,
+        Transaction,
         TxPointer,
         UtxoId,
     },
@@ -45,6 +48,24 @@ use std::collections::{
 pub trait CompressDb: TemporalRegistryAll + EvictorDbAll + UtxoIdToPointer {}
 impl<T> CompressDb for T where T: TemporalRegistryAll + EvictorDbAll + UtxoIdToPointer {}
 
+/// Statistics collected while compressing a block.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionStats {
+    /// Number of `UtxoId`s replaced with pointers to the outputs that created them.
+    pub utxo_pointers_referenced: usize,
+    /// Number of values replaced with keys already present in the registry.
+    pub registry_entries_referenced: usize,
+    /// Number of values added to the registry by the block.
+    pub new_registry_entries: usize,
+}
+
+/// The compressed block along with the statistics of its compression.
+#[derive(Debug, Clone)]
+pub struct CompressedBlockWithStats {
+    pub block: VersionedCompressedBlock,
+    pub stats: CompressionStats,
+}
+
 /// This must be called for all new blocks in sequence, otherwise the result will be garbage, since
 /// the registry is valid for only the current block height. On any other height you could be
 /// referring to keys that have already been overwritten, or have not been written to yet.
@@ -52,7 +73,7 @@ pub async fn compress<D>(
     config: Config,
     mut db: D,
     block: &Block,
-) -> anyhow::Result<VersionedCompressedBlock>
+) -> anyhow::Result<CompressedBlockWithStats>
 where
     D: CompressDb,
 {
@@ -68,13 +89,71 @@ where
 
     let mut ctx = prepare_ctx.into_compression_context()?;
     let transactions = target.compress_with(&mut ctx).await?;
+    let stats = ctx.stats;
     let registrations: RegistrationsPerTable = ctx.finalize()?;
 
-    Ok(VersionedCompressedBlock::V0(CompressedBlockPayloadV0 {
-        registrations,
-        header: block.header().into(),
-        transactions,
-    }))
+    Ok(CompressedBlockWithStats {
+        block: VersionedCompressedBlock::V0(CompressedBlockPayloadV0 {
+            registrations,
+            header: block.header().into(),
+            transactions,
+        }),
+        stats,
+    })
+}
+
+/// Same as [`compress`], but serializes the compressed block with `postcard`
+/// into the `writer`, without an intermediate byte buffer.
+///
+/// It doesn't stream the block: the whole compressed block is built in memory first,
+/// because the registrations are serialized before the transactions
+/// and are known only after all transactions are compressed.
+pub async fn compress_to_writer<D, W>(
+    config: Config,
+    db: D,
+    block: &Block,
+    writer: W,
+) -> anyhow::Result<CompressionStats>
+where
+    D: CompressDb,
+    W: std::io::Write,
+{
+    let CompressedBlockWithStats { block, stats } = compress(config, db, block).await?;
+    postcard::to_io(&block, writer).context("Failed to write the compressed block")?;
+    Ok(stats)
+}
+
+/// Compresses a single transaction against the registry state at `timestamp`,
+/// f.e. to gossip it before it is included into a block.
+/// Unlike [`compress`], the database is not modified: values missing from the registry
+/// are carried by the compressed transaction instead.
+pub async fn compress_transaction<D>(
+    config: Config,
+    mut db: D,
+    timestamp: Tai64,
+    tx: &Transaction,
+) -> anyhow::Result<VersionedCompressedTransaction>
+where
+    D: CompressDb,
+{
+    let mut prepare_ctx = PrepareCtx {
+        config,
+        timestamp,
+        db: &mut db,
+        accessed_keys: Default::default(),
+    };
+    let _ = tx.compress_with(&mut prepare_ctx).await?;
+
+    let mut ctx = prepare_ctx.into_compression_context()?;
+    let transaction = tx.compress_with(&mut ctx).await?;
+    let registrations = ctx.into_registrations();
+
+    Ok(VersionedCompressedTransaction::V0(
+        CompressedTransactionPayloadV0 {
+            registrations,
+            transaction,
+        },
+    ))
 }
 
 /// Preparation pass through the block to collect all keys accessed during compression.
@@ -124,6 +203,7 @@ macro_rules! compression {
             config: Config,
             timestamp: Tai64,
             db: D,
+            stats: CompressionStats,
             $($ident: CompressCtxKeyspace<$type>,)*
         }
 
@@ -143,6 +223,7 @@ macro_rules! compression {
                     config: self.config,
                     timestamp: self.timestamp,
                     db: self.db,
+                    stats: CompressionStats::default(),
                 })
             }
         }
@@ -161,6 +242,17 @@ macro_rules! compression {
                 registrations.write_to_registry(&mut self.db, self.timestamp)?;
                 Ok(registrations)
             }
+
+            /// Returns the changes to the registry without committing anything to the database.
+            fn into_registrations(self) -> RegistrationsPerTable {
+                let mut registrations = RegistrationsPerTable::default();
+                $(
+                    for (key, value) in self.$ident.changes.into_iter() {
+                        registrations.$ident.push((key, value));
+                    }
+                )*
+                registrations
+            }
         }
 
         $(
@@ -200,16 +292,23 @@ macro_rules! compression {
                         return Ok(RegistryKey::DEFAULT_VALUE);
                     }
                     if let Some(found) = ctx.$ident.changes_lookup.get(self) {
+                        ctx.stats.registry_entries_referenced =
+                            ctx.stats.registry_entries_referenced.saturating_add(1);
                         return Ok(*found);
                     }
                     if let Some(found) = ctx.db.registry_index_lookup(self)? {
                         let key_timestamp = ctx.db.read_timestamp(&found)
                             .context("Database invariant violated: no timestamp stored but key found")?;
                         if ctx.config.is_timestamp_accessible(ctx.timestamp, key_timestamp)? {
+                            ctx.stats.registry_entries_referenced =
+                                ctx.stats.registry_entries_referenced.saturating_add(1);
                             return Ok(found);
                         }
                     }
 
+                    ctx.stats.new_registry_entries =
+                        ctx.stats.new_registry_entries.saturating_add(1);
+
                     let key = ctx.$ident.cache_evictor.next_key();
                     let old = ctx.$ident.changes.insert(key, self.clone());
                     let old_rev = ctx.$ident.changes_lookup.insert(self.clone(), key);
@@ -242,6 +341,605 @@ where
         &self,
         ctx: &mut CompressCtx<D>,
     ) -> anyhow::Result<CompressedUtxoId> {
-        ctx.db.lookup(*self)
+        let pointer = ctx.db.lookup(*self)?;
+        ctx.stats.utxo_pointers_referenced =
+            ctx.stats.utxo_pointers_referenced.saturating_add(1);
+        Ok(pointer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        decompress::{
+            decompress,
+            decompress_header,
+            decompress_transaction,
+            deserialize_block,
+        },
+        ports::{
+            CoinInfo,
+            HistoryLookup,
+            MessageInfo,
+        },
+    };
+    use core::time::Duration;
+    use fuel_core_types::{
+        fuel_tx::{
+            field::Inputs,
+            Input,
+            Output,
+            Policies,
+        },
+        fuel_types::{
+            canonical::Serialize,
+            Bytes32,
+            Nonce,
+        },
+    };
+
+    #[derive(Default)]
+    struct MockRegistry<T> {
+        values: HashMap<RegistryKey, (T, Tai64)>,
+        latest_assigned_key: Option<RegistryKey>,
+    }
+
+    #[derive(Default)]
+    struct MockTxDb {
+        address: MockRegistry<Address>,
+        asset_id: MockRegistry<AssetId>,
+        contract_id: MockRegistry<ContractId>,
+        script_code: MockRegistry<ScriptCode>,
+        predicate_code: MockRegistry<PredicateCode>,
+        coins: Vec<(UtxoId, CompressedUtxoId, CoinInfo)>,
+        messages: HashMap<Nonce, MessageInfo>,
+    }
+
+    macro_rules! mock_registry {
+        ($($ident:ident: $type:ty),*) => {
+            $(
+                impl TemporalRegistry<$type> for MockTxDb {
+                    fn read_registry(&self, key: &RegistryKey) -> anyhow::Result<$type> {
+                        self.$ident
+                            .values
+                            .get(key)
+                            .map(|(value, _)| value.clone())
+                            .context("Key not found")
+                    }
+
+                    fn read_timestamp(&self, key: &RegistryKey) -> anyhow::Result<Tai64> {
+                        self.$ident
+                            .values
+                            .get(key)
+                            .map(|(_, timestamp)| *timestamp)
+                            .context("Key not found")
+                    }
+
+                    fn write_registry(
+                        &mut self,
+                        key: &RegistryKey,
+                        value: &$type,
+                        timestamp: Tai64,
+                    ) -> anyhow::Result<()> {
+                        self.$ident.values.insert(*key, (value.clone(), timestamp));
+                        Ok(())
+                    }
+
+                    fn registry_index_lookup(
+                        &self,
+                        value: &$type,
+                    ) -> anyhow::Result<Option<RegistryKey>> {
+                        Ok(self
+                            .$ident
+                            .values
+                            .iter()
+                            .find(|(_, (v, _))| v == value)
+                            .map(|(key, _)| *key))
+                    }
+                }
+
+                impl EvictorDb<$type> for MockTxDb {
+                    fn get_latest_assigned_key(&self) -> anyhow::Result<Option<RegistryKey>> {
+                        Ok(self.$ident.latest_assigned_key)
+                    }
+
+                    fn set_latest_assigned_key(
+                        &mut self,
+                        key: RegistryKey,
+                    ) -> anyhow::Result<()> {
+                        self.$ident.latest_assigned_key = Some(key);
+                        Ok(())
+                    }
+                }
+            )*
+        };
+    }
+
+    mock_registry!(
+        address: Address,
+        asset_id: AssetId,
+        contract_id: ContractId,
+        script_code: ScriptCode,
+        predicate_code: PredicateCode
+    );
+
+    impl MockTxDb {
+        /// Panics if a coin input of the block is missing from the UTXO id mapping,
+        /// so the decompression of the block would not be able to restore it.
+        fn assert_all_utxos_decompressed(&self, original_block: &Block) {
+            for tx in original_block.transactions() {
+                let inputs = match tx {
+                    Transaction::Script(tx) => tx.inputs(),
+                    Transaction::Create(tx) => tx.inputs(),
+                    Transaction::Upgrade(tx) => tx.inputs(),
+                    Transaction::Upload(tx) => tx.inputs(),
+                    Transaction::Blob(tx) => tx.inputs(),
+                    Transaction::Mint(_) => continue,
+                };
+                let utxo_ids = inputs
+                    .iter()
+                    .filter(|input| input.is_coin())
+                    .filter_map(|input| input.utxo_id());
+                for utxo_id in utxo_ids {
+                    assert!(
+                        self.coins.iter().any(|(id, _, _)| id == utxo_id),
+                        "The UTXO {utxo_id} of the original block is missing from the UTXO id mapping"
+                    );
+                }
+            }
+        }
+    }
+
+    impl UtxoIdToPointer for MockTxDb {
+        fn lookup(&self, utxo_id: UtxoId) -> anyhow::Result<CompressedUtxoId> {
+            self.coins
+                .iter()
+                .find(|(id, _, _)| *id == utxo_id)
+                .map(|(_, pointer, _)| *pointer)
+                .context("UtxoId not found")
+        }
+    }
+
+    impl HistoryLookup for MockTxDb {
+        fn utxo_id(&self, c: CompressedUtxoId) -> anyhow::Result<UtxoId> {
+            self.coins
+                .iter()
+                .find(|(_, pointer, _)| *pointer == c)
+                .map(|(utxo_id, _, _)| *utxo_id)
+                .context("Pointer not found")
+        }
+
+        fn coin(&self, utxo_id: UtxoId) -> anyhow::Result<CoinInfo> {
+            self.coins
+                .iter()
+                .find(|(id, _, _)| *id == utxo_id)
+                .map(|(_, _, coin)| coin.clone())
+                .context("Coin not found")
+        }
+
+        fn message(&self, nonce: Nonce) -> anyhow::Result<MessageInfo> {
+            self.messages
+                .get(&nonce)
+                .cloned()
+                .context("Message not found")
+        }
+
+        fn message_count(&self, recipient: &Address) -> anyhow::Result<u64> {
+            let count = self
+                .messages
+                .values()
+                .filter(|m| m.recipient == *recipient)
+                .count();
+            Ok(count as u64)
+        }
+    }
+
+    #[test]
+    fn coin_validated_fails_when_owner_mismatches() {
+        let owner = Address::from([1; 32]);
+        let other_owner = Address::from([2; 32]);
+        let utxo_id = UtxoId::new([3; 32].into(), 0);
+        let mut db = MockTxDb::default();
+
+        // Given
+        db.coins.push((
+            utxo_id,
+            CompressedUtxoId {
+                tx_pointer: Default::default(),
+                output_index: 0,
+            },
+            CoinInfo {
+                owner,
+                amount: 100,
+                asset_id: AssetId::from([4; 32]),
+            },
+        ));
+
+        // When
+        let valid = db.coin_validated(utxo_id, &owner);
+        let invalid = db.coin_validated(utxo_id, &other_owner);
+
+        // Then
+        assert_eq!(valid.unwrap().owner, owner);
+        let err = invalid.unwrap_err().to_string();
+        assert!(err.contains("Owner mismatch"), "{err}");
+    }
+
+    #[test]
+    fn coin_validated_fails_for_zero_amount_coin() {
+        let owner = Address::from([1; 32]);
+        let utxo_id = UtxoId::new([3; 32].into(), 0);
+        let mut db = MockTxDb::default();
+
+        // Given
+        db.coins.push((
+            utxo_id,
+            CompressedUtxoId {
+                tx_pointer: Default::default(),
+                output_index: 0,
+            },
+            CoinInfo {
+                owner,
+                amount: 0,
+                asset_id: AssetId::from([4; 32]),
+            },
+        ));
+
+        // When
+        let result = db.coin_validated(utxo_id, &owner);
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("zero amount"), "{err}");
+    }
+
+    #[test]
+    fn message_count_counts_messages_of_each_recipient() {
+        let alice = Address::from([1; 32]);
+        let bob = Address::from([2; 32]);
+        let mut db = MockTxDb::default();
+
+        // Given
+        for (i, recipient) in [alice, bob, alice, bob, alice].into_iter().enumerate() {
+            let nonce = Nonce::from([u8::try_from(i).unwrap(); 32]);
+            db.messages.insert(
+                nonce,
+                MessageInfo {
+                    sender: Address::from([3; 32]),
+                    recipient,
+                    amount: 100,
+                    data: vec![],
+                },
+            );
+        }
+
+        // When
+        let alice_count = db.message_count(&alice).unwrap();
+        let bob_count = db.message_count(&bob).unwrap();
+        let unknown_count = db.message_count(&Address::from([4; 32])).unwrap();
+
+        // Then
+        assert_eq!(alice_count, 3);
+        assert_eq!(bob_count, 2);
+        assert_eq!(unknown_count, 0);
+    }
+
+    #[tokio::test]
+    async fn compress_transaction_shrinks_coin_inputs_after_registry_is_populated() {
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+        };
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let mut db = MockTxDb::default();
+
+        // Given
+        let mut block = Block::default();
+        let register_tx = Transaction::script(
+            1_000_000,
+            vec![],
+            vec![],
+            Policies::new(),
+            vec![],
+            vec![Output::coin(owner, 100, asset_id)],
+            vec![],
+        );
+        *block.transactions_mut() = vec![register_tx.into()];
+        compress(config, &mut db, &block).await.unwrap();
+
+        let inputs: Vec<_> = (0..3)
+            .map(|output_index| {
+                let utxo_id = UtxoId::new(Bytes32::from([3; 32]), output_index);
+                db.coins.push((
+                    utxo_id,
+                    CompressedUtxoId {
+                        tx_pointer: TxPointer::default(),
+                        output_index,
+                    },
+                    CoinInfo {
+                        owner,
+                        amount: 100,
+                        asset_id,
+                    },
+                ));
+                Input::coin_signed(
+                    utxo_id,
+                    owner,
+                    100,
+                    asset_id,
+                    Default::default(),
+                    Default::default(),
+                )
+            })
+            .collect();
+        let tx: Transaction = Transaction::script(
+            1_000_000,
+            vec![],
+            vec![],
+            Policies::new(),
+            inputs,
+            vec![Output::change(owner, 0, asset_id)],
+            vec![vec![0; 64].into()],
+        )
+        .into();
+
+        // When
+        let compressed =
+            compress_transaction(config, &mut db, block.header().time(), &tx)
+                .await
+                .unwrap();
+
+        // Then
+        let VersionedCompressedTransaction::V0(payload) = &compressed;
+        assert_eq!(payload.registrations, RegistrationsPerTable::default());
+        let original_size = tx.to_bytes().len();
+        let compressed_size = postcard::to_allocvec(&compressed).unwrap().len();
+        assert!(
+            compressed_size.saturating_mul(2) <= original_size,
+            "compressed size {compressed_size} is not half of {original_size}"
+        );
+
+        let decompressed =
+            decompress_transaction(config, db, block.header().time(), compressed)
+                .await
+                .unwrap();
+        assert_eq!(decompressed.to_bytes(), tx.to_bytes());
+    }
+
+    #[tokio::test]
+    async fn compress_reports_registry_and_utxo_pointer_stats() {
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+        };
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let mut db = MockTxDb::default();
+
+        // Given
+        let mut first_block = Block::default();
+        let register_tx = Transaction::script(
+            1_000_000,
+            vec![],
+            vec![],
+            Policies::new(),
+            vec![],
+            vec![Output::coin(owner, 100, asset_id)],
+            vec![],
+        );
+        *first_block.transactions_mut() = vec![register_tx.into()];
+
+        let utxo_id = UtxoId::new(Bytes32::from([3; 32]), 0);
+        db.coins.push((
+            utxo_id,
+            CompressedUtxoId {
+                tx_pointer: TxPointer::default(),
+                output_index: 0,
+            },
+            CoinInfo {
+                owner,
+                amount: 100,
+                asset_id,
+            },
+        ));
+        let mut second_block = Block::default();
+        let spend_tx = Transaction::script(
+            1_000_000,
+            vec![],
+            vec![],
+            Policies::new(),
+            vec![Input::coin_signed(
+                utxo_id,
+                owner,
+                100,
+                asset_id,
+                Default::default(),
+                Default::default(),
+            )],
+            vec![Output::change(owner, 0, asset_id)],
+            vec![vec![0; 64].into()],
+        );
+        *second_block.transactions_mut() = vec![spend_tx.into()];
+
+        // When
+        let first = compress(config, &mut db, &first_block).await.unwrap();
+        let second = compress(config, &mut db, &second_block).await.unwrap();
+
+        // Then
+        db.assert_all_utxos_decompressed(&second_block);
+        assert_eq!(first.stats.new_registry_entries, 2);
+        assert_eq!(first.stats.utxo_pointers_referenced, 0);
+        assert_eq!(second.stats.new_registry_entries, 0);
+        assert_eq!(second.stats.utxo_pointers_referenced, 1);
+        assert!(second.stats.registry_entries_referenced >= 2);
+    }
+
+    #[tokio::test]
+    async fn compress_to_writer_matches_serialized_compress_output() {
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+        };
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+
+        // Given
+        let mut block = Block::default();
+        let tx = Transaction::script(
+            1_000_000,
+            vec![],
+            vec![],
+            Policies::new(),
+            vec![],
+            vec![Output::coin(owner, 100, asset_id)],
+            vec![],
+        );
+        *block.transactions_mut() = vec![tx.into()];
+        let buffered = compress(config, &mut MockTxDb::default(), &block)
+            .await
+            .unwrap();
+
+        // When
+        let mut streamed = Vec::new();
+        let stats =
+            compress_to_writer(config, &mut MockTxDb::default(), &block, &mut streamed)
+                .await
+                .unwrap();
+
+        // Then
+        assert_eq!(streamed, postcard::to_allocvec(&buffered.block).unwrap());
+        assert_eq!(stats, buffered.stats);
+    }
+
+    #[tokio::test]
+    async fn compress_decompress_roundtrip() {
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+        };
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let mut db = MockTxDb::default();
+
+        // Given
+        let utxo_id = UtxoId::new(Bytes32::from([3; 32]), 0);
+        db.coins.push((
+            utxo_id,
+            CompressedUtxoId {
+                tx_pointer: TxPointer::default(),
+                output_index: 0,
+            },
+            CoinInfo {
+                owner,
+                amount: 100,
+                asset_id,
+            },
+        ));
+        let mut block = Block::default();
+        let tx = Transaction::script(
+            1_000_000,
+            vec![],
+            vec![],
+            Policies::new(),
+            vec![Input::coin_signed(
+                utxo_id,
+                owner,
+                100,
+                asset_id,
+                Default::default(),
+                Default::default(),
+            )],
+            vec![Output::change(owner, 0, asset_id)],
+            vec![vec![0; 64].into()],
+        );
+        *block.transactions_mut() = vec![tx.into()];
+
+        // When
+        let compressed = compress(config, &mut db, &block).await.unwrap().block;
+
+        // Then
+        db.assert_all_utxos_decompressed(&block);
+        let decompressed = decompress(config, db, compressed).await.unwrap();
+        assert_eq!(decompressed.transactions, block.transactions());
+    }
+
+    #[tokio::test]
+    async fn decompress_header_matches_header_of_full_decompress() {
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+        };
+        let mut db = MockTxDb::default();
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+
+        // Given
+        let mut block = Block::default();
+        let tx = Transaction::script(
+            1_000_000,
+            vec![],
+            vec![],
+            Policies::new(),
+            vec![],
+            vec![Output::coin(owner, 100, asset_id)],
+            vec![],
+        );
+        *block.transactions_mut() = vec![tx.into()];
+        let compressed = compress(config, &mut db, &block).await.unwrap().block;
+        let serialized = postcard::to_allocvec(&compressed).unwrap();
+
+        // When
+        let header = decompress_header(&serialized).unwrap();
+
+        // Then
+        let decompressed = decompress(config, db, compressed).await.unwrap();
+        assert_eq!(header, decompressed.header);
+    }
+
+    #[tokio::test]
+    async fn deserialize_block_accepts_block_within_max_txs() {
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+        };
+        let mut db = MockTxDb::default();
+
+        // Given
+        let mut block = Block::default();
+        let tx = Transaction::script(
+            1_000_000,
+            vec![],
+            vec![],
+            Policies::new(),
+            vec![],
+            vec![],
+            vec![],
+        );
+        *block.transactions_mut() = vec![tx.into(); 3];
+        let compressed = compress(config, &mut db, &block).await.unwrap().block;
+        let serialized = postcard::to_allocvec(&compressed).unwrap();
+
+        // When
+        let result = deserialize_block(&serialized, 3);
+
+        // Then
+        assert_eq!(result.unwrap(), compressed);
+    }
+
+    #[test]
+    fn deserialize_block_rejects_claimed_count_above_max_txs_before_parsing() {
+        // Given
+        let mut serialized =
+            postcard::to_allocvec(&VersionedCompressedBlock::default()).unwrap();
+        // The empty transactions vector is serialized as a single zero length byte
+        assert_eq!(serialized.pop(), Some(0));
+        let huge_count = 1u64 << 40;
+        serialized.extend(postcard::to_allocvec(&huge_count).unwrap());
+
+        // When
+        let result = deserialize_block(&serialized, 2);
+
+        // Then
+        let err = result.unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "The compressed block has 1099511627776 transactions, while the max is 2"
+        );
     }
 }
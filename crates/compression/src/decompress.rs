@@ -1,14 +1,25 @@
 use crate::{
     config::Config,
     ports::{
+        CoinInfo,
         HistoryLookup,
+        MessageInfo,
         TemporalRegistry,
     },
-    registry::TemporalRegistryAll,
+    registry::{
+        RegistrationsOverlay,
+        TemporalRegistryAll,
+    },
+    CompressedBlockHeaderPrefixV0,
     VersionedCompressedBlock,
+    VersionedCompressedBlockHeaderPrefix,
+    VersionedCompressedTransaction,
 };
 use fuel_core_types::{
-    blockchain::block::PartialFuelBlock,
+    blockchain::{
+        block::PartialFuelBlock,
+        header::PartialBlockHeader,
+    },
     fuel_compression::{
         Compressible,
         ContextError,
@@ -39,6 +50,7 @@ use fuel_core_types::{
     fuel_types::{
         Address,
         ContractId,
+        Nonce,
     },
     tai64::Tai64,
 };
@@ -81,6 +93,93 @@ where
     })
 }
 
+/// Deserializes a postcard-serialized [`VersionedCompressedBlock`].
+///
+/// Fails before the transactions are parsed if the block declares more
+/// than `max_txs` of them, so a crafted count can't cause unbounded allocations.
+pub fn deserialize_block(
+    compressed: &[u8],
+    max_txs: usize,
+) -> anyhow::Result<VersionedCompressedBlock> {
+    let (_prefix, transactions) =
+        postcard::take_from_bytes::<VersionedCompressedBlockHeaderPrefix>(compressed)?;
+    // The transactions are preceded by their count encoded as a varint.
+    let (txs_count, _) = postcard::take_from_bytes::<u64>(transactions)?;
+    let max_txs = u64::try_from(max_txs).unwrap_or(u64::MAX);
+    if txs_count > max_txs {
+        anyhow::bail!(
+            "The compressed block has {txs_count} transactions, while the max is {max_txs}"
+        );
+    }
+
+    Ok(postcard::from_bytes(compressed)?)
+}
+
+/// Reads only the header of a postcard-serialized [`VersionedCompressedBlock`].
+///
+/// The header is stored right after the registrations of the block, so the
+/// transactions are neither parsed nor decompressed, and the database isn't touched.
+/// Unlike [`decompress`], it can be called for blocks out of sequence.
+pub fn decompress_header(compressed: &[u8]) -> anyhow::Result<PartialBlockHeader> {
+    let (prefix, _transactions) =
+        postcard::take_from_bytes::<VersionedCompressedBlockHeaderPrefix>(compressed)?;
+    let VersionedCompressedBlockHeaderPrefix::V0(CompressedBlockHeaderPrefixV0(
+        _registrations,
+        header,
+    )) = prefix;
+    Ok(header)
+}
+
+/// Decompresses a transaction compressed by
+/// [`compress_transaction`](crate::compress::compress_transaction).
+/// The registrations carried by the transaction are used only for its decompression
+/// and are not written to the database.
+pub async fn decompress_transaction<D>(
+    config: Config,
+    db: D,
+    timestamp: Tai64,
+    tx: VersionedCompressedTransaction,
+) -> anyhow::Result<Transaction>
+where
+    D: DecompressDb,
+{
+    let VersionedCompressedTransaction::V0(compressed) = tx;
+
+    let ctx = DecompressCtx {
+        config,
+        timestamp,
+        db: RegistrationsOverlay {
+            db,
+            registrations: compressed.registrations,
+            timestamp,
+        },
+    };
+
+    <Transaction as DecompressibleBy<_>>::decompress_with(compressed.transaction, &ctx)
+        .await
+}
+
+impl<D> HistoryLookup for RegistrationsOverlay<D>
+where
+    D: HistoryLookup,
+{
+    fn utxo_id(&self, c: CompressedUtxoId) -> anyhow::Result<UtxoId> {
+        self.db.utxo_id(c)
+    }
+
+    fn coin(&self, utxo_id: UtxoId) -> anyhow::Result<CoinInfo> {
+        self.db.coin(utxo_id)
+    }
+
+    fn message(&self, nonce: Nonce) -> anyhow::Result<MessageInfo> {
+        self.db.message(nonce)
+    }
+
+    fn message_count(&self, recipient: &Address) -> anyhow::Result<u64> {
+        self.db.message_count(recipient)
+    }
+}
+
 pub struct DecompressCtx<D> {
     pub config: Config,
     /// Timestamp of the block being decompressed
@@ -146,6 +245,7 @@ where
     ) -> anyhow::Result<Coin<Specification>> {
         let utxo_id = UtxoId::decompress_with(c.utxo_id, ctx).await?;
         let coin_info = ctx.db.coin(utxo_id)?;
+        coin_info.validate()?;
         let witness_index = c.witness_index.decompress(ctx).await?;
         let predicate_gas_used = c.predicate_gas_used.decompress(ctx).await?;
         let predicate = c.predicate.decompress(ctx).await?;
@@ -261,6 +361,10 @@ mod tests {
         ) -> anyhow::Result<crate::ports::MessageInfo> {
             unimplemented!()
         }
+
+        fn message_count(&self, _: &Address) -> anyhow::Result<u64> {
+            unimplemented!()
+        }
     }
     macro_rules! mock_temporal {
         ($type:ty) => {
@@ -333,4 +437,18 @@ mod tests {
         let _ =
             result.expect_err("should fail to deserialize because of unknown version");
     }
+
+    #[test]
+    fn decompress_header_with_unknown_version() {
+        // Given
+        let mut block =
+            postcard::to_stdvec(&VersionedCompressedBlock::default()).unwrap();
+        block[0] = 1;
+
+        // When
+        let result = decompress_header(&block);
+
+        // Then
+        let _ = result.expect_err("should fail to parse because of unknown version");
+    }
 }
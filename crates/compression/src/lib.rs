@@ -36,12 +36,40 @@ pub enum VersionedCompressedBlock {
     V0(CompressedBlockPayloadV0),
 }
 
+/// The prefix of the serialized [`CompressedBlockPayloadV0`] that ends with the header.
+/// The fields must match the order of the payload fields.
+#[derive(serde::Deserialize)]
+struct CompressedBlockHeaderPrefixV0(RegistrationsPerTable, PartialBlockHeader);
+
+/// The prefix of the serialized [`VersionedCompressedBlock`] that ends with the header.
+/// The variants must match the variants of the versioned block.
+#[derive(serde::Deserialize)]
+enum VersionedCompressedBlockHeaderPrefix {
+    V0(CompressedBlockHeaderPrefixV0),
+}
+
 impl Default for VersionedCompressedBlock {
     fn default() -> Self {
         Self::V0(Default::default())
     }
 }
 
+/// Compressed transaction, without the preceding version byte.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CompressedTransactionPayloadV0 {
+    /// Temporal registry insertions required to decompress the transaction.
+    /// Unlike the block ones, they are never written to the registry.
+    pub registrations: RegistrationsPerTable,
+    /// Compressed transaction
+    pub transaction: CompressedTransaction,
+}
+
+/// Versioned compressed transaction.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum VersionedCompressedTransaction {
+    V0(CompressedTransactionPayloadV0),
+}
+
 #[cfg(test)]
 mod tests {
     use fuel_core_compression as _;
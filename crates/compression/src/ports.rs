@@ -81,7 +81,26 @@ where
 pub trait HistoryLookup {
     fn utxo_id(&self, c: CompressedUtxoId) -> anyhow::Result<UtxoId>;
     fn coin(&self, utxo_id: UtxoId) -> anyhow::Result<CoinInfo>;
+    /// Same as [`HistoryLookup::coin`], but fails if the coin is not owned
+    /// by the `expected_owner`.
+    fn coin_validated(
+        &self,
+        utxo_id: UtxoId,
+        expected_owner: &Address,
+    ) -> anyhow::Result<CoinInfo> {
+        let coin = self.coin(utxo_id)?;
+        coin.validate()?;
+        if coin.owner != *expected_owner {
+            anyhow::bail!(
+                "Owner mismatch for the coin {utxo_id}: expected {expected_owner}, got {}",
+                coin.owner
+            );
+        }
+        Ok(coin)
+    }
     fn message(&self, nonce: Nonce) -> anyhow::Result<MessageInfo>;
+    /// Returns the number of messages sent to the `recipient`.
+    fn message_count(&self, recipient: &Address) -> anyhow::Result<u64>;
 }
 
 /// Information about a coin.
@@ -92,6 +111,21 @@ pub struct CoinInfo {
     pub asset_id: AssetId,
 }
 
+impl CoinInfo {
+    /// Checks that the coin could have been created by a transaction.
+    /// Outputs without any value don't create coins.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.amount == 0 {
+            anyhow::bail!(
+                "Coin of the owner {} with the asset {} has zero amount",
+                self.owner,
+                self.asset_id
+            );
+        }
+        Ok(())
+    }
+}
+
 /// Information about a message.
 #[derive(Debug, Clone)]
 pub struct MessageInfo {
@@ -91,9 +91,56 @@ macro_rules! tables {
                 Ok(())
             }
         }
+
+        $(
+            impl<D> TemporalRegistry<$type> for RegistrationsOverlay<D>
+            where
+                D: TemporalRegistry<$type>
+            {
+                fn read_registry(&self, key: &RegistryKey) -> anyhow::Result<$type> {
+                    match self.registrations.$ident.iter().find(|(k, _)| k == key) {
+                        Some((_, value)) => Ok(value.clone()),
+                        None => <D as TemporalRegistry<$type>>::read_registry(&self.db, key),
+                    }
+                }
+
+                fn read_timestamp(&self, key: &RegistryKey) -> anyhow::Result<Tai64> {
+                    if self.registrations.$ident.iter().any(|(k, _)| k == key) {
+                        Ok(self.timestamp)
+                    } else {
+                        <D as TemporalRegistry<$type>>::read_timestamp(&self.db, key)
+                    }
+                }
+
+                fn write_registry(
+                    &mut self,
+                    _key: &RegistryKey,
+                    _value: &$type,
+                    _timestamp: Tai64,
+                ) -> anyhow::Result<()> {
+                    anyhow::bail!("The registrations overlay is read-only")
+                }
+
+                fn registry_index_lookup(&self, value: &$type) -> anyhow::Result<Option<RegistryKey>> {
+                    match self.registrations.$ident.iter().find(|(_, v)| v == value) {
+                        Some((key, _)) => Ok(Some(*key)),
+                        None => <D as TemporalRegistry<$type>>::registry_index_lookup(&self.db, value),
+                    }
+                }
+            }
+        )*
     }};
 }
 
+/// Resolves the registrations carried by a single compressed transaction
+/// before falling back to the underlying registry. Read-only.
+pub(crate) struct RegistrationsOverlay<D> {
+    pub db: D,
+    pub registrations: RegistrationsPerTable,
+    /// Timestamp assigned to the carried registrations
+    pub timestamp: Tai64,
+}
+
 tables!(
     address: Address,
     asset_id: AssetId,
@@ -72,3 +72,68 @@ impl OffChainIterableKeyValueView {
             .map(|v| v.map(|v| v.into_owned()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{
+        database_description::off_chain::OffChain,
+        Database,
+    };
+    use fuel_core_storage::{
+        transactional::AtomicView,
+        StorageAsMut,
+    };
+
+    fn address_with_last_byte(last_byte: u8) -> Address {
+        let mut bytes = [1u8; 32];
+        bytes[31] = last_byte;
+        bytes.into()
+    }
+
+    #[test]
+    fn owned_transactions_returns_only_transactions_of_the_owner() {
+        // Given
+        let mut database = Database::<OffChain>::default();
+        let owner = address_with_last_byte(1);
+        let owner_txs = [
+            (
+                OwnedTransactionIndexKey::new(&owner, 1.into(), 0),
+                Bytes32::from([10u8; 32]),
+            ),
+            (
+                OwnedTransactionIndexKey::new(&owner, 2.into(), 3),
+                Bytes32::from([11u8; 32]),
+            ),
+        ];
+        let neighbour_txs = [
+            (
+                OwnedTransactionIndexKey::new(&address_with_last_byte(0), 1.into(), 1),
+                Bytes32::from([20u8; 32]),
+            ),
+            (
+                OwnedTransactionIndexKey::new(&address_with_last_byte(2), 1.into(), 2),
+                Bytes32::from([21u8; 32]),
+            ),
+        ];
+        for (key, tx_id) in owner_txs.iter().chain(neighbour_txs.iter()) {
+            database
+                .storage_as_mut::<OwnedTransactions>()
+                .insert(key, tx_id)
+                .unwrap();
+        }
+
+        // When
+        let view = database.latest_view().unwrap();
+        let tx_ids = view
+            .owned_transactions(owner, None, None)
+            .map(|result| result.map(|(_, tx_id)| tx_id))
+            .collect::<StorageResult<Vec<Bytes32>>>()
+            .unwrap();
+
+        // Then
+        let expected_tx_ids: Vec<Bytes32> =
+            owner_txs.iter().map(|(_, tx_id)| *tx_id).collect();
+        assert_eq!(tx_ids, expected_tx_ids);
+    }
+}
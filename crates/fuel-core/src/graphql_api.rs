@@ -44,6 +44,9 @@ pub struct ServiceConfig {
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
     pub api_request_timeout: Duration,
+    /// The max number of blocks the commit block of a message proof may lag behind
+    /// the latest block. Unlimited if `None`.
+    pub max_message_proof_age_blocks: Option<u32>,
     /// Configurable cost parameters to limit graphql queries complexity
     pub costs: Costs,
 }
@@ -9,6 +9,7 @@ use crate::{
             OffChainDatabase,
             OnChainDatabase,
             P2pPort,
+            RelayerDatabase,
             TxPoolPort,
         },
         validation_extension::ValidationExtension,
@@ -213,12 +214,13 @@ impl RunnableTask for Task {
 
 // Need a separate Data Object for each Query endpoint, cannot be avoided
 #[allow(clippy::too_many_arguments)]
-pub fn new_service<OnChain, OffChain>(
+pub fn new_service<OnChain, OffChain, Relayer>(
     genesis_block_height: BlockHeight,
     config: Config,
     schema: CoreSchemaBuilder,
     on_database: OnChain,
     off_database: OffChain,
+    relayer_database: Relayer,
     txpool: TxPool,
     producer: BlockProducer,
     consensus_module: ConsensusModule,
@@ -230,8 +232,10 @@ pub fn new_service<OnChain, OffChain>(
 where
     OnChain: AtomicView + 'static,
     OffChain: AtomicView + 'static,
+    Relayer: AtomicView + 'static,
     OnChain::LatestView: OnChainDatabase,
     OffChain::LatestView: OffChainDatabase,
+    Relayer::LatestView: RelayerDatabase,
 {
     graphql_api::initialize_query_costs(config.config.costs.clone())?;
 
@@ -241,6 +245,7 @@ where
         genesis_block_height,
         on_database,
         off_database,
+        relayer_database,
     );
     let request_timeout = config.config.api_request_timeout;
     let concurrency_limit = config.config.max_concurrent_queries;
@@ -58,9 +58,15 @@ where
     .now_or_never()
     .expect("The current implementation resolved all futures instantly")?;
 
+    tracing::debug!(
+        "DA compression stats for block {}: {:?}",
+        block.header().consensus().height,
+        compressed.stats
+    );
+
     db_tx
         .storage_as_mut::<DaCompressedBlocks>()
-        .insert(&block.header().consensus().height, &compressed)?;
+        .insert(&block.header().consensus().height, &compressed.block)?;
 
     Ok(())
 }
@@ -3,6 +3,7 @@ use crate::fuel_core_graphql_api::{
     ports::{
         OffChainDatabase,
         OnChainDatabase,
+        RelayerDatabase,
     },
 };
 use fuel_core_services::yield_stream::StreamYieldExt;
@@ -74,8 +75,10 @@ mod arc_wrapper;
 pub type OnChainView = Arc<dyn OnChainDatabase>;
 /// The off-chain view of the database used by the [`ReadView`] to fetch off-chain data.
 pub type OffChainView = Arc<dyn OffChainDatabase>;
+/// The relayer view of the database used by the [`ReadView`] to fetch not yet relayed data.
+pub type RelayerView = Arc<dyn RelayerDatabase>;
 
-/// The container of the on-chain and off-chain database view provides.
+/// The container of the on-chain, off-chain and relayer database view provides.
 /// It is used only by `ViewExtension` to create a [`ReadView`].
 pub struct ReadDatabase {
     /// The size of the batch during fetching from the database.
@@ -86,27 +89,34 @@ pub struct ReadDatabase {
     on_chain: Box<dyn AtomicView<LatestView = OnChainView>>,
     /// The off-chain database view provider.
     off_chain: Box<dyn AtomicView<LatestView = OffChainView>>,
+    /// The relayer database view provider.
+    relayer: Box<dyn AtomicView<LatestView = RelayerView>>,
 }
 
 impl ReadDatabase {
-    /// Creates a new [`ReadDatabase`] with the given on-chain and off-chain database view providers.
-    pub fn new<OnChain, OffChain>(
+    /// Creates a new [`ReadDatabase`] with the given on-chain, off-chain and relayer
+    /// database view providers.
+    pub fn new<OnChain, OffChain, Relayer>(
         batch_size: usize,
         genesis_height: BlockHeight,
         on_chain: OnChain,
         off_chain: OffChain,
+        relayer: Relayer,
     ) -> Self
     where
         OnChain: AtomicView + 'static,
         OffChain: AtomicView + 'static,
+        Relayer: AtomicView + 'static,
         OnChain::LatestView: OnChainDatabase,
         OffChain::LatestView: OffChainDatabase,
+        Relayer::LatestView: RelayerDatabase,
     {
         Self {
             batch_size,
             genesis_height,
             on_chain: Box::new(ArcWrapper::new(on_chain)),
             off_chain: Box::new(ArcWrapper::new(off_chain)),
+            relayer: Box::new(ArcWrapper::new(relayer)),
         }
     }
 
@@ -120,6 +130,7 @@ impl ReadDatabase {
             genesis_height: self.genesis_height,
             on_chain: self.on_chain.latest_view()?,
             off_chain: self.off_chain.latest_view()?,
+            relayer: self.relayer.latest_view()?,
         })
     }
 
@@ -135,6 +146,7 @@ pub struct ReadView {
     pub(crate) genesis_height: BlockHeight,
     pub(crate) on_chain: OnChainView,
     pub(crate) off_chain: OffChainView,
+    pub(crate) relayer: RelayerView,
 }
 
 impl ReadView {
@@ -281,6 +293,15 @@ impl ReadView {
         self.on_chain.message_exists(nonce)
     }
 
+    pub fn message_is_pending_relay(&self, nonce: &Nonce) -> StorageResult<bool> {
+        let da_height = self.da_height()?;
+        self.relayer.message_is_pending_relay(nonce, da_height)
+    }
+
+    pub fn finalized_da_height(&self) -> StorageResult<Option<DaBlockHeight>> {
+        self.relayer.finalized_da_height()
+    }
+
     pub fn relayed_transaction_status(
         &self,
         id: Bytes32,
@@ -2,10 +2,12 @@ use crate::fuel_core_graphql_api::{
     database::{
         OffChainView,
         OnChainView,
+        RelayerView,
     },
     ports::{
         OffChainDatabase,
         OnChainDatabase,
+        RelayerDatabase,
     },
 };
 use fuel_core_storage::{
@@ -15,7 +17,7 @@ use fuel_core_storage::{
 use std::sync::Arc;
 
 /// The GraphQL can't work with the generics in [`async_graphql::Context::data_unchecked`] and requires a known type.
-/// It is an `Arc` wrapper around the generic for on-chain and off-chain databases.
+/// It is an `Arc` wrapper around the generic for on-chain, off-chain and relayer databases.
 pub struct ArcWrapper<Provider, ArcView> {
     inner: Provider,
     _marker: core::marker::PhantomData<ArcView>,
@@ -53,3 +55,15 @@ where
         Ok(Arc::new(self.inner.latest_view()?))
     }
 }
+
+impl<Provider, View> AtomicView for ArcWrapper<Provider, RelayerView>
+where
+    Provider: AtomicView<LatestView = View>,
+    View: RelayerDatabase + 'static,
+{
+    type LatestView = RelayerView;
+
+    fn latest_view(&self) -> StorageResult<Self::LatestView> {
+        Ok(Arc::new(self.inner.latest_view()?))
+    }
+}
@@ -4,12 +4,14 @@ use async_graphql::{
         ExtensionContext,
         ExtensionFactory,
         NextParseQuery,
+        NextPrepareRequest,
         NextRequest,
         NextResolve,
         NextValidation,
         ResolveInfo,
     },
     parser::types::ExecutableDocument,
+    Request,
     Response,
     ServerError,
     ServerResult,
@@ -17,7 +19,10 @@ use async_graphql::{
     Value,
     Variables,
 };
-use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use fuel_core_metrics::graphql_metrics::{
+    graphql_metrics,
+    SpanExemplar,
+};
 use std::{
     sync::{
         Arc,
@@ -42,6 +47,7 @@ impl ExtensionFactory for MetricsExtension {
         Arc::new(MetricsExtInner {
             log_threshold_ms: self.log_threshold_ms,
             current_query: OnceLock::new(),
+            current_operation: OnceLock::new(),
         })
     }
 }
@@ -49,19 +55,53 @@ impl ExtensionFactory for MetricsExtension {
 pub(crate) struct MetricsExtInner {
     log_threshold_ms: Duration,
     current_query: OnceLock<String>,
+    current_operation: OnceLock<String>,
+}
+
+impl MetricsExtInner {
+    /// Identifies the observation by the current `tracing` span, if the request is in one.
+    fn exemplar(&self) -> Option<SpanExemplar> {
+        let span_id = tracing::Span::current().id()?;
+        let operation = self
+            .current_operation
+            .get()
+            .map(String::as_str)
+            .unwrap_or("UNKNOWN");
+        Some(SpanExemplar {
+            span_id: format!("{:016x}", span_id.into_u64()),
+            operation: operation.to_string(),
+        })
+    }
 }
 
 #[async_trait::async_trait]
 impl Extension for MetricsExtInner {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        if let Some(operation) = &request.operation_name {
+            let _ = self.current_operation.set(operation.clone());
+        }
+        next.run(ctx, request).await
+    }
+
     async fn request(
         &self,
         ctx: &ExtensionContext<'_>,
         next: NextRequest<'_>,
     ) -> Response {
+        let _inflight = graphql_metrics().start_request();
         let start_time = Instant::now();
         let result = next.run(ctx).await;
         let seconds = start_time.elapsed().as_secs_f64();
-        graphql_metrics().graphql_observe("request", seconds);
+        graphql_metrics().graphql_observe_with_exemplar(
+            "request",
+            seconds,
+            self.exemplar(),
+        );
 
         result
     }
@@ -102,7 +142,11 @@ impl Extension for MetricsExtInner {
         let elapsed = start_time.elapsed();
 
         if let Some(field_name) = field_name {
-            graphql_metrics().graphql_observe(field_name, elapsed.as_secs_f64());
+            graphql_metrics().graphql_observe_with_exemplar(
+                field_name,
+                elapsed.as_secs_f64(),
+                self.exemplar(),
+            );
         }
 
         if elapsed > self.log_threshold_ms {
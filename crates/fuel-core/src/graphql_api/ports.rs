@@ -130,6 +130,21 @@ pub trait OnChainDatabase:
 {
 }
 
+/// The relayer database port expected by GraphQL API service.
+pub trait RelayerDatabase: Send + Sync {
+    /// Returns `true` if the message was observed on the DA layer after the `da_height`,
+    /// meaning that it is not relayed to the chain yet.
+    fn message_is_pending_relay(
+        &self,
+        nonce: &Nonce,
+        da_height: DaBlockHeight,
+    ) -> StorageResult<bool>;
+
+    /// Returns the latest finalized DA height synced by the relayer,
+    /// or `None` if it is unknown.
+    fn finalized_da_height(&self) -> StorageResult<Option<DaBlockHeight>>;
+}
+
 /// Trait that specifies all the getters required for blocks.
 pub trait DatabaseBlocks {
     /// Get a transaction by its id.
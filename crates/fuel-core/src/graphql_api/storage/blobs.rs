@@ -0,0 +1,120 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageMutate,
+};
+use fuel_core_types::fuel_tx::BlobId;
+
+/// The table that stores the number of transactions referencing the blob.
+/// The blob is safe to drop when it has no entry in this table.
+pub struct BlobRefCount;
+
+impl Mappable for BlobRefCount {
+    type Key = BlobId;
+    type OwnedKey = Self::Key;
+    type Value = u32;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for BlobRefCount {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::BlobRefCount
+    }
+}
+
+/// Increments the number of references to the blob and returns the new count.
+pub fn increment_blob_ref_count<S>(
+    storage: &mut S,
+    blob_id: &BlobId,
+) -> StorageResult<u32>
+where
+    S: StorageMutate<BlobRefCount, Error = StorageError>,
+{
+    let count = storage
+        .storage_as_mut::<BlobRefCount>()
+        .get(blob_id)?
+        .map(|count| count.into_owned())
+        .unwrap_or_default()
+        .saturating_add(1);
+    storage
+        .storage_as_mut::<BlobRefCount>()
+        .insert(blob_id, &count)?;
+    Ok(count)
+}
+
+/// Decrements the number of references to the blob and returns the new count.
+/// The entry is removed when the count drops to zero.
+pub fn decrement_blob_ref_count<S>(
+    storage: &mut S,
+    blob_id: &BlobId,
+) -> StorageResult<u32>
+where
+    S: StorageMutate<BlobRefCount, Error = StorageError>,
+{
+    let count = storage
+        .storage_as_mut::<BlobRefCount>()
+        .get(blob_id)?
+        .map(|count| count.into_owned())
+        .unwrap_or_default()
+        .saturating_sub(1);
+    if count == 0 {
+        storage.storage_as_mut::<BlobRefCount>().remove(blob_id)?;
+    } else {
+        storage
+            .storage_as_mut::<BlobRefCount>()
+            .insert(blob_id, &count)?;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        structured_storage::test::InMemoryStorage,
+        transactional::WriteTransaction,
+        StorageAsRef,
+    };
+
+    fuel_core_storage::basic_storage_tests!(
+        BlobRefCount,
+        <BlobRefCount as Mappable>::Key::default(),
+        <BlobRefCount as Mappable>::Value::default()
+    );
+
+    #[test]
+    fn blob_ref_count_round_trip() {
+        // Given
+        let mut storage = InMemoryStorage::default();
+        let mut storage_transaction = storage.write_transaction();
+        let blob_id = BlobId::from([1u8; 32]);
+
+        // When
+        increment_blob_ref_count(&mut storage_transaction, &blob_id).unwrap();
+        let incremented =
+            increment_blob_ref_count(&mut storage_transaction, &blob_id).unwrap();
+        let decremented =
+            decrement_blob_ref_count(&mut storage_transaction, &blob_id).unwrap();
+        let last = decrement_blob_ref_count(&mut storage_transaction, &blob_id).unwrap();
+
+        // Then
+        assert_eq!(incremented, 2);
+        assert_eq!(decremented, 1);
+        assert_eq!(last, 0);
+        assert!(!storage_transaction
+            .storage::<BlobRefCount>()
+            .contains_key(&blob_id)
+            .unwrap());
+    }
+}
@@ -10,7 +10,10 @@ use fuel_core_storage::{
 use fuel_core_types::{
     entities::coins::coin::Coin,
     fuel_tx::UtxoId,
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        AssetId,
+    },
 };
 use futures::{
     Stream,
@@ -63,4 +66,19 @@ impl ReadView {
             })
             .try_flatten()
     }
+
+    /// Returns the coins of the `owner` with the `asset_id`.
+    ///
+    /// It iterates over the owned coins of the `owner` using the prefix of the owner
+    /// and skips coins of other assets.
+    pub fn owned_coins_of_asset<'a>(
+        &'a self,
+        owner: &Address,
+        asset_id: &'a AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<Coin>> + 'a {
+        self.owned_coins(owner, start_coin, direction)
+            .try_filter(move |coin| futures::future::ready(coin.asset_id == *asset_id))
+    }
 }
@@ -14,7 +14,10 @@ use fuel_core_storage::{
     StorageAsRef,
 };
 use fuel_core_types::{
-    blockchain::block::CompressedBlock,
+    blockchain::{
+        block::CompressedBlock,
+        primitives::DaBlockHeight,
+    },
     entities::relayer::message::{
         MerkleProof,
         Message,
@@ -23,7 +26,6 @@ use fuel_core_types::{
     },
     fuel_merkle::binary::in_memory::MerkleTree,
     fuel_tx::{
-        input::message::compute_message_id,
         Receipt,
         TxId,
     },
@@ -42,7 +44,11 @@ use futures::{
     TryStreamExt,
 };
 use itertools::Itertools;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    sync::Arc,
+    time::Duration,
+};
 
 #[cfg(test)]
 mod test;
@@ -71,6 +77,48 @@ pub trait MessageQueryData: Send + Sync {
     ) -> BoxedIter<StorageResult<Message>>;
 }
 
+/// A pagination cursor of the messages that keeps the position and the direction
+/// of the iteration together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageCursor {
+    pub nonce: Nonce,
+    pub direction: IterDirection,
+}
+
+impl MessageCursor {
+    const FORWARD: &'static str = "forward";
+    const REVERSE: &'static str = "reverse";
+
+    pub fn new(nonce: Nonce, direction: IterDirection) -> Self {
+        Self { nonce, direction }
+    }
+
+    /// Encodes the cursor as `<direction>:<nonce>`.
+    pub fn encode(&self) -> String {
+        let direction = match self.direction {
+            IterDirection::Forward => Self::FORWARD,
+            IterDirection::Reverse => Self::REVERSE,
+        };
+        format!("{direction}:{:#x}", self.nonce)
+    }
+
+    /// Decodes the cursor encoded by [`MessageCursor::encode`].
+    pub fn decode(cursor: &str) -> anyhow::Result<Self> {
+        let (direction, nonce) = cursor
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Invalid message cursor: {cursor}"))?;
+        let direction = match direction {
+            Self::FORWARD => IterDirection::Forward,
+            Self::REVERSE => IterDirection::Reverse,
+            _ => anyhow::bail!("Invalid direction of the message cursor: {direction}"),
+        };
+        let nonce = nonce.parse().map_err(|err| {
+            anyhow::anyhow!("Invalid nonce of the message cursor: {err}")
+        })?;
+        Ok(Self { nonce, direction })
+    }
+}
+
 impl ReadView {
     pub fn message(&self, id: &Nonce) -> StorageResult<Message> {
         self.on_chain
@@ -212,13 +260,11 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
         None => return Ok(None),
     };
 
-    let message_id = compute_message_id(&sender, &recipient, &nonce, amount, &data);
-
-    let message_proof =
-        match message_receipts_proof(database, message_id, &message_block_txs)? {
-            Some(proof) => proof,
-            None => return Ok(None),
-        };
+    let leaves = message_outbox_leaves(database, &message_block_txs)?;
+    let message_proof = match message_receipts_proof(&nonce, &leaves) {
+        Some(proof) => proof,
+        None => return Ok(None),
+    };
 
     // Get the commit fuel block header.
     let commit_block_header = match database
@@ -254,52 +300,162 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     }))
 }
 
-fn message_receipts_proof<T: MessageProofData + ?Sized>(
+/// Generates an output proof like [`message_proof`], but fails if the database
+/// doesn't provide the data within the `timeout`.
+///
+/// The proof is generated on the blocking thread pool. The generation is not
+/// interrupted by the timeout, its result is discarded.
+pub async fn message_proof_with_timeout<T>(
+    database: Arc<T>,
+    transaction_id: Bytes32,
+    desired_nonce: Nonce,
+    commit_block_height: BlockHeight,
+    timeout: Duration,
+) -> StorageResult<Option<MessageProof>>
+where
+    T: MessageProofData + Send + Sync + ?Sized + 'static,
+{
+    let proof = tokio::task::spawn_blocking(move || {
+        message_proof(
+            database.as_ref(),
+            transaction_id,
+            desired_nonce,
+            commit_block_height,
+        )
+    });
+
+    match tokio::time::timeout(timeout, proof).await {
+        Ok(result) => result.map_err(|e| {
+            StorageError::from(anyhow::anyhow!(
+                "The message proof generation failed: {e}"
+            ))
+        })?,
+        Err(_) => Err(anyhow::anyhow!(
+            "The message proof wasn't generated within {timeout:?}"
+        )
+        .into()),
+    }
+}
+
+/// Verifies the message proof locally, without access to the database.
+///
+/// Checks that the message is included in the outbox of the message block, and that
+/// the message block is a part of the history committed by the commit block.
+pub fn verify_message_proof(proof: &MessageProof) -> StorageResult<bool> {
+    let message_block_header = &proof.message_block_header;
+    let application = message_block_header.application();
+
+    // The outbox root is trusted only if it is committed by the block id.
+    if message_block_header.application_hash() != &application.hash() {
+        return Ok(false)
+    }
+
+    let message_id = proof.message_id();
+    if !verify_merkle_proof(
+        &application.message_outbox_root,
+        &message_id,
+        &proof.message_proof,
+        u64::from(application.message_receipt_count),
+    ) {
+        return Ok(false)
+    }
+
+    // The `prev_root` of the commit block is the root of all blocks before it.
+    let commit_block_header = &proof.commit_block_header;
+    let blocks_count = u64::from(u32::from(*commit_block_header.height()));
+    let message_block_id = message_block_header.hash();
+
+    Ok(verify_merkle_proof(
+        commit_block_header.prev_root(),
+        &message_block_id,
+        &proof.block_proof,
+        blocks_count,
+    ))
+}
+
+/// Returns `true` if the commit block of the proof is at most `max_age_blocks`
+/// blocks behind the `current_height`.
+pub fn is_proof_still_valid(
+    proof: &MessageProof,
+    current_height: BlockHeight,
+    max_age_blocks: u32,
+) -> bool {
+    let commit_height = u32::from(*proof.commit_block_header.height());
+    u32::from(current_height).saturating_sub(commit_height) <= max_age_blocks
+}
+
+fn verify_merkle_proof<D: AsRef<[u8]>>(
+    root: &Bytes32,
+    data: &D,
+    proof: &MerkleProof,
+    leaves_count: u64,
+) -> bool {
+    fuel_core_types::fuel_merkle::binary::verify(
+        root,
+        data,
+        &proof.proof_set,
+        proof.proof_index,
+        leaves_count,
+    )
+}
+
+/// Returns the nonces and ids of the messages sent in the block,
+/// in the order of the leaves of the message outbox tree.
+fn message_outbox_leaves<T: MessageProofData + ?Sized>(
     database: &T,
-    message_id: MessageId,
     message_block_txs: &[Bytes32],
-) -> StorageResult<Option<MerkleProof>> {
+) -> StorageResult<Vec<(Nonce, MessageId)>> {
     // Get the message receipts from the block.
-    let leaves: Vec<Vec<Receipt>> = message_block_txs
+    let receipts: Vec<Vec<Receipt>> = message_block_txs
         .iter()
         .map(|id| database.receipts(id))
         .filter_map(|result| result.into_api_result::<_, StorageError>().transpose())
         .try_collect()?;
-    let leaves = leaves.into_iter()
+    let leaves = receipts
+        .into_iter()
         // Flatten the receipts after filtering on output messages
         // and mapping to message ids.
-        .flat_map(|receipts|
-            receipts.into_iter().filter_map(|r| r.message_id()));
-
-    // Build the merkle proof from the above iterator.
-    let mut tree = MerkleTree::new();
-
-    let mut proof_index = None;
+        .flatten()
+        .filter_map(|r| Some((*r.nonce()?, r.message_id()?)))
+        .collect();
+    Ok(leaves)
+}
 
-    for (index, id) in leaves.enumerate() {
-        // Check if this is the message id being proved.
-        if message_id == id {
-            // Save the index of this message to use as the proof index.
-            proof_index = Some(index as u64);
-        }
+fn message_receipts_proof(
+    nonce: &Nonce,
+    leaves: &[(Nonce, MessageId)],
+) -> Option<MerkleProof> {
+    // The index of the message being proved.
+    let proof_index = leaves
+        .iter()
+        .position(|(leaf_nonce, _)| leaf_nonce == nonce)? as u64;
 
-        // Build the merkle tree.
+    // Build the merkle tree.
+    let mut tree = MerkleTree::new();
+    for (_, id) in leaves {
         tree.push(id.as_ref());
     }
 
-    // If we found the leaf proof index then return the proof.
-    match proof_index {
-        Some(proof_index) => {
-            // Generate the actual merkle proof.
-            match tree.prove(proof_index) {
-                Some((_, proof_set)) => Ok(Some(MerkleProof {
-                    proof_set,
-                    proof_index,
-                })),
-                None => Ok(None),
-            }
+    // Generate the actual merkle proof.
+    let (_, proof_set) = tree.prove(proof_index)?;
+    Some(MerkleProof {
+        proof_set,
+        proof_index,
+    })
+}
+
+/// The unspent message is unfinalized if it was emitted by a DA block
+/// beyond the latest finalized one. If the finalized DA height is unknown,
+/// the message is considered finalized.
+fn unspent_message_status(
+    da_height: DaBlockHeight,
+    finalized_da_height: Option<DaBlockHeight>,
+) -> MessageStatus {
+    match finalized_da_height {
+        Some(finalized_da_height) if da_height > finalized_da_height => {
+            MessageStatus::unspent_unfinalized()
         }
-        None => Ok(None),
+        _ => MessageStatus::unspent(),
     }
 }
 
@@ -310,7 +466,11 @@ pub fn message_status(
     if database.message_is_spent(&message_nonce)? {
         Ok(MessageStatus::spent())
     } else if database.message_exists(&message_nonce)? {
-        Ok(MessageStatus::unspent())
+        let da_height = database.message(&message_nonce)?.da_height();
+        let finalized_da_height = database.finalized_da_height()?;
+        Ok(unspent_message_status(da_height, finalized_da_height))
+    } else if database.message_is_pending_relay(&message_nonce)? {
+        Ok(MessageStatus::relay_pending())
     } else {
         Ok(MessageStatus::not_found())
     }
@@ -3,10 +3,14 @@ use std::ops::Deref;
 use fuel_core_types::{
     blockchain::header::{
         ApplicationHeader,
+        BlockHeader,
         ConsensusHeader,
         PartialBlockHeader,
     },
-    entities::relayer::message::MerkleProof,
+    entities::relayer::message::{
+        MerkleProof,
+        MessageState,
+    },
     fuel_tx::{
         AssetId,
         ContractId,
@@ -207,3 +211,284 @@ async fn can_build_message_proof() {
     );
     assert_eq!(proof.block_proof, block_proof);
 }
+
+#[test]
+fn message_receipts_proof_matches_proof_of_outbox_tree() {
+    // Given
+    let leaves: Vec<(Nonce, MessageId)> = [
+        receipt(Some(1)),
+        receipt(None),
+        receipt(Some(2)),
+        receipt(Some(3)),
+    ]
+    .iter()
+    .filter_map(|r| Some((*r.nonce()?, r.message_id()?)))
+    .collect();
+    let mut tree = MerkleTree::new();
+    for (_, id) in &leaves {
+        tree.push(id.as_ref());
+    }
+
+    for (index, (nonce, _)) in leaves.iter().enumerate() {
+        // When
+        let proof = message_receipts_proof(nonce, &leaves);
+
+        // Then
+        let proof_index = index as u64;
+        let (_, proof_set) = tree.prove(proof_index).unwrap();
+        assert_eq!(
+            proof,
+            Some(MerkleProof {
+                proof_set,
+                proof_index,
+            })
+        );
+    }
+    assert_eq!(message_receipts_proof(&Nonce::new([9; 32]), &leaves), None);
+}
+
+fn block_header(
+    height: u32,
+    prev_root: Bytes32,
+    message_ids: &[MessageId],
+) -> BlockHeader {
+    PartialBlockHeader {
+        application: ApplicationHeader {
+            da_height: 0u64.into(),
+            consensus_parameters_version: Default::default(),
+            state_transition_bytecode_version: Default::default(),
+            generated: Default::default(),
+        },
+        consensus: ConsensusHeader {
+            prev_root,
+            height: height.into(),
+            time: Tai64::UNIX_EPOCH,
+            generated: Default::default(),
+        },
+    }
+    .generate(&[], message_ids, Default::default())
+    .unwrap()
+}
+
+#[tokio::test]
+async fn message_proof_with_timeout_fails_if_database_is_too_slow() {
+    // Given
+    let mut data = MockProofDataStorage::new();
+    data.expect_receipts().returning(|_| {
+        std::thread::sleep(Duration::from_millis(500));
+        Ok(vec![])
+    });
+
+    // When
+    let result = message_proof_with_timeout(
+        Arc::new(data),
+        txn_id(1),
+        Nonce::default(),
+        1u32.into(),
+        Duration::from_millis(10),
+    )
+    .await;
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn message_proof_with_timeout_returns_result_within_timeout() {
+    // Given
+    let mut data = MockProofDataStorage::new();
+    data.expect_receipts().returning(|_| Ok(vec![]));
+
+    // When
+    let result = message_proof_with_timeout(
+        Arc::new(data),
+        txn_id(1),
+        Nonce::default(),
+        1u32.into(),
+        Duration::from_secs(10),
+    )
+    .await;
+
+    // Then
+    assert!(result.unwrap().is_none());
+}
+
+fn valid_message_proof() -> MessageProof {
+    let sender = Address::new([1; 32]);
+    let recipient = Address::new([2; 32]);
+    let nonce = Nonce::new([3; 32]);
+    let amount = 100;
+    let data = vec![4; 8];
+    let message_id = compute_message_id(&sender, &recipient, &nonce, amount, &data);
+
+    let message_ids = [MessageId::new([5; 32]), message_id, MessageId::new([6; 32])];
+    let mut message_tree = MerkleTree::new();
+    for id in message_ids.iter() {
+        message_tree.push(id.as_ref());
+    }
+    let (_, message_proof_set) = message_tree.prove(1).unwrap();
+
+    let genesis_header = block_header(0, Bytes32::zeroed(), &[]);
+    let message_block_header = block_header(1, Bytes32::zeroed(), &message_ids);
+    let mut block_tree = MerkleTree::new();
+    block_tree.push(genesis_header.id().as_ref());
+    block_tree.push(message_block_header.id().as_ref());
+    let (prev_root, block_proof_set) = block_tree.prove(1).unwrap();
+    let commit_block_header = block_header(2, prev_root.into(), &[]);
+
+    MessageProof {
+        message_proof: MerkleProof {
+            proof_set: message_proof_set,
+            proof_index: 1,
+        },
+        block_proof: MerkleProof {
+            proof_set: block_proof_set,
+            proof_index: 1,
+        },
+        message_block_header,
+        commit_block_header,
+        sender,
+        recipient,
+        nonce,
+        amount,
+        data,
+    }
+}
+
+#[test]
+fn verify_message_proof_accepts_valid_proof() {
+    // Given
+    let proof = valid_message_proof();
+
+    // When
+    let result = verify_message_proof(&proof);
+
+    // Then
+    assert!(result.unwrap());
+}
+
+#[test]
+fn verify_message_proof_rejects_tampered_message() {
+    // Given
+    let mut proof = valid_message_proof();
+    proof.amount = 101;
+
+    // When
+    let result = verify_message_proof(&proof);
+
+    // Then
+    assert!(!result.unwrap());
+}
+
+#[test]
+fn verify_message_proof_rejects_tampered_block_proof() {
+    // Given
+    let mut proof = valid_message_proof();
+    proof.block_proof.proof_index = 0;
+
+    // When
+    let result = verify_message_proof(&proof);
+
+    // Then
+    assert!(!result.unwrap());
+}
+
+#[test]
+fn is_proof_still_valid_accepts_proof_at_max_age() {
+    // Given
+    let proof = valid_message_proof();
+    let commit_height = u32::from(*proof.commit_block_header.height());
+
+    // When
+    let result =
+        is_proof_still_valid(&proof, commit_height.saturating_add(10).into(), 10);
+
+    // Then
+    assert!(result);
+}
+
+#[test]
+fn is_proof_still_valid_rejects_proof_one_block_past_max_age() {
+    // Given
+    let proof = valid_message_proof();
+    let commit_height = u32::from(*proof.commit_block_header.height());
+
+    // When
+    let result =
+        is_proof_still_valid(&proof, commit_height.saturating_add(11).into(), 10);
+
+    // Then
+    assert!(!result);
+}
+
+#[test]
+fn unspent_message_status_is_unspent_when_da_height_is_finalized() {
+    // Given
+    let finalized_da_height = Some(DaBlockHeight(10));
+
+    // When
+    let status = unspent_message_status(DaBlockHeight(10), finalized_da_height);
+
+    // Then
+    assert!(matches!(status.state, MessageState::Unspent));
+}
+
+#[test]
+fn unspent_message_status_is_unfinalized_when_da_height_is_beyond_finalized() {
+    // Given
+    let finalized_da_height = Some(DaBlockHeight(10));
+
+    // When
+    let status = unspent_message_status(DaBlockHeight(11), finalized_da_height);
+
+    // Then
+    assert!(matches!(status.state, MessageState::UnspentUnfinalized));
+}
+
+#[test]
+fn unspent_message_status_is_unspent_when_finalized_da_height_is_unknown() {
+    // Given
+    let finalized_da_height = None;
+
+    // When
+    let status = unspent_message_status(DaBlockHeight(11), finalized_da_height);
+
+    // Then
+    assert!(matches!(status.state, MessageState::Unspent));
+}
+
+#[test]
+fn message_cursor_round_trips_forward_direction() {
+    // Given
+    let cursor = MessageCursor::new(Nonce::new([7; 32]), IterDirection::Forward);
+
+    // When
+    let decoded = MessageCursor::decode(&cursor.encode()).unwrap();
+
+    // Then
+    assert_eq!(decoded, cursor);
+}
+
+#[test]
+fn message_cursor_round_trips_reverse_direction() {
+    // Given
+    let cursor = MessageCursor::new(Nonce::new([7; 32]), IterDirection::Reverse);
+
+    // When
+    let decoded = MessageCursor::decode(&cursor.encode()).unwrap();
+
+    // Then
+    assert_eq!(decoded, cursor);
+}
+
+#[test]
+fn message_cursor_decode_fails_for_unknown_direction() {
+    // Given
+    let encoded = format!("sideways:{:#x}", Nonce::new([7; 32]));
+
+    // When
+    let result = MessageCursor::decode(&encoded);
+
+    // Then
+    assert!(result.is_err());
+}
@@ -1,4 +1,7 @@
-use crate::schema::tx::types::TransactionStatus as ApiTxStatus;
+use crate::schema::tx::types::{
+    ReplacedStatus,
+    TransactionStatus as ApiTxStatus,
+};
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::TxStatusMessage;
 use fuel_core_types::{
@@ -64,6 +67,9 @@ where
                     let status = ApiTxStatus::new(transaction_id, status);
                     Ok(status)
                 },
+                TxStatusMessage::Replaced { by } => {
+                    Ok(ApiTxStatus::Replaced(ReplacedStatus { by }))
+                }
                 // Map a failed status to an error for the api.
                 TxStatusMessage::FailedStatus => {
                     Err(anyhow::anyhow!("Failed to get transaction status"))
@@ -105,6 +105,9 @@ enum FinalTxStatus {
     Squeezed,
     /// The transaction failed to execute and was included in a block.
     Failed,
+    /// The transaction was replaced in the txpool by another transaction
+    /// spending the same inputs.
+    Replaced,
 }
 
 /// Strategy to generate an Option<TransactionStatus>
@@ -121,6 +124,7 @@ fn state_result() -> impl Strategy<Value = Result<Option<TransactionStatus>, Err
 fn tx_status_message() -> impl Strategy<Value = TxStatusMessage> {
     prop_oneof![
         Just(TxStatusMessage::FailedStatus),
+        Just(TxStatusMessage::Replaced { by: txn_id(1) }),
         transaction_status().prop_map(TxStatusMessage::Status),
     ]
 }
@@ -187,6 +191,11 @@ fn transaction_status_change_model(
                     ControlFlow::Break(out)
                 }
             },
+            // In case of a replacement, push the final status to the output vector and break
+            TxStatusMessage::Replaced { .. } => {
+                out.push(Ok(TxStatus::Final(FinalTxStatus::Replaced)));
+                ControlFlow::Break(out)
+            }
             // In case of a failed status, push the error to the output vector and break
             TxStatusMessage::FailedStatus => {
                 out.push(Err(Error));
@@ -281,6 +290,9 @@ impl From<crate::schema::tx::types::TransactionStatus> for TxStatus {
             crate::schema::tx::types::TransactionStatus::Failed(_) => {
                 TxStatus::Final(FinalTxStatus::Failed)
             }
+            crate::schema::tx::types::TransactionStatus::Replaced(_) => {
+                TxStatus::Final(FinalTxStatus::Replaced)
+            }
         }
     }
 }
@@ -65,6 +65,7 @@ pub struct Query(
     node_info::NodeQuery,
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
+    gas_price::EstimatedFeeQuery,
     message::MessageQuery,
     relayed_tx::RelayedTransactionQuery,
     upgrades::UpgradeQuery,
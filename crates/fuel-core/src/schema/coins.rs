@@ -39,6 +39,7 @@ use fuel_core_types::{
     },
     fuel_tx,
 };
+use futures::future::Either;
 use itertools::Itertools;
 use tokio_stream::StreamExt;
 
@@ -177,19 +178,17 @@ impl CoinQuery {
         let query = ctx.read_view()?;
         let owner: fuel_tx::Address = filter.owner.into();
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let coins = query
-                .owned_coins(&owner, (*start).map(Into::into), direction)
-                .filter_map(|result| {
-                    if let (Ok(coin), Some(filter_asset_id)) = (&result, &filter.asset_id)
-                    {
-                        if coin.asset_id != filter_asset_id.0 {
-                            return None
-                        }
-                    }
-
-                    Some(result)
-                })
-                .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
+            let start = (*start).map(Into::into);
+            let coins = match &filter.asset_id {
+                Some(asset_id) => Either::Left(query.owned_coins_of_asset(
+                    &owner,
+                    &asset_id.0,
+                    start,
+                    direction,
+                )),
+                None => Either::Right(query.owned_coins(&owner, start, direction)),
+            }
+            .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
 
             Ok(coins)
         })
@@ -1,10 +1,15 @@
 use super::scalars::{
+    HexString,
     U32,
     U64,
 };
 use crate::{
     graphql_api::{
-        api_service::GasPriceProvider,
+        api_service::{
+            BlockProducer,
+            ConsensusProvider,
+            GasPriceProvider,
+        },
         query_costs,
     },
     schema::ReadViewProvider,
@@ -17,8 +22,12 @@ use fuel_core_types::{
     blockchain::block::Block,
     fuel_tx::{
         field::MintGasPrice,
+        Cacheable,
+        ConsensusParameters,
         Transaction,
+        TransactionFee,
     },
+    fuel_types::canonical::Deserialize,
 };
 
 pub struct LatestGasPrice {
@@ -111,3 +120,111 @@ impl EstimateGasPriceQuery {
         })
     }
 }
+
+pub struct EstimatedFee {
+    pub min_fee: U64,
+    pub max_fee: U64,
+    pub gas_used: U64,
+}
+
+#[Object]
+impl EstimatedFee {
+    async fn min_fee(&self) -> U64 {
+        self.min_fee
+    }
+
+    async fn max_fee(&self) -> U64 {
+        self.max_fee
+    }
+
+    async fn gas_used(&self) -> U64 {
+        self.gas_used
+    }
+}
+
+#[derive(Default)]
+pub struct EstimatedFeeQuery {}
+
+#[Object]
+impl EstimatedFeeQuery {
+    /// Estimate the fee of the transaction for the next block, using a dry-run
+    /// of the transaction to get the gas used.
+    #[graphql(complexity = "query_costs().dry_run + 2 * query_costs().storage_read")]
+    async fn estimated_fee(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The encoded transaction to estimate the fee for")]
+        encoded_transaction: HexString,
+    ) -> async_graphql::Result<EstimatedFee> {
+        let query = ctx.read_view()?;
+
+        let latest_block_height: u32 = query.latest_block_height()?.into();
+        let next_block =
+            latest_block_height
+                .checked_add(1)
+                .ok_or(async_graphql::Error::new(format!(
+                    "Next block overflows latest block :{latest_block_height:?}"
+                )))?;
+
+        let gas_price_provider = ctx.data_unchecked::<GasPriceProvider>();
+        let gas_price = gas_price_provider
+            .worst_case_gas_price(next_block.into())
+            .await
+            .ok_or(async_graphql::Error::new(format!(
+                "Failed to estimate gas price for block, algorithm not yet set: {next_block:?}"
+            )))?;
+
+        let consensus_params = ctx
+            .data_unchecked::<ConsensusProvider>()
+            .latest_consensus_params();
+        let mut tx = Transaction::from_bytes(&encoded_transaction.0)?;
+        tx.precompute(&consensus_params.chain_id())?;
+
+        let fee = transaction_fee(&tx, &consensus_params, gas_price).ok_or(
+            async_graphql::Error::new("Failed to calculate the fee of the transaction"),
+        )?;
+
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let tx_status = block_producer
+            .dry_run_txs(vec![tx], None, None, None, Some(gas_price))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(async_graphql::Error::new(
+                "The dry-run didn't return the status of the transaction",
+            ))?;
+
+        Ok(EstimatedFee {
+            min_fee: fee.min_fee().into(),
+            max_fee: fee.max_fee().into(),
+            gas_used: (*tx_status.result.total_gas()).into(),
+        })
+    }
+}
+
+fn transaction_fee(
+    tx: &Transaction,
+    consensus_params: &ConsensusParameters,
+    gas_price: u64,
+) -> Option<TransactionFee> {
+    let gas_costs = consensus_params.gas_costs();
+    let fee_params = consensus_params.fee_params();
+    match tx {
+        Transaction::Script(tx) => {
+            TransactionFee::checked_from_tx(gas_costs, fee_params, tx, gas_price)
+        }
+        Transaction::Create(tx) => {
+            TransactionFee::checked_from_tx(gas_costs, fee_params, tx, gas_price)
+        }
+        Transaction::Upgrade(tx) => {
+            TransactionFee::checked_from_tx(gas_costs, fee_params, tx, gas_price)
+        }
+        Transaction::Upload(tx) => {
+            TransactionFee::checked_from_tx(gas_costs, fee_params, tx, gas_price)
+        }
+        Transaction::Blob(tx) => {
+            TransactionFee::checked_from_tx(gas_costs, fee_params, tx, gas_price)
+        }
+        Transaction::Mint(_) => None,
+    }
+}
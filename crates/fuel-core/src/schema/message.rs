@@ -11,7 +11,10 @@ use super::{
     ReadViewProvider,
 };
 use crate::{
-    fuel_core_graphql_api::query_costs,
+    fuel_core_graphql_api::{
+        query_costs,
+        Config as GraphQLConfig,
+    },
     graphql_api::IntoApiResult,
     schema::scalars::{
         BlockId,
@@ -150,13 +153,28 @@ impl MessageQuery {
             ))?,
         };
 
-        Ok(crate::query::message_proof(
+        let proof = crate::query::message_proof(
             query.as_ref(),
             transaction_id.into(),
             nonce.into(),
             height,
-        )?
-        .map(MessageProof))
+        )?;
+
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if let (Some(proof), Some(max_age_blocks)) =
+            (&proof, config.config.max_message_proof_age_blocks)
+        {
+            let current_height = query.latest_block_height()?;
+            if !crate::query::is_proof_still_valid(proof, current_height, max_age_blocks)
+            {
+                return Err(anyhow!(
+                    "The commit block is more than {max_age_blocks} blocks behind the latest block"
+                )
+                .into())
+            }
+        }
+
+        Ok(proof.map(MessageProof))
     }
 
     #[graphql(complexity = "query_costs().storage_read + child_complexity")]
@@ -246,8 +264,10 @@ pub struct MessageStatus(pub(crate) entities::relayer::message::MessageStatus);
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
 enum MessageState {
     Unspent,
+    UnspentUnfinalized,
     Spent,
     NotFound,
+    RelayPending,
 }
 
 #[Object]
@@ -255,8 +275,14 @@ impl MessageStatus {
     async fn state(&self) -> MessageState {
         match self.0.state {
             entities::relayer::message::MessageState::Unspent => MessageState::Unspent,
+            entities::relayer::message::MessageState::UnspentUnfinalized => {
+                MessageState::UnspentUnfinalized
+            }
             entities::relayer::message::MessageState::Spent => MessageState::Spent,
             entities::relayer::message::MessageState::NotFound => MessageState::NotFound,
+            entities::relayer::message::MessageState::RelayPending => {
+                MessageState::RelayPending
+            }
         }
     }
 }
@@ -36,6 +36,7 @@ use async_graphql::{
         EmptyFields,
     },
     Context,
+    ErrorExtensions,
     Object,
     Subscription,
 };
@@ -73,6 +74,7 @@ use std::{
 };
 use types::{
     DryRunTransactionExecutionStatus,
+    ReplacedStatus,
     Transaction,
 };
 
@@ -346,10 +348,7 @@ impl TxMutation {
             .latest_consensus_params();
         let tx = FuelTx::from_bytes(&tx.0)?;
 
-        txpool
-            .insert(tx.clone())
-            .await
-            .map_err(|e| anyhow::anyhow!(e))?;
+        txpool.insert(tx.clone()).await.map_err(into_submit_error)?;
         let id = tx.id(&params.chain_id());
 
         let tx = Transaction(tx, id);
@@ -425,6 +424,29 @@ impl TxStatusSubscription {
     }
 }
 
+/// Converts the error of the transaction insertion into the GraphQL error.
+/// If the pool is overloaded, the retry hint is exposed as the `retryAfterMs` extension.
+fn into_submit_error(error: anyhow::Error) -> async_graphql::Error {
+    let retry_after = match error.downcast_ref::<fuel_core_txpool::error::Error>() {
+        Some(fuel_core_txpool::error::Error::PoolOverloaded { retry_after }) => {
+            Some(*retry_after)
+        }
+        _ => None,
+    };
+    let graphql_error = async_graphql::Error::new(error.to_string());
+
+    match retry_after {
+        Some(retry_after) => {
+            let retry_after_ms =
+                u64::try_from(retry_after.as_millis()).unwrap_or(u64::MAX);
+            graphql_error.extend_with(|_, extensions| {
+                extensions.set("retryAfterMs", retry_after_ms)
+            })
+        }
+        None => graphql_error,
+    }
+}
+
 async fn submit_and_await_status<'a>(
     ctx: &'a Context<'a>,
     tx: HexString,
@@ -440,7 +462,7 @@ async fn submit_and_await_status<'a>(
     let tx_id = tx.id(&params.chain_id());
     let subscription = txpool.tx_update_subscribe(tx_id)?;
 
-    txpool.insert(tx).await?;
+    txpool.insert(tx).await.map_err(into_submit_error)?;
 
     Ok(subscription
         .map(move |event| match event {
@@ -448,6 +470,9 @@ async fn submit_and_await_status<'a>(
                 let status = TransactionStatus::new(tx_id, status);
                 Ok(status)
             }
+            TxStatusMessage::Replaced { by } => {
+                Ok(TransactionStatus::Replaced(ReplacedStatus { by }))
+            }
             TxStatusMessage::FailedStatus => {
                 Err(anyhow::anyhow!("Failed to get transaction status").into())
             }
@@ -144,6 +144,7 @@ pub enum TransactionStatus {
     Success(SuccessStatus),
     SqueezedOut(SqueezedOutStatus),
     Failed(FailureStatus),
+    Replaced(ReplacedStatus),
 }
 
 #[derive(Debug)]
@@ -284,6 +285,20 @@ impl SqueezedOutStatus {
     }
 }
 
+/// The transaction was removed from the pool because another transaction
+/// spending the same inputs with a higher tip replaced it.
+#[derive(Debug)]
+pub struct ReplacedStatus {
+    pub by: TxId,
+}
+
+#[Object]
+impl ReplacedStatus {
+    async fn by(&self) -> TransactionId {
+        self.by.into()
+    }
+}
+
 impl TransactionStatus {
     pub fn new(tx_id: TxId, tx_status: TxStatus) -> Self {
         match tx_status {
@@ -370,6 +385,9 @@ impl From<TransactionStatus> for TxStatus {
                 total_gas,
                 total_fee,
             },
+            TransactionStatus::Replaced(ReplacedStatus { by }) => TxStatus::SqueezedOut {
+                reason: format!("Transaction was replaced by {by}"),
+            },
         }
     }
 }
@@ -19,12 +19,12 @@ impl fuel_core_executor::ports::TransactionsSource for TransactionsSource {
     ) -> Vec<MaybeCheckedTransaction> {
         self.tx_pool
             .exclusive_lock()
-            .extract_transactions_for_block(Constraints {
-                minimal_gas_price: self.minimum_gas_price,
-                max_gas: gas_limit,
-                maximum_txs: transactions_limit,
-                maximum_block_size: block_transaction_size_limit,
-            })
+            .extract_transactions_for_block(
+                Constraints::new(gas_limit)
+                    .with_minimal_gas_price(self.minimum_gas_price)
+                    .with_max_txs(transactions_limit)
+                    .with_max_bytes(block_transaction_size_limit),
+            )
             .into_iter()
             .map(|tx| {
                 let transaction = Arc::unwrap_or_clone(tx);
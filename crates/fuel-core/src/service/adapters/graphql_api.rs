@@ -55,6 +55,7 @@ use std::{
 
 mod off_chain;
 mod on_chain;
+mod relayer;
 
 #[async_trait]
 impl TxPoolPort for TxPoolAdapter {
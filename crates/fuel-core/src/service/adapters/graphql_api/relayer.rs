@@ -0,0 +1,73 @@
+use crate::{
+    database::RelayerIterableKeyValueView,
+    fuel_core_graphql_api::ports::RelayerDatabase,
+};
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::Nonce,
+};
+
+impl RelayerDatabase for RelayerIterableKeyValueView {
+    #[cfg(feature = "relayer")]
+    fn message_is_pending_relay(
+        &self,
+        nonce: &Nonce,
+        da_height: DaBlockHeight,
+    ) -> StorageResult<bool> {
+        use fuel_core_relayer::storage::EventsHistory;
+        use fuel_core_storage::iter::{
+            IterDirection,
+            IteratorOverTable,
+        };
+        use fuel_core_types::services::relayer::Event;
+
+        // Events up to the `da_height` are already included into the chain.
+        let start = DaBlockHeight(da_height.0.saturating_add(1));
+        for result in self.iter_all_by_start::<EventsHistory>(
+            Some(&start),
+            Some(IterDirection::Forward),
+        ) {
+            let (_, events) = result?;
+            let is_pending = events.iter().any(|event| {
+                matches!(event, Event::Message(message) if message.nonce() == nonce)
+            });
+
+            if is_pending {
+                return Ok(true)
+            }
+        }
+
+        Ok(false)
+    }
+
+    #[cfg(feature = "relayer")]
+    fn finalized_da_height(&self) -> StorageResult<Option<DaBlockHeight>> {
+        use fuel_core_relayer::storage::EventsHistory;
+        use fuel_core_storage::iter::{
+            IterDirection,
+            IteratorOverTable,
+        };
+
+        // The relayer records the events of every synced DA height,
+        // and it only syncs finalized DA blocks.
+        self.iter_all_keys::<EventsHistory>(Some(IterDirection::Reverse))
+            .next()
+            .transpose()
+    }
+
+    #[cfg(not(feature = "relayer"))]
+    fn message_is_pending_relay(
+        &self,
+        _: &Nonce,
+        _: DaBlockHeight,
+    ) -> StorageResult<bool> {
+        // Without the relayer, messages can't be observed on the DA layer.
+        Ok(false)
+    }
+
+    #[cfg(not(feature = "relayer"))]
+    fn finalized_da_height(&self) -> StorageResult<Option<DaBlockHeight>> {
+        Ok(None)
+    }
+}
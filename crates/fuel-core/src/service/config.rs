@@ -149,6 +149,7 @@ impl Config {
                 request_body_bytes_limit: 16 * 1024 * 1024,
                 query_log_threshold_time: Duration::from_secs(2),
                 api_request_timeout: Duration::from_secs(60),
+                max_message_proof_age_blocks: None,
                 costs: Default::default(),
             },
             combined_db_config,
@@ -302,6 +302,7 @@ pub fn init_sub_services(
         schema,
         database.on_chain().clone(),
         database.off_chain().clone(),
+        database.relayer().clone(),
         Box::new(tx_pool_adapter),
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
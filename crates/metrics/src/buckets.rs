@@ -9,6 +9,8 @@ use strum_macros::EnumIter;
 #[cfg_attr(test, derive(EnumIter))]
 pub(crate) enum Buckets {
     Timing,
+    Fee,
+    TransactionsCount,
 }
 static BUCKETS: OnceLock<HashMap<Buckets, Vec<f64>>> = OnceLock::new();
 pub(crate) fn buckets(b: Buckets) -> impl Iterator<Item = f64> {
@@ -34,6 +36,38 @@ fn initialize_buckets() -> HashMap<Buckets, Vec<f64>> {
                10.000,
             ],
         ),
+        (
+            // Fees are denominated in the base asset units
+            Buckets::Fee,
+            vec![
+                          1.0,
+                         10.0,
+                        100.0,
+                      1_000.0,
+                     10_000.0,
+                    100_000.0,
+                  1_000_000.0,
+                 10_000_000.0,
+                100_000_000.0,
+              1_000_000_000.0,
+             10_000_000_000.0,
+            ],
+        ),
+        (
+            Buckets::TransactionsCount,
+            vec![
+                    1.0,
+                    5.0,
+                   10.0,
+                   50.0,
+                  100.0,
+                  500.0,
+                1_000.0,
+                5_000.0,
+               10_000.0,
+               50_000.0,
+            ],
+        ),
     ]
     .into_iter()
     .collect()
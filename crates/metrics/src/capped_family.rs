@@ -0,0 +1,144 @@
+//! The metric family with a limited number of label sets.
+//!
+//! Labels with unbounded values (like peers or user input) may produce
+//! too many series and overload Prometheus. [`CappedFamily`] keeps at most
+//! `max_series` label sets and folds the rest into the overflow label set.
+
+use prometheus_client::metrics::family::{
+    Family,
+    MetricConstructor,
+};
+use std::{
+    collections::HashSet,
+    hash::Hash,
+    ops::Deref,
+};
+
+/// The default maximum number of label sets per metric family.
+pub const DEFAULT_MAX_SERIES: usize = 1000;
+
+/// The label set that absorbs label values above the cardinality limit.
+pub trait OverflowLabel {
+    /// Returns the overflow label set.
+    fn overflow() -> Self;
+}
+
+/// The wrapper around [`Family`] that limits the number of distinct label sets.
+pub struct CappedFamily<S, M, C = fn() -> M> {
+    family: Family<S, M, C>,
+    max_series: usize,
+    series: parking_lot::Mutex<HashSet<S>>,
+}
+
+impl<S, M, C> CappedFamily<S, M, C> {
+    pub fn new(family: Family<S, M, C>, max_series: usize) -> Self {
+        Self {
+            family,
+            max_series,
+            series: Default::default(),
+        }
+    }
+
+    /// The underlying family, f.e. to register it in the registry.
+    pub fn family(&self) -> &Family<S, M, C> {
+        &self.family
+    }
+}
+
+impl<S, M, C> CappedFamily<S, M, C>
+where
+    S: OverflowLabel + Clone + Hash + Eq,
+    C: MetricConstructor<M>,
+{
+    /// Returns the metric of the label set, or the metric of the overflow label set
+    /// if the family already has `max_series` other label sets.
+    pub fn get_or_create(&self, label_set: &S) -> impl Deref<Target = M> + '_ {
+        let mut series = self.series.lock();
+        let label_set = if series.contains(label_set) {
+            label_set.clone()
+        } else if series.len() < self.max_series {
+            series.insert(label_set.clone());
+            label_set.clone()
+        } else {
+            S::overflow()
+        };
+        drop(series);
+        self.family.get_or_create(&label_set)
+    }
+
+    /// Returns the metric of the label set if it exists, without creating
+    /// the series or taking one of the `max_series` slots.
+    pub fn get(&self, label_set: &S) -> Option<impl Deref<Target = M> + '_> {
+        self.family.get(label_set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CappedFamily,
+        OverflowLabel,
+    };
+    use prometheus_client::{
+        encoding::EncodeLabelSet,
+        metrics::{
+            counter::Counter,
+            family::Family,
+        },
+    };
+
+    #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+    struct PeerLabel {
+        peer: String,
+    }
+
+    impl OverflowLabel for PeerLabel {
+        fn overflow() -> Self {
+            Self {
+                peer: "overflow".to_string(),
+            }
+        }
+    }
+
+    fn label(peer: &str) -> PeerLabel {
+        PeerLabel {
+            peer: peer.to_string(),
+        }
+    }
+
+    #[test]
+    fn get_or_create_folds_label_sets_above_cap_into_overflow() {
+        // Given
+        let family = CappedFamily::new(Family::<PeerLabel, Counter>::default(), 2);
+
+        // When
+        for peer in ["a", "b", "c", "d", "e", "a"] {
+            family.get_or_create(&label(peer)).inc();
+        }
+
+        // Then
+        let inner = family.family();
+        assert_eq!(inner.get_or_create(&label("a")).get(), 2);
+        assert_eq!(inner.get_or_create(&label("b")).get(), 1);
+        assert_eq!(inner.get_or_create(&PeerLabel::overflow()).get(), 3);
+        assert_eq!(inner.get_or_create(&label("c")).get(), 0);
+    }
+
+    #[test]
+    fn get_does_not_take_a_series_slot() {
+        // Given
+        let family = CappedFamily::new(Family::<PeerLabel, Counter>::default(), 1);
+
+        // When
+        let missing = family.get(&label("a")).map(|counter| counter.get());
+        family.get_or_create(&label("b")).inc();
+
+        // Then
+        assert_eq!(missing, None);
+        assert_eq!(
+            family.get(&label("b")).map(|counter| counter.get()),
+            Some(1)
+        );
+        assert!(family.get(&PeerLabel::overflow()).is_none());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::global_registry;
+use crate::{
+    chain_id,
+    global_registry,
+};
 use prometheus_client::metrics::counter::Counter;
 use std::collections::HashMap;
 
@@ -17,6 +20,8 @@ pub struct DatabaseMetrics {
 impl DatabaseMetrics {
     pub fn new(name: &str, columns: &[(u32, String)]) -> Self {
         let mut registry = global_registry().registry.lock();
+        let registry =
+            registry.sub_registry_with_label(("chain_id".into(), chain_id().into()));
 
         let columns_read_statistic = columns
             .iter()
@@ -96,3 +101,35 @@ impl DatabaseMetrics {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DatabaseMetrics;
+    use crate::{
+        encode_metrics,
+        set_chain_id,
+    };
+
+    #[test]
+    fn database_metrics_are_labeled_with_chain_id() {
+        // Given
+        set_chain_id(9889);
+
+        // When
+        let metrics = DatabaseMetrics::new("ChainIdTest", &[(0, "Coins".to_string())]);
+        metrics.write_meter.inc();
+
+        // Then
+        let encoded = encode_metrics().unwrap();
+        let writes = encoded
+            .lines()
+            .find(|line| line.starts_with("ChainIdTest_Database_Writes"))
+            .expect("The metric should be encoded");
+        assert!(writes.contains("chain_id=\"9889\""));
+        let column_reads = encoded
+            .lines()
+            .find(|line| line.starts_with("ChainIdTest_Column_Coins_Reads"))
+            .expect("The metric should be encoded");
+        assert!(column_reads.contains("chain_id=\"9889\""));
+    }
+}
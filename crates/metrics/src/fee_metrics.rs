@@ -0,0 +1,182 @@
+use crate::{
+    buckets::{
+        buckets,
+        Buckets,
+    },
+    global_registry,
+    labeled_histogram::LabeledHistogram,
+};
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        histogram::Histogram,
+    },
+};
+use std::sync::OnceLock;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct AssetLabel {
+    // the asset in which the fee is paid
+    pub asset_id: String,
+}
+
+pub struct FeeMetrics {
+    pub block_fee_revenue: Histogram,
+    pub tx_fee_distribution: Histogram,
+    pub cumulative_fees: Counter,
+    pub fee_histogram_by_asset: LabeledHistogram<AssetLabel>,
+}
+
+impl Default for FeeMetrics {
+    fn default() -> Self {
+        let block_fee_revenue = Histogram::new(buckets(Buckets::Fee));
+        let tx_fee_distribution = Histogram::new(buckets(Buckets::Fee));
+        let cumulative_fees = Counter::default();
+        let fee_histogram_by_asset = LabeledHistogram::new(buckets(Buckets::Fee));
+
+        let metrics = FeeMetrics {
+            block_fee_revenue,
+            tx_fee_distribution,
+            cumulative_fees,
+            fee_histogram_by_asset,
+        };
+
+        let mut registry = global_registry().registry.lock();
+        registry.register(
+            "fee_block_revenue",
+            "The total fee paid by transactions of a produced block",
+            metrics.block_fee_revenue.clone(),
+        );
+
+        registry.register(
+            "fee_tx_distribution",
+            "The fee paid by each transaction of a produced block",
+            metrics.tx_fee_distribution.clone(),
+        );
+
+        registry.register(
+            "fee_cumulative",
+            "The total fee paid by transactions of all produced blocks",
+            metrics.cumulative_fees.clone(),
+        );
+
+        registry.register(
+            "fee_histogram_by_asset",
+            "The fee paid by each transaction of a produced block, labeled by the asset",
+            metrics.fee_histogram_by_asset.family().clone(),
+        );
+
+        metrics
+    }
+}
+
+impl FeeMetrics {
+    /// Records the fees of the transactions included into one block.
+    pub fn record_block_fees<I>(&self, tx_fees: I)
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let mut block_fee = 0u64;
+        for tx_fee in tx_fees {
+            self.tx_fee_distribution.observe(tx_fee as f64);
+            block_fee = block_fee.saturating_add(tx_fee);
+        }
+        self.block_fee_revenue.observe(block_fee as f64);
+        self.cumulative_fees.inc_by(block_fee);
+    }
+
+    /// Records the fee of the transaction paid in the `asset_id`.
+    pub fn record_tx_fee_by_asset(&self, asset_id: &str, tx_fee: u64) {
+        self.fee_histogram_by_asset.observe(
+            &AssetLabel {
+                asset_id: asset_id.to_string(),
+            },
+            tx_fee as f64,
+        );
+    }
+}
+
+static FEE_METRICS: OnceLock<FeeMetrics> = OnceLock::new();
+pub fn fee_metrics() -> &'static FeeMetrics {
+    FEE_METRICS.get_or_init(FeeMetrics::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fee_metrics;
+    use crate::encode_metrics;
+
+    fn encoded_value(encoded: &str, name: &str) -> Option<u64> {
+        encoded.lines().find_map(|line| {
+            let (metric, value) = line.split_once(' ')?;
+            if metric == name {
+                value.parse().ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    #[test]
+    fn record_block_fees_updates_observation_counts_for_three_blocks() {
+        // Given
+        let blocks: [&[u64]; 3] = [&[10, 20], &[], &[1_000, 2_000, 3_000]];
+
+        // When
+        for tx_fees in blocks {
+            fee_metrics().record_block_fees(tx_fees.iter().copied());
+        }
+
+        // Then
+        let encoded = encode_metrics().unwrap();
+        assert_eq!(encoded_value(&encoded, "fee_block_revenue_count"), Some(3));
+        assert_eq!(
+            encoded_value(&encoded, "fee_tx_distribution_count"),
+            Some(5)
+        );
+        assert_eq!(encoded_value(&encoded, "fee_cumulative_total"), Some(6_030));
+    }
+
+    #[test]
+    fn record_tx_fee_by_asset_tracks_assets_independently() {
+        // Given
+        let fees = [("first", 5), ("first", 50), ("second", 5_000)];
+
+        // When
+        for (asset_id, tx_fee) in fees {
+            fee_metrics().record_tx_fee_by_asset(asset_id, tx_fee);
+        }
+
+        // Then
+        let encoded = encode_metrics().unwrap();
+        assert_eq!(
+            encoded_value(
+                &encoded,
+                r#"fee_histogram_by_asset_count{asset_id="first"}"#
+            ),
+            Some(2)
+        );
+        assert_eq!(
+            encoded_value(
+                &encoded,
+                r#"fee_histogram_by_asset_bucket{le="10.0",asset_id="first"}"#
+            ),
+            Some(1)
+        );
+        assert_eq!(
+            encoded_value(
+                &encoded,
+                r#"fee_histogram_by_asset_count{asset_id="second"}"#
+            ),
+            Some(1)
+        );
+        assert_eq!(
+            encoded_value(
+                &encoded,
+                r#"fee_histogram_by_asset_bucket{le="1000.0",asset_id="second"}"#
+            ),
+            Some(0)
+        );
+    }
+}
@@ -1,7 +1,16 @@
-use crate::global_registry;
+use crate::{
+    buckets::{
+        buckets,
+        Buckets,
+    },
+    global_registry,
+};
 use prometheus_client::{
     encoding::text::encode,
-    metrics::counter::Counter,
+    metrics::{
+        counter::Counter,
+        histogram::Histogram,
+    },
 };
 use std::ops::Deref;
 
@@ -9,7 +18,7 @@ pub mod future_tracker;
 pub mod metered_future;
 
 /// The statistic of the futures life cycle.
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct FuturesMetrics {
     /// The time spent for real actions by the future.
     ///
@@ -21,6 +30,20 @@ pub struct FuturesMetrics {
     /// Time is in nanoseconds.
     // TODO: Use `AtomicU128` when it is stable, otherwise, the field can overflow at some point.
     pub idle: Counter,
+    /// The time from the first poll of the future until its completion.
+    ///
+    /// Time is in seconds.
+    pub completion_time: Histogram,
+}
+
+impl Default for FuturesMetrics {
+    fn default() -> Self {
+        Self {
+            busy: Counter::default(),
+            idle: Counter::default(),
+            completion_time: Histogram::new(buckets(Buckets::Timing)),
+        }
+    }
 }
 
 impl FuturesMetrics {
@@ -57,6 +80,11 @@ impl FuturesMetrics {
             format!("The busy time of the {} future", futures_name),
             lifecycle.busy.clone(),
         );
+        lock.register(
+            format!("{}_completion_time_s", futures_name),
+            format!("The completion time of the {} future", futures_name),
+            lifecycle.completion_time.clone(),
+        );
 
         lifecycle
     }
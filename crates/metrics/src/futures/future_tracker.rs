@@ -33,6 +33,9 @@ impl<Output> ExecutionTime<Output> {
             u64::try_from(self.idle.as_nanos())
                 .expect("The task doesn't live longer than `u64`"),
         );
+        metric
+            .completion_time
+            .observe(self.busy.saturating_add(self.idle).as_secs_f64());
         self.output
     }
 }
@@ -152,7 +155,13 @@ impl<T: Future> Future for FutureTracker<T> {
 mod tests {
     use std::time::Duration;
 
-    use crate::futures::future_tracker::FutureTracker;
+    use crate::{
+        encode_metrics,
+        futures::{
+            future_tracker::FutureTracker,
+            FuturesMetrics,
+        },
+    };
 
     #[tokio::test]
     async fn empty_future() {
@@ -205,4 +214,19 @@ mod tests {
         assert_eq!(result.idle.as_secs(), 6);
         assert_eq!(result.busy.as_secs(), 3);
     }
+
+    #[tokio::test]
+    async fn extract_observes_completion_time() {
+        let metric = FuturesMetrics::obtain_futures_metrics("CompletionTimeTest");
+        let future = async { tokio::task::yield_now().await };
+        let wrapper_future = FutureTracker::new(future);
+        let result = wrapper_future.await;
+
+        result.extract(&metric);
+
+        let encoded = encode_metrics().unwrap();
+        assert!(encoded
+            .lines()
+            .any(|line| line == "CompletionTimeTest_completion_time_s_count 1"));
+    }
 }
@@ -3,11 +3,17 @@ use crate::{
         buckets,
         Buckets,
     },
+    capped_family::{
+        CappedFamily,
+        OverflowLabel,
+        DEFAULT_MAX_SERIES,
+    },
     global_registry,
 };
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::{
+        exemplar::HistogramWithExemplars,
         family::Family,
         gauge::Gauge,
         histogram::Histogram,
@@ -21,46 +27,97 @@ pub struct Label {
     path: String,
 }
 
+impl OverflowLabel for Label {
+    fn overflow() -> Self {
+        Self {
+            path: "overflow".to_string(),
+        }
+    }
+}
+
+/// The exemplar attached to the request duration, identifying the span of the request.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct SpanExemplar {
+    // the id of the `tracing` span of the request
+    pub span_id: String,
+    // the name of the graphql operation
+    pub operation: String,
+}
+
 pub struct GraphqlMetrics {
     // using gauges in case blocks are rolled back for any reason
     pub total_txs_count: Gauge,
-    requests: Family<Label, Histogram>,
+    // the number of requests that are being executed right now
+    pub inflight_requests: Gauge,
+    // each bucket keeps the exemplar of the latest request that fell into it,
+    // so the highest non-empty bucket points at one of the slowest requests
+    requests: CappedFamily<Label, HistogramWithExemplars<SpanExemplar>>,
     queries_complexity: Histogram,
 }
 
 impl GraphqlMetrics {
     fn new() -> Self {
         let tx_count_gauge = Gauge::default();
+        let inflight_requests = Gauge::default();
         let queries_complexity = Histogram::new(buckets_complexity());
-        let requests = Family::<Label, Histogram>::new_with_constructor(|| {
-            Histogram::new(buckets(Buckets::Timing))
-        });
-        let mut registry = global_registry().registry.lock();
-        registry.register("graphql_request_duration_seconds", "", requests.clone());
+        let requests: CappedFamily<Label, HistogramWithExemplars<SpanExemplar>> =
+            CappedFamily::new(
+                Family::new_with_constructor(|| {
+                    HistogramWithExemplars::new(buckets(Buckets::Timing))
+                }),
+                DEFAULT_MAX_SERIES,
+            );
+        let mut registry = global_registry().sub_registry("graphql");
+        registry.register("request_duration_seconds", "", requests.family().clone());
         registry.register(
-            "graphql_query_complexity",
+            "inflight_requests",
+            "The number of GraphQL requests executed concurrently",
+            inflight_requests.clone(),
+        );
+        registry.register(
+            "query_complexity",
             "The complexity of all queries received",
             queries_complexity.clone(),
         );
+        drop(registry);
 
-        registry.register(
-            "importer_tx_count",
+        // the total is updated by the GraphQL worker, but it describes the imported chain
+        global_registry().sub_registry("importer").register(
+            "tx_count",
             "the total amount of transactions that have been imported on chain",
             tx_count_gauge.clone(),
         );
 
         Self {
             total_txs_count: tx_count_gauge,
+            inflight_requests,
             queries_complexity,
             requests,
         }
     }
 
     pub fn graphql_observe(&self, query: &str, time: f64) {
+        self.graphql_observe_with_exemplar(query, time, None);
+    }
+
+    pub fn graphql_observe_with_exemplar(
+        &self,
+        query: &str,
+        time: f64,
+        exemplar: Option<SpanExemplar>,
+    ) {
         let histogram = self.requests.get_or_create(&Label {
             path: query.to_string(),
         });
-        histogram.observe(time);
+        histogram.observe(time, exemplar);
+    }
+
+    /// Marks the request as in-flight until the returned guard is dropped.
+    pub fn start_request(&self) -> InflightRequestGuard<'_> {
+        self.inflight_requests.inc();
+        InflightRequestGuard {
+            inflight_requests: &self.inflight_requests,
+        }
     }
 
     pub fn graphql_complexity_observe(&self, complexity: f64) {
@@ -68,6 +125,17 @@ impl GraphqlMetrics {
     }
 }
 
+/// Decrements the in-flight requests gauge on drop, including early returns and panics.
+pub struct InflightRequestGuard<'a> {
+    inflight_requests: &'a Gauge,
+}
+
+impl Drop for InflightRequestGuard<'_> {
+    fn drop(&mut self) {
+        self.inflight_requests.dec();
+    }
+}
+
 static GRAPHQL_METRICS: OnceLock<GraphqlMetrics> = OnceLock::new();
 pub fn graphql_metrics() -> &'static GraphqlMetrics {
     GRAPHQL_METRICS.get_or_init(GraphqlMetrics::new)
@@ -89,3 +157,97 @@ fn buckets_complexity() -> impl Iterator<Item = f64> {
     ]
     .into_iter()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        graphql_metrics,
+        SpanExemplar,
+    };
+    use crate::encode_metrics;
+    use std::{
+        sync::{
+            Arc,
+            Barrier,
+        },
+        thread,
+    };
+
+    #[test]
+    fn graphql_observe_with_exemplar_encodes_span_id_and_operation() {
+        // Given
+        let exemplar = SpanExemplar {
+            span_id: "4bf92f3577b34da6".to_string(),
+            operation: "SlowBalances".to_string(),
+        };
+
+        // When
+        graphql_metrics().graphql_observe_with_exemplar(
+            "exemplar_test",
+            1.5,
+            Some(exemplar),
+        );
+
+        // Then
+        let encoded = encode_metrics().unwrap();
+        let bucket = encoded
+            .lines()
+            .find(|line| {
+                line.starts_with("graphql_request_duration_seconds_bucket")
+                    && line.contains("path=\"exemplar_test\"")
+                    && line.contains(" # ")
+            })
+            .expect("The bucket with the exemplar should be encoded");
+        assert!(bucket.contains("span_id=\"4bf92f3577b34da6\""));
+        assert!(bucket.contains("operation=\"SlowBalances\""));
+    }
+
+    #[test]
+    fn inflight_requests_tracks_overlapping_requests() {
+        const CONCURRENCY: usize = 4;
+        let started = Arc::new(Barrier::new(CONCURRENCY.saturating_add(1)));
+        let finish = Arc::new(Barrier::new(CONCURRENCY.saturating_add(1)));
+
+        // Given
+        let requests: Vec<_> = (0..CONCURRENCY)
+            .map(|_| {
+                let started = started.clone();
+                let finish = finish.clone();
+                thread::spawn(move || {
+                    let _guard = graphql_metrics().start_request();
+                    started.wait();
+                    finish.wait();
+                })
+            })
+            .collect();
+
+        // When
+        started.wait();
+
+        // Then
+        assert_eq!(
+            graphql_metrics().inflight_requests.get(),
+            i64::try_from(CONCURRENCY).unwrap()
+        );
+
+        // When
+        finish.wait();
+        for request in requests {
+            request.join().unwrap();
+        }
+
+        // Then
+        assert_eq!(graphql_metrics().inflight_requests.get(), 0);
+
+        // When
+        let panicked = thread::spawn(|| {
+            let _guard = graphql_metrics().start_request();
+            panic!("resolver panicked");
+        })
+        .join();
+
+        // Then
+        assert!(panicked.is_err());
+        assert_eq!(graphql_metrics().inflight_requests.get(), 0);
+    }
+}
@@ -10,7 +10,10 @@ use prometheus_client::metrics::{
     histogram::Histogram,
 };
 use std::sync::{
-    atomic::AtomicU64,
+    atomic::{
+        AtomicU64,
+        Ordering,
+    },
     OnceLock,
 };
 
@@ -21,7 +24,11 @@ pub struct ImporterMetrics {
     pub gas_per_block: Gauge,
     pub fee_per_block: Gauge,
     pub transactions_per_block: Gauge,
+    pub transaction_count_per_block: Histogram,
     pub gas_price: Gauge,
+    pub da_height_lag: Gauge,
+    latest_da_height: AtomicU64,
+    imported_da_height: AtomicU64,
 }
 
 impl Default for ImporterMetrics {
@@ -32,51 +39,67 @@ impl Default for ImporterMetrics {
         let gas_per_block = Gauge::default();
         let fee_per_block = Gauge::default();
         let transactions_per_block = Gauge::default();
+        let transaction_count_per_block =
+            Histogram::new(buckets(Buckets::TransactionsCount));
         let gas_price = Gauge::default();
+        let da_height_lag = Gauge::default();
 
-        let mut registry = global_registry().registry.lock();
+        let mut registry = global_registry().sub_registry("importer");
         registry.register(
-            "importer_block_height",
+            "block_height",
             "the current height of the chain",
             block_height_gauge.clone(),
         );
 
         registry.register(
-            "importer_latest_block_commit_timestamp_s",
+            "latest_block_commit_timestamp_s",
             "A timestamp of when the current block was imported",
             latest_block_import_ms.clone(),
         );
 
         registry.register(
-            "importer_execute_and_commit_duration_s",
+            "execute_and_commit_duration_s",
             "Records the duration time of executing and committing a block",
             execute_and_commit_duration.clone(),
         );
 
         registry.register(
-            "importer_gas_per_block",
+            "gas_per_block",
             "The total gas used in a block",
             gas_per_block.clone(),
         );
 
         registry.register(
-            "importer_fee_per_block_gwei",
+            "fee_per_block_gwei",
             "The total fee (gwei) paid by transactions in a block",
             fee_per_block.clone(),
         );
 
         registry.register(
-            "importer_transactions_per_block",
+            "transactions_per_block",
             "The total number of transactions in a block",
             transactions_per_block.clone(),
         );
 
         registry.register(
-            "importer_gas_price_for_block",
+            "transaction_count_per_block",
+            "The distribution of the number of transactions in imported blocks",
+            transaction_count_per_block.clone(),
+        );
+
+        registry.register(
+            "gas_price_for_block",
             "The gas prices used in a block",
             transactions_per_block.clone(),
         );
 
+        registry.register(
+            "da_height_lag",
+            "The difference between the latest DA height known to the relayer \
+            and the DA height of the latest imported block",
+            da_height_lag.clone(),
+        );
+
         Self {
             block_height: block_height_gauge,
             latest_block_import_timestamp: latest_block_import_ms,
@@ -84,14 +107,112 @@ impl Default for ImporterMetrics {
             gas_per_block,
             fee_per_block,
             transactions_per_block,
+            transaction_count_per_block,
             gas_price,
+            da_height_lag,
+            latest_da_height: AtomicU64::new(0),
+            imported_da_height: AtomicU64::new(0),
         }
     }
 }
 
+impl ImporterMetrics {
+    /// Records the latest DA height known to the relayer.
+    pub fn record_latest_da_height(&self, da_height: u64) {
+        self.latest_da_height.store(da_height, Ordering::Relaxed);
+        self.update_da_height_lag();
+    }
+
+    /// Records the DA height of the latest imported block.
+    pub fn record_imported_da_height(&self, da_height: u64) {
+        self.imported_da_height.store(da_height, Ordering::Relaxed);
+        self.update_da_height_lag();
+    }
+
+    /// Records the number of transactions in the imported block.
+    pub fn record_transactions_count(&self, count: usize) {
+        self.transaction_count_per_block.observe(count as f64);
+    }
+
+    fn update_da_height_lag(&self) {
+        let lag = self
+            .latest_da_height
+            .load(Ordering::Relaxed)
+            .saturating_sub(self.imported_da_height.load(Ordering::Relaxed));
+        self.da_height_lag.set(lag.try_into().unwrap_or(i64::MAX));
+    }
+}
+
 // Setup a global static for accessing importer metrics
 static IMPORTER_METRICS: OnceLock<ImporterMetrics> = OnceLock::new();
 
 pub fn importer_metrics() -> &'static ImporterMetrics {
     IMPORTER_METRICS.get_or_init(ImporterMetrics::default)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::importer_metrics;
+    use prometheus_client::{
+        encoding::text::encode,
+        registry::Registry,
+    };
+
+    #[test]
+    fn da_height_lag_is_difference_between_latest_and_imported_da_height() {
+        // Given
+        importer_metrics().record_imported_da_height(100);
+
+        // When
+        importer_metrics().record_latest_da_height(115);
+
+        // Then
+        assert_eq!(importer_metrics().da_height_lag.get(), 15);
+
+        // When
+        importer_metrics().record_imported_da_height(115);
+
+        // Then
+        assert_eq!(importer_metrics().da_height_lag.get(), 0);
+    }
+
+    #[test]
+    fn record_transactions_count_increments_matching_buckets() {
+        // Given
+        let mut registry = Registry::default();
+        registry.register(
+            "transaction_count_per_block",
+            "",
+            importer_metrics().transaction_count_per_block.clone(),
+        );
+
+        // When
+        for count in [5, 50, 5000] {
+            importer_metrics().record_transactions_count(count);
+        }
+
+        // Then
+        let mut encoded = String::new();
+        encode(&mut encoded, &registry).unwrap();
+        let expected_buckets = [
+            ("1.0", 0),
+            ("5.0", 1),
+            ("10.0", 1),
+            ("50.0", 2),
+            ("100.0", 2),
+            ("500.0", 2),
+            ("1000.0", 2),
+            ("5000.0", 3),
+            ("10000.0", 3),
+            ("50000.0", 3),
+        ];
+        for (le, count) in expected_buckets {
+            let line =
+                format!("transaction_count_per_block_bucket{{le=\"{le}\"}} {count}");
+            assert!(encoded.lines().any(|l| l == line), "missing `{line}`");
+        }
+        assert!(encoded
+            .lines()
+            .any(|l| l == "transaction_count_per_block_count 3"));
+    }
+}
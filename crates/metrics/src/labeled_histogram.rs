@@ -0,0 +1,128 @@
+//! The histogram with a dynamic set of labels.
+//!
+//! Each distinct label set gets its own histogram with the same buckets,
+//! f.e. to track the fee distribution per asset.
+
+use prometheus_client::metrics::{
+    family::{
+        Family,
+        MetricConstructor,
+    },
+    histogram::Histogram,
+};
+use std::hash::Hash;
+
+/// Creates the histograms of a [`LabeledHistogram`] with the same buckets.
+#[derive(Clone, Debug)]
+pub struct HistogramConstructor {
+    buckets: Vec<f64>,
+}
+
+impl MetricConstructor<Histogram> for HistogramConstructor {
+    fn new_metric(&self) -> Histogram {
+        Histogram::new(self.buckets.iter().copied())
+    }
+}
+
+/// The wrapper around the [`Family`] of histograms keyed by the label set `L`.
+pub struct LabeledHistogram<L> {
+    family: Family<L, Histogram, HistogramConstructor>,
+}
+
+impl<L> LabeledHistogram<L>
+where
+    L: Clone + Hash + Eq,
+{
+    pub fn new(buckets: impl Iterator<Item = f64>) -> Self {
+        Self {
+            family: Family::new_with_constructor(HistogramConstructor {
+                buckets: buckets.collect(),
+            }),
+        }
+    }
+
+    /// Observes the `value` in the histogram of the `label` set.
+    pub fn observe(&self, label: &L, value: f64) {
+        self.family.get_or_create(label).observe(value);
+    }
+
+    /// The underlying family, f.e. to register it in the registry.
+    pub fn family(&self) -> &Family<L, Histogram, HistogramConstructor> {
+        &self.family
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LabeledHistogram;
+    use prometheus_client::{
+        encoding::{
+            text::encode,
+            EncodeLabelSet,
+        },
+        registry::Registry,
+    };
+
+    #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+    struct AssetLabel {
+        asset_id: String,
+    }
+
+    fn label(asset_id: &str) -> AssetLabel {
+        AssetLabel {
+            asset_id: asset_id.to_string(),
+        }
+    }
+
+    fn encoded_value(encoded: &str, name: &str) -> Option<u64> {
+        encoded.lines().find_map(|line| {
+            let (metric, value) = line.split_once(' ')?;
+            if metric == name {
+                value.parse().ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    #[test]
+    fn observe_keeps_buckets_of_label_sets_independent() {
+        // Given
+        let histogram = LabeledHistogram::new([10.0, 100.0].into_iter());
+        let mut registry = Registry::default();
+        registry.register("fee", "The fee", histogram.family().clone());
+
+        // When
+        histogram.observe(&label("a"), 5.0);
+        histogram.observe(&label("a"), 50.0);
+        histogram.observe(&label("b"), 500.0);
+
+        // Then
+        let mut encoded = String::new();
+        encode(&mut encoded, &registry).unwrap();
+        assert_eq!(
+            encoded_value(&encoded, r#"fee_bucket{le="10.0",asset_id="a"}"#),
+            Some(1)
+        );
+        assert_eq!(
+            encoded_value(&encoded, r#"fee_bucket{le="100.0",asset_id="a"}"#),
+            Some(2)
+        );
+        assert_eq!(
+            encoded_value(&encoded, r#"fee_bucket{le="10.0",asset_id="b"}"#),
+            Some(0)
+        );
+        assert_eq!(
+            encoded_value(&encoded, r#"fee_bucket{le="100.0",asset_id="b"}"#),
+            Some(0)
+        );
+        assert_eq!(
+            encoded_value(&encoded, r#"fee_count{asset_id="a"}"#),
+            Some(2)
+        );
+        assert_eq!(
+            encoded_value(&encoded, r#"fee_count{asset_id="b"}"#),
+            Some(1)
+        );
+    }
+}
@@ -3,6 +3,10 @@
 #![deny(unused_crate_dependencies)]
 #![deny(warnings)]
 
+use parking_lot::{
+    MappedMutexGuard,
+    MutexGuard,
+};
 use prometheus_client::{
     encoding::text::encode,
     registry::Registry,
@@ -19,13 +23,28 @@ pub struct GlobalRegistry {
     pub registry: parking_lot::Mutex<Registry>,
 }
 
+impl GlobalRegistry {
+    /// Returns a new sub-registry of the subsystem.
+    /// The names of metrics registered in it are prefixed with `{prefix}_`.
+    pub fn sub_registry(&self, prefix: &str) -> MappedMutexGuard<'_, Registry> {
+        MutexGuard::map(self.registry.lock(), |registry| {
+            registry.sub_registry_with_prefix(prefix)
+        })
+    }
+}
+
 mod buckets;
+pub mod capped_family;
 pub mod config;
 pub mod core_metrics;
+pub mod fee_metrics;
 pub mod futures;
 pub mod graphql_metrics;
 pub mod importer;
+pub mod labeled_histogram;
 pub mod p2p_metrics;
+pub mod producer;
+pub mod services;
 pub mod txpool_metrics;
 
 static GLOBAL_REGISTER: OnceLock<GlobalRegistry> = OnceLock::new();
@@ -34,6 +53,20 @@ pub fn global_registry() -> &'static GlobalRegistry {
     GLOBAL_REGISTER.get_or_init(GlobalRegistry::default)
 }
 
+static CHAIN_ID: OnceLock<String> = OnceLock::new();
+
+/// Sets the value of the `chain_id` label of the core metrics.
+/// Only the first call has an effect, and it should happen before the metrics are created.
+/// Returns `false` if the chain id was already set.
+pub fn set_chain_id(chain_id: u64) -> bool {
+    CHAIN_ID.set(chain_id.to_string()).is_ok()
+}
+
+/// The value of the `chain_id` label, `"unknown"` until [`set_chain_id`] is called.
+pub fn chain_id() -> &'static str {
+    CHAIN_ID.get().map(String::as_str).unwrap_or("unknown")
+}
+
 pub fn encode_metrics() -> Result<String, std::fmt::Error> {
     let mut encoded = String::new();
 
@@ -43,3 +76,79 @@ pub fn encode_metrics() -> Result<String, std::fmt::Error> {
 
     Ok(encoded)
 }
+
+/// Encodes only the metric families whose names start with the `prefix`.
+pub fn encode_metrics_filtered(prefix: &str) -> Result<String, std::fmt::Error> {
+    const DESCRIPTORS: [&str; 3] = ["# HELP ", "# TYPE ", "# UNIT "];
+
+    let encoded = encode_metrics()?;
+    let mut filtered = String::new();
+    for line in encoded.lines() {
+        let name = DESCRIPTORS
+            .iter()
+            .find_map(|descriptor| line.strip_prefix(descriptor))
+            .unwrap_or(line);
+        if line == "# EOF" || name.starts_with(prefix) {
+            filtered.push_str(line);
+            filtered.push('\n');
+        }
+    }
+
+    Ok(filtered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        encode_metrics,
+        encode_metrics_filtered,
+        global_registry,
+        txpool_metrics::txpool_metrics,
+    };
+    use prometheus_client::metrics::counter::Counter;
+
+    #[test]
+    fn sub_registry_prefixes_metric_names() {
+        // Given
+        let counter = Counter::<u64>::default();
+        global_registry().sub_registry("test_subsystem").register(
+            "duration_seconds",
+            "",
+            counter.clone(),
+        );
+
+        // When
+        counter.inc();
+
+        // Then
+        let encoded = encode_metrics().unwrap();
+        assert!(encoded
+            .lines()
+            .any(|line| line == "test_subsystem_duration_seconds_total 1"));
+    }
+
+    #[test]
+    fn encode_metrics_filtered_keeps_only_metrics_with_prefix() {
+        // Given
+        let _ = txpool_metrics();
+        let counter = Counter::<u64>::default();
+        global_registry().sub_registry("not_txpool").register(
+            "events",
+            "",
+            counter.clone(),
+        );
+        counter.inc();
+
+        // When
+        let encoded = encode_metrics_filtered("txpool").unwrap();
+
+        // Then
+        let samples = encoded
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .collect::<Vec<_>>();
+        assert!(!samples.is_empty());
+        assert!(samples.iter().all(|line| line.starts_with("txpool")));
+        assert!(!encoded.contains("not_txpool_events"));
+    }
+}
@@ -2,41 +2,56 @@ use crate::global_registry;
 use prometheus_client::metrics::{
     counter::Counter,
     gauge::Gauge,
+    histogram::Histogram,
 };
 use std::sync::OnceLock;
 
 pub struct P2PMetrics {
     pub unique_peers: Counter,
     pub blocks_requested: Gauge,
+    pub peer_score_histogram: Histogram,
 }
 
 impl P2PMetrics {
     fn new() -> Self {
         let unique_peers = Counter::default();
         let blocks_requested = Gauge::default();
+        let peer_score_histogram = Histogram::new(peer_score_buckets());
 
         let metrics = P2PMetrics {
             unique_peers,
             blocks_requested,
+            peer_score_histogram,
         };
 
-        let mut registry = global_registry().registry.lock();
+        let mut registry = global_registry().sub_registry("p2p");
         registry.register(
-            "Peer_Counter",
+            "peer_counter",
             "A Counter which keeps track of each unique peer the p2p service has connected to",
             metrics.unique_peers.clone(),
         );
 
         registry.register(
-            "Blocks_Requested",
+            "blocks_requested",
             "A Gauge which keeps track of how many blocks were requested and served over the p2p req/res protocol",
             metrics.blocks_requested.clone()
         );
 
+        registry.register(
+            "peer_score_histogram",
+            "A Histogram of the app scores of the connected peers, observed on each score decay",
+            metrics.peer_score_histogram.clone(),
+        );
+
         metrics
     }
 }
 
+/// The buckets of the [`P2PMetrics::peer_score_histogram`].
+pub fn peer_score_buckets() -> impl Iterator<Item = f64> {
+    [-100.0, -50.0, -10.0, 0.0, 10.0, 50.0, 100.0].into_iter()
+}
+
 static P2P_METRICS: OnceLock<P2PMetrics> = OnceLock::new();
 
 pub fn p2p_metrics() -> &'static P2PMetrics {
@@ -0,0 +1,29 @@
+use crate::global_registry;
+use prometheus_client::metrics::counter::Counter;
+use std::sync::OnceLock;
+
+pub struct ProducerMetrics {
+    pub empty_blocks: Counter,
+}
+
+impl Default for ProducerMetrics {
+    fn default() -> Self {
+        let empty_blocks = Counter::default();
+
+        let metrics = ProducerMetrics { empty_blocks };
+
+        let mut registry = global_registry().sub_registry("producer");
+        registry.register(
+            "empty_blocks",
+            "The number of blocks produced without transactions from the TxPool",
+            metrics.empty_blocks.clone(),
+        );
+
+        metrics
+    }
+}
+
+static PRODUCER_METRICS: OnceLock<ProducerMetrics> = OnceLock::new();
+pub fn producer_metrics() -> &'static ProducerMetrics {
+    PRODUCER_METRICS.get_or_init(ProducerMetrics::default)
+}
@@ -0,0 +1,112 @@
+use crate::global_registry;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        family::Family,
+        gauge::Gauge,
+    },
+};
+use std::sync::OnceLock;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ServiceLabel {
+    // the name of the service
+    pub name: String,
+}
+
+pub struct ServiceHealthMetrics {
+    pub service_running: Family<ServiceLabel, Gauge>,
+}
+
+impl Default for ServiceHealthMetrics {
+    fn default() -> Self {
+        let service_running = Family::<ServiceLabel, Gauge>::default();
+
+        let metrics = ServiceHealthMetrics { service_running };
+
+        let mut registry = global_registry().sub_registry("fuel_core");
+        registry.register(
+            "service_running",
+            "Whether the service is currently running (1) or not (0), labeled by the service name",
+            metrics.service_running.clone(),
+        );
+
+        metrics
+    }
+}
+
+impl ServiceHealthMetrics {
+    /// Marks the service as running.
+    pub fn register_service(&self, name: &str) {
+        self.gauge(name).set(1);
+    }
+
+    /// Marks the service as not running.
+    pub fn deregister_service(&self, name: &str) {
+        self.gauge(name).set(0);
+    }
+
+    /// Returns `true` if the service is marked as running.
+    pub fn is_running(&self, name: &str) -> bool {
+        self.service_running
+            .get(&ServiceLabel {
+                name: name.to_string(),
+            })
+            .is_some_and(|gauge| gauge.get() == 1)
+    }
+
+    fn gauge(&self, name: &str) -> Gauge {
+        self.service_running
+            .get_or_create(&ServiceLabel {
+                name: name.to_string(),
+            })
+            .clone()
+    }
+}
+
+static SERVICE_HEALTH_METRICS: OnceLock<ServiceHealthMetrics> = OnceLock::new();
+pub fn service_health_metrics() -> &'static ServiceHealthMetrics {
+    SERVICE_HEALTH_METRICS.get_or_init(ServiceHealthMetrics::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::service_health_metrics;
+    use crate::encode_metrics;
+
+    #[test]
+    fn service_running_gauge_follows_registration() {
+        // Given
+        let metrics = service_health_metrics();
+
+        // When
+        metrics.register_service("test_service");
+
+        // Then
+        assert!(metrics.is_running("test_service"));
+        let encoded = encode_metrics().unwrap();
+        assert!(encoded
+            .lines()
+            .any(|line| line == "fuel_core_service_running{name=\"test_service\"} 1"));
+
+        // When
+        metrics.deregister_service("test_service");
+
+        // Then
+        assert!(!metrics.is_running("test_service"));
+    }
+
+    #[test]
+    fn is_running_does_not_create_the_series() {
+        // Given
+        let metrics = service_health_metrics();
+
+        // When
+        let running = metrics.is_running("unknown_service");
+
+        // Then
+        assert!(!running);
+        let encoded = encode_metrics().unwrap();
+        assert!(!encoded.contains("unknown_service"));
+    }
+}
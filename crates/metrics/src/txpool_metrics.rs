@@ -1,9 +1,46 @@
-use crate::global_registry;
-use prometheus_client::metrics::histogram::Histogram;
+use crate::{
+    buckets::{
+        buckets,
+        Buckets,
+    },
+    capped_family::{
+        CappedFamily,
+        OverflowLabel,
+        DEFAULT_MAX_SERIES,
+    },
+    global_registry,
+};
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        histogram::Histogram,
+    },
+};
 use std::sync::OnceLock;
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RejectionReasonLabel {
+    // the name of the error that rejected the transaction
+    pub reason: String,
+}
+
+impl OverflowLabel for RejectionReasonLabel {
+    fn overflow() -> Self {
+        Self {
+            reason: "overflow".to_string(),
+        }
+    }
+}
+
 pub struct TxPoolMetrics {
     pub tx_size_histogram: Histogram,
+    pub transactions_inserted: Counter,
+    pub transactions_rejected: CappedFamily<RejectionReasonLabel, Counter>,
+    pub selection_duration: Histogram,
+    pub selected_transactions_count: Histogram,
+    pub time_to_inclusion: Histogram,
 }
 
 impl Default for TxPoolMetrics {
@@ -11,20 +48,91 @@ impl Default for TxPoolMetrics {
         let tx_sizes = Vec::new();
 
         let tx_size_histogram = Histogram::new(tx_sizes.into_iter());
+        let transactions_inserted = Counter::default();
+        let transactions_rejected = CappedFamily::new(
+            Family::<RejectionReasonLabel, Counter>::default(),
+            DEFAULT_MAX_SERIES,
+        );
+        let selection_duration = Histogram::new(buckets(Buckets::Timing));
+        let selected_transactions_count =
+            Histogram::new(buckets(Buckets::TransactionsCount));
+        let time_to_inclusion = Histogram::new(buckets(Buckets::Timing));
 
-        let metrics = TxPoolMetrics { tx_size_histogram };
+        let metrics = TxPoolMetrics {
+            tx_size_histogram,
+            transactions_inserted,
+            transactions_rejected,
+            selection_duration,
+            selected_transactions_count,
+            time_to_inclusion,
+        };
 
-        let mut registry = global_registry().registry.lock();
+        let mut registry = global_registry().sub_registry("txpool");
         registry.register(
-            "Tx_Size_Histogram",
+            "tx_size_histogram",
             "A Histogram keeping track of the size of txs",
             metrics.tx_size_histogram.clone(),
         );
 
+        registry.register(
+            "transactions_inserted",
+            "The number of transactions successfully inserted into the pool",
+            metrics.transactions_inserted.clone(),
+        );
+
+        registry.register(
+            "transactions_rejected",
+            "The number of transactions rejected by the pool, labeled by the reason",
+            metrics.transactions_rejected.family().clone(),
+        );
+
+        registry.register(
+            "selection_seconds",
+            "The time spent selecting the transactions for a block",
+            metrics.selection_duration.clone(),
+        );
+
+        registry.register(
+            "selected_transactions_count",
+            "The number of transactions selected for a block",
+            metrics.selected_transactions_count.clone(),
+        );
+
+        registry.register(
+            "time_to_inclusion_seconds",
+            "The time transactions spent in the pool before being selected for a block",
+            metrics.time_to_inclusion.clone(),
+        );
+
         metrics
     }
 }
 
+impl TxPoolMetrics {
+    pub fn transaction_rejected(&self, reason: &str) {
+        self.transactions_rejected
+            .get_or_create(&RejectionReasonLabel {
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
+
+    pub fn record_selection(&self, duration_secs: f64, transactions_count: usize) {
+        self.selection_duration.observe(duration_secs);
+        self.selected_transactions_count
+            .observe(transactions_count as f64);
+    }
+
+    pub fn rejected_count(&self, reason: &str) -> u64 {
+        self.transactions_rejected
+            .get(&RejectionReasonLabel {
+                reason: reason.to_string(),
+            })
+            .map(|counter| counter.get())
+            .unwrap_or_default()
+    }
+}
+
 static TXPOOL_METRICS: OnceLock<TxPoolMetrics> = OnceLock::new();
 pub fn txpool_metrics() -> &'static TxPoolMetrics {
     TXPOOL_METRICS.get_or_init(TxPoolMetrics::default)
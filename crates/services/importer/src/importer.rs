@@ -437,6 +437,9 @@ where
         importer_metrics()
             .transactions_per_block
             .set(total_transactions.try_into().unwrap_or(i64::MAX));
+        importer_metrics().record_transactions_count(total_transactions);
+        importer_metrics()
+            .record_imported_da_height(*result.sealed_block.entity.header().da_height);
     }
 }
 
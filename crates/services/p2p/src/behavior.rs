@@ -93,7 +93,7 @@ impl FuelBehaviour {
 
         let gossipsub = build_gossipsub_behaviour(p2p_config);
 
-        let peer_report = peer_report::Behaviour::new(&p2p_config.reserved_nodes);
+        let peer_report = peer_report::Behaviour::new(p2p_config);
 
         let identify = {
             let identify_config = identify::Config::new(
@@ -213,4 +213,8 @@ impl FuelBehaviour {
     pub fn block_peer(&mut self, peer_id: PeerId) {
         self.blocked_peer.block_peer(peer_id)
     }
+
+    pub fn greylist_peer(&mut self, peer_id: PeerId) {
+        self.peer_report.greylist_peer(peer_id)
+    }
 }
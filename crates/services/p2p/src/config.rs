@@ -4,7 +4,10 @@ use crate::{
     peer_manager::ConnectionState,
     TryPeerId,
 };
-use fuel_core_types::blockchain::consensus::Genesis;
+use fuel_core_types::{
+    blockchain::consensus::Genesis,
+    services::p2p::peer_reputation::AppScore,
+};
 
 use libp2p::{
     gossipsub,
@@ -126,6 +129,12 @@ pub struct Config<State = Initialized> {
     pub heartbeat_max_avg_interval: Duration,
     /// Max time since a given peer has sent a heartbeat before getting reputation penalty
     pub heartbeat_max_time_since_last: Duration,
+    /// Time between reputation decays of all connected peers
+    pub reputation_decay_interval: Duration,
+    /// Peers with the reputation below this score are greylisted
+    pub greylist_app_score: AppScore,
+    /// For how long inbound connections from a greylisted peer are refused
+    pub greylist_duration: Duration,
 
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
@@ -183,6 +192,9 @@ impl Config<NotInitialized> {
             heartbeat_check_interval: self.heartbeat_check_interval,
             heartbeat_max_avg_interval: self.heartbeat_max_time_since_last,
             heartbeat_max_time_since_last: self.heartbeat_max_time_since_last,
+            reputation_decay_interval: self.reputation_decay_interval,
+            greylist_app_score: self.greylist_app_score,
+            greylist_duration: self.greylist_duration,
             metrics: self.metrics,
             database_read_threads: self.database_read_threads,
             tx_pool_threads: self.tx_pool_threads,
@@ -233,6 +245,9 @@ impl Config<NotInitialized> {
             heartbeat_check_interval: Duration::from_secs(10),
             heartbeat_max_avg_interval: Duration::from_secs(20),
             heartbeat_max_time_since_last: Duration::from_secs(40),
+            reputation_decay_interval: Duration::from_secs(1),
+            greylist_app_score: -25.0,
+            greylist_duration: Duration::from_secs(60),
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
             metrics: false,
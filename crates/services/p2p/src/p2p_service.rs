@@ -22,17 +22,24 @@ use crate::{
     },
     heartbeat,
     peer_manager::{
+        capabilities::PeerCapabilities,
         PeerManager,
         Punisher,
     },
     peer_report::PeerReportEvent,
-    request_response::messages::{
-        RequestError,
-        RequestMessage,
-        ResponseError,
-        ResponseMessage,
-        ResponseSendError,
-        ResponseSender,
+    request_response::{
+        messages::{
+            RequestError,
+            RequestMessage,
+            ResponseError,
+            ResponseMessage,
+            ResponseSendError,
+            ResponseSender,
+        },
+        protocols::{
+            negotiate_version,
+            SUPPORTED_VERSIONS,
+        },
     },
     TryPeerId,
 };
@@ -90,6 +97,10 @@ impl Punisher for Swarm<FuelBehaviour> {
     fn ban_peer(&mut self, peer_id: PeerId) {
         self.behaviour_mut().block_peer(peer_id)
     }
+
+    fn greylist_peer(&mut self, peer_id: PeerId) {
+        self.behaviour_mut().greylist_peer(peer_id)
+    }
 }
 
 /// Listens to the events on the p2p network
@@ -298,6 +309,7 @@ impl FuelP2PService {
                 reserved_peers,
                 connection_state,
                 config.max_peers_connected as usize,
+                config.greylist_app_score,
             ),
         })
     }
@@ -640,6 +652,9 @@ impl FuelP2PService {
                 self.peer_manager.handle_peer_disconnect(peer_id);
                 return Some(FuelP2PEvent::PeerDisconnected(peer_id));
             }
+            PeerReportEvent::PeerGreylisted { peer_id, until } => {
+                debug!(target: "fuel-p2p", "Peer {peer_id} is greylisted until {until:?}");
+            }
         }
         None
     }
@@ -772,6 +787,11 @@ impl FuelP2PService {
             identify::Event::Received { peer_id, info } => {
                 self.update_metrics(increment_unique_peers);
 
+                if let Err(error) = negotiate_version(SUPPORTED_VERSIONS, &info) {
+                    debug!(target: "fuel-p2p", "Request-response negotiation with peer {:?} failed => {}", peer_id, error);
+                }
+
+                let capabilities = PeerCapabilities::from_identify_info(&info);
                 let mut addresses = info.listen_addrs;
                 let agent_version = info.agent_version;
 
@@ -789,6 +809,7 @@ impl FuelP2PService {
                     &peer_id,
                     addresses.clone(),
                     agent_version,
+                    capabilities,
                 );
 
                 self.swarm
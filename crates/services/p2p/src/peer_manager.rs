@@ -1,3 +1,4 @@
+use fuel_core_metrics::p2p_metrics::p2p_metrics;
 use fuel_core_types::{
     fuel_types::BlockHeight,
     services::p2p::peer_reputation::{
@@ -12,6 +13,7 @@ use libp2p::{
     Multiaddr,
     PeerId,
 };
+use prometheus_client::metrics::histogram::Histogram;
 use rand::seq::IteratorRandom;
 use std::{
     collections::{
@@ -30,9 +32,13 @@ use tracing::{
 
 use crate::{
     gossipsub_config::GRAYLIST_THRESHOLD,
-    peer_manager::heartbeat_data::HeartbeatData,
+    peer_manager::{
+        capabilities::PeerCapabilities,
+        heartbeat_data::HeartbeatData,
+    },
 };
 
+pub mod capabilities;
 pub mod heartbeat_data;
 
 /// At this point we better just ban the peer
@@ -43,6 +49,7 @@ const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 pub struct PeerInfo {
     pub peer_addresses: HashSet<Multiaddr>,
     pub client_version: Option<String>,
+    pub capabilities: PeerCapabilities,
     pub heartbeat_data: HeartbeatData,
     pub score: AppScore,
 }
@@ -52,6 +59,7 @@ impl PeerInfo {
         Self {
             peer_addresses: HashSet::new(),
             client_version: None,
+            capabilities: PeerCapabilities::default(),
             heartbeat_data: HeartbeatData::new(heartbeat_avg_window),
             score: DEFAULT_APP_SCORE,
         }
@@ -76,9 +84,13 @@ impl PeerManager {
         reserved_peers: HashSet<PeerId>,
         connection_state: Arc<RwLock<ConnectionState>>,
         max_non_reserved_peers: usize,
+        greylist_app_score: AppScore,
     ) -> Self {
         Self {
-            score_config: ScoreConfig::default(),
+            score_config: ScoreConfig {
+                greylist_app_score,
+                ..ScoreConfig::default()
+            },
             non_reserved_connected_peers: HashMap::with_capacity(max_non_reserved_peers),
             reserved_connected_peers: HashMap::with_capacity(reserved_peers.len()),
             reserved_peers,
@@ -135,16 +147,30 @@ impl PeerManager {
         peer_id: &PeerId,
         addresses: Vec<Multiaddr>,
         agent_version: String,
+        capabilities: PeerCapabilities,
     ) {
         let peers = self.get_assigned_peer_table_mut(peer_id);
         insert_client_version(peers, peer_id, agent_version);
+        insert_capabilities(peers, peer_id, capabilities);
         insert_peer_addresses(peers, peer_id, addresses);
     }
 
     pub fn batch_update_score_with_decay(&mut self) {
+        self.decay_scores_and_record(&p2p_metrics().peer_score_histogram)
+    }
+
+    fn decay_scores_and_record(&mut self, histogram: &Histogram) {
         for peer_info in self.non_reserved_connected_peers.values_mut() {
             peer_info.score *= DECAY_APP_SCORE;
         }
+
+        for peer_info in self
+            .non_reserved_connected_peers
+            .values()
+            .chain(self.reserved_connected_peers.values())
+        {
+            histogram.observe(peer_info.score);
+        }
     }
 
     pub fn update_app_score<T: Punisher>(
@@ -163,6 +189,8 @@ impl PeerManager {
 
             if new_score < self.score_config.min_app_score_allowed {
                 punisher.ban_peer(peer_id);
+            } else if new_score < self.score_config.greylist_app_score {
+                punisher.greylist_peer(peer_id);
             }
         } else {
             log_missing_peer(&peer_id);
@@ -356,6 +384,18 @@ fn insert_client_version(
     }
 }
 
+fn insert_capabilities(
+    peers: &mut HashMap<PeerId, PeerInfo>,
+    peer_id: &PeerId,
+    capabilities: PeerCapabilities,
+) {
+    if let Some(peer) = peers.get_mut(peer_id) {
+        peer.capabilities = capabilities;
+    } else {
+        log_missing_peer(peer_id);
+    }
+}
+
 fn log_missing_peer(peer_id: &PeerId) {
     debug!(target: "fuel-p2p", "Peer with PeerId: {:?} is not among the connected peers", peer_id)
 }
@@ -364,6 +404,7 @@ fn log_missing_peer(peer_id: &PeerId) {
 struct ScoreConfig {
     max_app_score: AppScore,
     min_app_score_allowed: AppScore,
+    greylist_app_score: AppScore,
     min_gossip_score_allowed: f64,
 }
 
@@ -378,6 +419,7 @@ impl ScoreConfig {
         Self {
             max_app_score: MAX_APP_SCORE,
             min_app_score_allowed: MIN_APP_SCORE,
+            greylist_app_score: MIN_APP_SCORE,
             min_gossip_score_allowed: MIN_GOSSIPSUB_SCORE_BEFORE_BAN,
         }
     }
@@ -385,6 +427,8 @@ impl ScoreConfig {
 
 pub trait Punisher {
     fn ban_peer(&mut self, peer_id: PeerId);
+
+    fn greylist_peer(&mut self, peer_id: PeerId);
 }
 
 #[cfg(test)]
@@ -408,6 +452,7 @@ mod tests {
             reserved_peers.into_iter().collect(),
             connection_state,
             max_non_reserved_peers,
+            MIN_APP_SCORE,
         )
     }
 
@@ -486,4 +531,51 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[test]
+    fn decay_records_scores_of_connected_peers_in_histogram() {
+        use fuel_core_metrics::p2p_metrics::peer_score_buckets;
+        use prometheus_client::{
+            encoding::text::encode,
+            registry::Registry,
+        };
+
+        let scores = [-90.0, -30.0, 5.0, 30.0, 120.0];
+        let mut peer_manager = initialize_peer_manager(vec![], scores.len());
+        let random_peers = get_random_peers(scores.len());
+        for (peer_id, score) in random_peers.iter().zip(scores) {
+            peer_manager.handle_initial_connection(peer_id);
+            peer_manager
+                .non_reserved_connected_peers
+                .get_mut(peer_id)
+                .unwrap()
+                .score = score;
+        }
+        let histogram = Histogram::new(peer_score_buckets());
+        let mut registry = Registry::default();
+        registry.register("peer_score_histogram", "", histogram.clone());
+
+        // decayed scores are -81.0, -27.0, 4.5, 27.0 and 108.0
+        peer_manager.decay_scores_and_record(&histogram);
+
+        let mut encoded = String::new();
+        encode(&mut encoded, &registry).unwrap();
+        let expected_buckets = [
+            ("-100.0", 0),
+            ("-50.0", 1),
+            ("-10.0", 2),
+            ("0.0", 2),
+            ("10.0", 3),
+            ("50.0", 4),
+            ("100.0", 4),
+            ("+Inf", 5),
+        ];
+        for (le, count) in expected_buckets {
+            let line = format!("peer_score_histogram_bucket{{le=\"{le}\"}} {count}");
+            assert!(
+                encoded.lines().any(|l| l == line),
+                "Missing `{line}` in:\n{encoded}"
+            );
+        }
+    }
 }
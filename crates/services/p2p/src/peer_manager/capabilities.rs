@@ -0,0 +1,117 @@
+use crate::config::MAX_RESPONSE_SIZE;
+use libp2p::identify;
+
+/// The protocol advertised by peers that can serve compressed blocks.
+pub const BLOCK_COMPRESSION_PROTOCOL: &str = "/fuel/capabilities/block_compression/0.0.1";
+/// The protocol advertised by peers that accept blob transactions.
+pub const BLOB_TXS_PROTOCOL: &str = "/fuel/capabilities/blob_txs/0.0.1";
+/// The prefix of the protocol advertising the maximum response size in bytes,
+/// f.e. `/fuel/capabilities/max_response_size/1048576`.
+pub const MAX_RESPONSE_SIZE_PROTOCOL_PREFIX: &str =
+    "/fuel/capabilities/max_response_size/";
+
+/// Optional protocol extensions supported by a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerCapabilities {
+    pub supports_block_compression: bool,
+    pub supports_blob_txs: bool,
+    pub max_response_size: usize,
+}
+
+impl Default for PeerCapabilities {
+    fn default() -> Self {
+        Self {
+            supports_block_compression: false,
+            supports_blob_txs: false,
+            max_response_size: MAX_RESPONSE_SIZE,
+        }
+    }
+}
+
+impl PeerCapabilities {
+    /// Parses the capabilities from the protocols reported by the peer.
+    /// Unknown protocols are ignored.
+    pub fn from_identify_info(info: &identify::Info) -> Self {
+        let mut capabilities = Self::default();
+        for protocol in &info.protocols {
+            match protocol.as_ref() {
+                BLOCK_COMPRESSION_PROTOCOL => {
+                    capabilities.supports_block_compression = true
+                }
+                BLOB_TXS_PROTOCOL => capabilities.supports_blob_txs = true,
+                protocol => {
+                    if let Some(max_response_size) = protocol
+                        .strip_prefix(MAX_RESPONSE_SIZE_PROTOCOL_PREFIX)
+                        .and_then(|size| size.parse().ok())
+                    {
+                        capabilities.max_response_size = max_response_size;
+                    }
+                }
+            }
+        }
+        capabilities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libp2p::{
+        identity::Keypair,
+        Multiaddr,
+        StreamProtocol,
+    };
+
+    fn identify_info(protocols: Vec<String>) -> identify::Info {
+        identify::Info {
+            public_key: Keypair::generate_secp256k1().public(),
+            protocol_version: "/fuel/1.0".to_string(),
+            agent_version: "fuel-core".to_string(),
+            listen_addrs: vec![],
+            protocols: protocols
+                .into_iter()
+                .map(|protocol| StreamProtocol::try_from_owned(protocol).unwrap())
+                .collect(),
+            observed_addr: Multiaddr::empty(),
+        }
+    }
+
+    #[test]
+    fn from_identify_info_parses_advertised_capabilities() {
+        // Given
+        let info = identify_info(vec![
+            "/fuel/req_res/0.0.1".to_string(),
+            BLOCK_COMPRESSION_PROTOCOL.to_string(),
+            BLOB_TXS_PROTOCOL.to_string(),
+            format!("{MAX_RESPONSE_SIZE_PROTOCOL_PREFIX}1024"),
+        ]);
+
+        // When
+        let capabilities = PeerCapabilities::from_identify_info(&info);
+
+        // Then
+        assert_eq!(
+            capabilities,
+            PeerCapabilities {
+                supports_block_compression: true,
+                supports_blob_txs: true,
+                max_response_size: 1024,
+            }
+        );
+    }
+
+    #[test]
+    fn from_identify_info_uses_defaults_without_capability_protocols() {
+        // Given
+        let info = identify_info(vec![
+            "/fuel/req_res/0.0.1".to_string(),
+            format!("{MAX_RESPONSE_SIZE_PROTOCOL_PREFIX}not_a_number"),
+        ]);
+
+        // When
+        let capabilities = PeerCapabilities::from_identify_info(&info);
+
+        // Then
+        assert_eq!(capabilities, PeerCapabilities::default());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    config::Config,
     utils::is_dialable,
     TryPeerId,
 };
@@ -30,6 +31,7 @@ use libp2p::{
 use std::{
     collections::{
         BTreeMap,
+        HashMap,
         HashSet,
         VecDeque,
     },
@@ -48,8 +50,12 @@ use tokio::time::{
 };
 use void::Void;
 
+/// The error returned when an inbound connection from a greylisted peer is refused.
+#[derive(Debug, thiserror::Error)]
+#[error("Peer {0} is greylisted")]
+pub struct PeerIsGreylisted(PeerId);
+
 const HEALTH_CHECK_INTERVAL_IN_SECONDS: u64 = 10;
-const REPUTATION_DECAY_INTERVAL_IN_SECONDS: u64 = 1;
 
 /// Events emitted by PeerReportBehavior
 #[derive(Debug, Clone)]
@@ -62,6 +68,12 @@ pub enum PeerReportEvent {
     },
     /// Informs p2p service / PeerManager to perform reputation decay of connected nodes
     PerformDecay,
+    /// The peer's reputation dropped below the greylist threshold,
+    /// so inbound connections from it are refused until `until`
+    PeerGreylisted {
+        peer_id: PeerId,
+        until: time::Instant,
+    },
 }
 
 // `Behaviour` that reports events about peers
@@ -72,10 +84,13 @@ pub struct Behaviour {
     pending_connections: HashSet<ConnectionId>,
     pending_events: VecDeque<ToSwarm<PeerReportEvent, Void>>,
     decay_interval: Interval,
+    greylisted_peers: HashMap<PeerId, time::Instant>,
+    greylist_duration: Duration,
 }
 
 impl Behaviour {
-    pub(crate) fn new(reserved_nodes_multiaddrs: &[Multiaddr]) -> Self {
+    pub(crate) fn new(config: &Config) -> Self {
+        let reserved_nodes_multiaddrs = &config.reserved_nodes;
         let mut reserved_nodes_to_connect = VecDeque::new();
         let mut reserved_nodes_multiaddr_map = BTreeMap::<PeerId, Vec<Multiaddr>>::new();
 
@@ -101,11 +116,38 @@ impl Behaviour {
             connected_reserved_nodes: Default::default(),
             pending_connections: Default::default(),
             pending_events: VecDeque::default(),
-            decay_interval: time::interval(Duration::from_secs(
-                REPUTATION_DECAY_INTERVAL_IN_SECONDS,
-            )),
+            decay_interval: time::interval(config.reputation_decay_interval),
+            greylisted_peers: Default::default(),
+            greylist_duration: config.greylist_duration,
         }
     }
+
+    /// Refuses inbound connections from the peer for the configured greylist duration.
+    pub(crate) fn greylist_peer(&mut self, peer_id: PeerId) {
+        let now = time::Instant::now();
+        let until = now.checked_add(self.greylist_duration).unwrap_or(now);
+        self.greylisted_peers.insert(peer_id, until);
+        self.pending_events.push_back(ToSwarm::GenerateEvent(
+            PeerReportEvent::PeerGreylisted { peer_id, until },
+        ));
+    }
+
+    fn is_greylisted(&mut self, peer_id: &PeerId) -> bool {
+        match self.greylisted_peers.get(peer_id) {
+            Some(until) if time::Instant::now() < *until => true,
+            Some(_) => {
+                self.greylisted_peers.remove(peer_id);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Forgets the peers whose greylist expired, so the map doesn't grow unbounded.
+    fn prune_expired_greylist(&mut self) {
+        let now = time::Instant::now();
+        self.greylisted_peers.retain(|_, until| now < *until);
+    }
 }
 
 impl NetworkBehaviour for Behaviour {
@@ -115,10 +157,13 @@ impl NetworkBehaviour for Behaviour {
     fn handle_established_inbound_connection(
         &mut self,
         _connection_id: ConnectionId,
-        _peer: PeerId,
+        peer: PeerId,
         _local_addr: &Multiaddr,
         _remote_addr: &Multiaddr,
     ) -> Result<THandler<Self>, ConnectionDenied> {
+        if self.is_greylisted(&peer) {
+            return Err(ConnectionDenied::new(PeerIsGreylisted(peer)))
+        }
         Ok(dummy::ConnectionHandler)
     }
 
@@ -226,9 +271,109 @@ impl NetworkBehaviour for Behaviour {
         }
 
         if self.decay_interval.poll_tick(cx).is_ready() {
+            self.prune_expired_greylist();
             return Poll::Ready(ToSwarm::GenerateEvent(PeerReportEvent::PerformDecay))
         }
 
         Poll::Pending
     }
 }
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poll_decay(behaviour: &mut Behaviour) -> bool {
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        matches!(
+            behaviour.poll(&mut cx),
+            Poll::Ready(ToSwarm::GenerateEvent(PeerReportEvent::PerformDecay))
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn poll__emits_decay_after_configured_interval() {
+        // Given
+        let mut config = Config::default_initialized("test_network");
+        config.reputation_decay_interval = Duration::from_millis(100);
+        let mut behaviour = Behaviour::new(&config);
+        // The first tick of the interval completes immediately.
+        assert!(poll_decay(&mut behaviour));
+
+        // When
+        time::advance(Duration::from_millis(99)).await;
+        let decay_before_interval = poll_decay(&mut behaviour);
+        time::advance(Duration::from_millis(1)).await;
+        let decay_after_interval = poll_decay(&mut behaviour);
+
+        // Then
+        assert!(!decay_before_interval);
+        assert!(decay_after_interval);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn handle_established_inbound_connection__refuses_greylisted_peer_until_expiry()
+    {
+        // Given
+        let mut config = Config::default_initialized("test_network");
+        config.greylist_duration = Duration::from_secs(60);
+        let mut behaviour = Behaviour::new(&config);
+        let peer_id = PeerId::random();
+        let addr = Multiaddr::empty();
+        behaviour.greylist_peer(peer_id);
+
+        // When
+        let refused = behaviour.handle_established_inbound_connection(
+            ConnectionId::new_unchecked(0),
+            peer_id,
+            &addr,
+            &addr,
+        );
+        time::advance(Duration::from_secs(60)).await;
+        let allowed = behaviour.handle_established_inbound_connection(
+            ConnectionId::new_unchecked(1),
+            peer_id,
+            &addr,
+            &addr,
+        );
+
+        // Then
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(matches!(
+            behaviour.poll(&mut cx),
+            Poll::Ready(ToSwarm::GenerateEvent(PeerReportEvent::PeerGreylisted { peer_id: id, .. })) if id == peer_id
+        ));
+        assert!(refused.is_err());
+        assert!(allowed.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn poll__prunes_expired_greylisted_peers_on_decay() {
+        // Given
+        let mut config = Config::default_initialized("test_network");
+        config.reputation_decay_interval = Duration::from_secs(1);
+        config.greylist_duration = Duration::from_secs(2);
+        let mut behaviour = Behaviour::new(&config);
+        assert!(poll_decay(&mut behaviour));
+        let expired_peer = PeerId::random();
+        behaviour.greylist_peer(expired_peer);
+        time::advance(Duration::from_secs(1)).await;
+        let active_peer = PeerId::random();
+        behaviour.greylist_peer(active_peer);
+        // Drain the `PeerGreylisted` events.
+        assert!(!poll_decay(&mut behaviour));
+        assert!(!poll_decay(&mut behaviour));
+
+        // When
+        time::advance(Duration::from_secs(1)).await;
+        let decayed = poll_decay(&mut behaviour);
+
+        // Then
+        assert!(decayed);
+        assert!(!behaviour.greylisted_peers.contains_key(&expired_peer));
+        assert!(behaviour.greylisted_peers.contains_key(&active_peer));
+    }
+}
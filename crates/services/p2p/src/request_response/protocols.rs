@@ -0,0 +1,103 @@
+use crate::request_response::messages::REQUEST_RESPONSE_PROTOCOL_ID;
+use libp2p::identify;
+use thiserror::Error;
+
+/// The common prefix of all versions of the request-response protocol.
+const REQUEST_RESPONSE_PROTOCOL_PREFIX: &str = "/fuel/req_res/";
+
+/// Versions of the request-response protocol, ordered from the oldest to the latest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProtocolVersion {
+    V1,
+}
+
+/// Versions of the request-response protocol supported by this node.
+pub const SUPPORTED_VERSIONS: &[ProtocolVersion] = &[ProtocolVersion::V1];
+
+impl ProtocolVersion {
+    /// The protocol name advertised for this version.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProtocolVersion::V1 => REQUEST_RESPONSE_PROTOCOL_ID,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum NegotiationError {
+    #[error(
+        "No common request-response protocol version, supported: {supported:?}, offered by the peer: {offered:?}"
+    )]
+    NoCommonVersion {
+        supported: Vec<ProtocolVersion>,
+        offered: Vec<String>,
+    },
+}
+
+/// Returns the latest version from `local` that is also advertised by the peer.
+pub fn negotiate_version(
+    local: &[ProtocolVersion],
+    info: &identify::Info,
+) -> Result<ProtocolVersion, NegotiationError> {
+    let offered: Vec<String> = info
+        .protocols
+        .iter()
+        .map(|protocol| protocol.to_string())
+        .filter(|protocol| protocol.starts_with(REQUEST_RESPONSE_PROTOCOL_PREFIX))
+        .collect();
+
+    local
+        .iter()
+        .filter(|version| offered.iter().any(|protocol| protocol == version.as_str()))
+        .max()
+        .copied()
+        .ok_or_else(|| NegotiationError::NoCommonVersion {
+            supported: local.to_vec(),
+            offered,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libp2p::{
+        identity::Keypair,
+        Multiaddr,
+        StreamProtocol,
+    };
+
+    fn identify_info(protocols: Vec<&'static str>) -> identify::Info {
+        identify::Info {
+            public_key: Keypair::generate_secp256k1().public(),
+            protocol_version: "/fuel/1.0".to_string(),
+            agent_version: "fuel-core".to_string(),
+            listen_addrs: vec![],
+            protocols: protocols.into_iter().map(StreamProtocol::new).collect(),
+            observed_addr: Multiaddr::empty(),
+        }
+    }
+
+    #[test]
+    fn negotiate_version_picks_version_offered_by_peer() {
+        let info = identify_info(vec!["/fuel/heartbeat/0.0.1", "/fuel/req_res/0.0.1"]);
+
+        let version = negotiate_version(&[ProtocolVersion::V1], &info);
+
+        assert_eq!(version, Ok(ProtocolVersion::V1));
+    }
+
+    #[test]
+    fn negotiate_version_reports_both_sets_when_there_is_no_overlap() {
+        let info = identify_info(vec!["/fuel/heartbeat/0.0.1", "/fuel/req_res/0.0.2"]);
+
+        let result = negotiate_version(&[ProtocolVersion::V1], &info);
+
+        assert_eq!(
+            result,
+            Err(NegotiationError::NoCommonVersion {
+                supported: vec![ProtocolVersion::V1],
+                offered: vec!["/fuel/req_res/0.0.2".to_string()],
+            })
+        );
+    }
+}
@@ -14,6 +14,10 @@ use anyhow::{
     anyhow,
     Context,
 };
+use fuel_core_metrics::{
+    fee_metrics::fee_metrics,
+    producer::producer_metrics,
+};
 use fuel_core_storage::transactional::{
     AtomicView,
     Changes,
@@ -31,6 +35,7 @@ use fuel_core_types::{
     fuel_tx::{
         field::{
             InputContract,
+            MintAssetId,
             MintGasPrice,
         },
         Transaction,
@@ -42,6 +47,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            ExecutionResult,
             TransactionExecutionStatus,
             UncommittedResult,
         },
@@ -213,9 +219,36 @@ where
             .context(context_string)?;
 
         debug!("Produced block with result: {:?}", result.result());
+        if self.config.metrics {
+            Self::update_fee_metrics(result.result());
+        }
         Ok(result)
     }
 
+    fn update_fee_metrics(result: &ExecutionResult) {
+        let tx_fees = result
+            .block
+            .transactions()
+            .iter()
+            .zip(result.tx_status.iter())
+            .filter(|(transaction, _)| !transaction.is_mint())
+            .map(|(_, tx_status)| *tx_status.result.total_fee())
+            .collect::<Vec<_>>();
+        // The fees are collected by the mint transaction in its asset
+        let fee_asset_id = result
+            .block
+            .transactions()
+            .iter()
+            .find_map(Transaction::as_mint)
+            .map(|mint| mint.mint_asset_id().to_string());
+        if let Some(asset_id) = fee_asset_id {
+            for tx_fee in tx_fees.iter() {
+                fee_metrics().record_tx_fee_by_asset(&asset_id, *tx_fee);
+            }
+        }
+        fee_metrics().record_block_fees(tx_fees);
+    }
+
     async fn calculate_gas_price(&self) -> anyhow::Result<u64> {
         self.gas_price_provider
             .next_gas_price()
@@ -240,12 +273,26 @@ where
         height: BlockHeight,
         block_time: Tai64,
     ) -> anyhow::Result<UncommittedResult<Changes>> {
-        self.produce_and_execute::<TxSource, _>(
-            height,
-            block_time,
-            |gas_price, height| self.txpool.get_source(gas_price, height),
-        )
-        .await
+        let result = self
+            .produce_and_execute::<TxSource, _>(
+                height,
+                block_time,
+                |gas_price, height| self.txpool.get_source(gas_price, height),
+            )
+            .await?;
+
+        if self.config.metrics {
+            let is_empty = result
+                .result()
+                .block
+                .transactions()
+                .iter()
+                .all(|transaction| transaction.is_mint());
+            if is_empty {
+                producer_metrics().empty_blocks.inc();
+            }
+        }
+        Ok(result)
     }
 }
 
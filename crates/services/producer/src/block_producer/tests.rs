@@ -20,6 +20,7 @@ use crate::{
     Config,
     Producer,
 };
+use fuel_core_metrics::producer::producer_metrics;
 use fuel_core_producer as _;
 use fuel_core_types::{
     blockchain::{
@@ -572,6 +573,28 @@ mod produce_and_execute_block_txpool {
         assert_eq!(expected, actual);
     }
 
+    #[tokio::test]
+    async fn produce_and_execute_block_txpool__empty_pool_increments_empty_blocks_counter(
+    ) {
+        // given
+        let mut ctx = TestContext::default();
+        ctx.config.metrics = true;
+        let producer = ctx.producer();
+        let empty_blocks_before = producer_metrics().empty_blocks.get();
+
+        // when
+        let result = producer
+            .produce_and_execute_block_txpool(1u32.into(), Tai64::now())
+            .await;
+
+        // then
+        assert!(result.is_ok());
+        assert_eq!(
+            producer_metrics().empty_blocks.get(),
+            empty_blocks_before.saturating_add(1)
+        );
+    }
+
     #[tokio::test]
     async fn produce_and_execute_block_txpool__missing_gas_price_causes_block_production_to_fail(
     ) {
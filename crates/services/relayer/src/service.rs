@@ -23,6 +23,7 @@ use ethers_providers::{
     QuorumProvider,
     WeightedProvider,
 };
+use fuel_core_metrics::importer::importer_metrics;
 use fuel_core_services::{
     RunnableService,
     RunnableTask,
@@ -155,6 +156,7 @@ where
     fn update_synced(&self, state: &state::EthState) {
         self.synced.send_if_modified(|last_state| {
             if let Some(val) = state.is_synced_at() {
+                importer_metrics().record_latest_da_height(val);
                 *last_state = Some(DaBlockHeight::from(val));
                 true
             } else {
@@ -6,9 +6,12 @@ use crate::{
     Shared,
 };
 use anyhow::anyhow;
-use fuel_core_metrics::futures::{
-    future_tracker::FutureTracker,
-    FuturesMetrics,
+use fuel_core_metrics::{
+    futures::{
+        future_tracker::FutureTracker,
+        FuturesMetrics,
+    },
+    services::service_health_metrics,
 };
 use futures::FutureExt;
 use std::any::Any;
@@ -262,6 +265,7 @@ where
             ));
             tracing::debug!("awaiting run");
             let result = run.catch_unwind().await;
+            service_health_metrics().deregister_service(S::NAME);
 
             let stopped_state = if let Err(e) = result {
                 let panic_information = panic_to_string(e);
@@ -321,6 +325,7 @@ async fn run<S>(
         .await
         .expect("The initialization of the service failed.");
 
+    service_health_metrics().register_service(S::NAME);
     sender.send_if_modified(|s| {
         if s.starting() {
             *s = State::Started;
@@ -578,6 +583,42 @@ mod tests {
         assert!(matches!(state, State::Stopped));
     }
 
+    struct HealthCheckedService;
+
+    #[async_trait::async_trait]
+    impl RunnableService for HealthCheckedService {
+        const NAME: &'static str = "HealthCheckedService";
+
+        type SharedData = EmptyShared;
+        type Task = MockTask;
+        type TaskParams = ();
+
+        fn shared_data(&self) -> EmptyShared {
+            EmptyShared
+        }
+
+        async fn into_task(
+            self,
+            state: &StateWatcher,
+            params: (),
+        ) -> anyhow::Result<MockTask> {
+            MockService::new_empty().into_task(state, params).await
+        }
+    }
+
+    #[tokio::test]
+    async fn service_running_gauge_follows_lifecycle() {
+        let service = ServiceRunner::new(HealthCheckedService);
+        let metrics = service_health_metrics();
+        assert!(!metrics.is_running(HealthCheckedService::NAME));
+
+        service.start_and_await().await.unwrap();
+        assert!(metrics.is_running(HealthCheckedService::NAME));
+
+        service.stop_and_await().await.unwrap();
+        assert!(!metrics.is_running(HealthCheckedService::NAME));
+    }
+
     #[tokio::test]
     async fn stop_unused_service() {
         let mut receiver;
@@ -96,6 +96,10 @@ where
             .collect()
     }
 
+    fn get_coin_spender(&self, utxo_id: &UtxoId) -> Option<Self::StorageIndex> {
+        self.coins_spenders.get(utxo_id).copied()
+    }
+
     fn find_collisions(
         &self,
         transaction: &PoolTransaction,
@@ -3,7 +3,10 @@ use crate::error::{
     Error,
 };
 use fuel_core_types::{
-    fuel_tx::TxId,
+    fuel_tx::{
+        TxId,
+        UtxoId,
+    },
     services::txpool::PoolTransaction,
 };
 use std::collections::HashMap;
@@ -27,6 +30,9 @@ pub trait CollisionManager {
     /// Get spenders of coins UTXO created by a transaction ID.
     fn get_coins_spenders(&self, tx_creator_id: &TxId) -> Vec<Self::StorageIndex>;
 
+    /// Get the transaction that currently spends the coin UTXO.
+    fn get_coin_spender(&self, utxo_id: &UtxoId) -> Option<Self::StorageIndex>;
+
     /// Inform the collision manager that a transaction was stored.
     fn on_stored_transaction(
         &mut self,
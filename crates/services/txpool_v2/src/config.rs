@@ -18,6 +18,7 @@ use fuel_core_types::{
             },
         },
         Address,
+        AssetId,
         ContractId,
         Input,
         UtxoId,
@@ -26,7 +27,12 @@ use fuel_core_types::{
     services::txpool::PoolTransaction,
 };
 
-use crate::error::BlacklistedError;
+use num_rational::Ratio;
+
+use crate::error::{
+    BlacklistedError,
+    Error,
+};
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct BlackList {
@@ -38,6 +44,8 @@ pub struct BlackList {
     pub messages: HashSet<Nonce>,
     /// Blacklisted contracts.
     pub contracts: HashSet<ContractId>,
+    /// Blacklisted assets.
+    pub assets: HashSet<AssetId>,
 }
 
 impl BlackList {
@@ -47,30 +55,45 @@ impl BlackList {
         utxo_ids: Vec<UtxoId>,
         messages: Vec<Nonce>,
         contracts: Vec<ContractId>,
+        assets: Vec<AssetId>,
     ) -> Self {
         Self {
             owners: owners.into_iter().collect(),
             coins: utxo_ids.into_iter().collect(),
             messages: messages.into_iter().collect(),
             contracts: contracts.into_iter().collect(),
+            assets: assets.into_iter().collect(),
         }
     }
 
-    /// Check if the transaction has blacklisted inputs.
+    /// Check if the transaction has blacklisted inputs or outputs.
     pub fn check_blacklisting(
         &self,
         tx: &PoolTransaction,
     ) -> Result<(), BlacklistedError> {
         for input in tx.inputs() {
             match input {
-                Input::CoinSigned(CoinSigned { utxo_id, owner, .. })
-                | Input::CoinPredicate(CoinPredicate { utxo_id, owner, .. }) => {
+                Input::CoinSigned(CoinSigned {
+                    utxo_id,
+                    owner,
+                    asset_id,
+                    ..
+                })
+                | Input::CoinPredicate(CoinPredicate {
+                    utxo_id,
+                    owner,
+                    asset_id,
+                    ..
+                }) => {
                     if self.coins.contains(utxo_id) {
                         return Err(BlacklistedError::BlacklistedUTXO(*utxo_id));
                     }
                     if self.owners.contains(owner) {
                         return Err(BlacklistedError::BlacklistedOwner(*owner));
                     }
+                    if self.assets.contains(asset_id) {
+                        return Err(BlacklistedError::BlacklistedAsset(*asset_id))
+                    }
                 }
                 Input::Contract(contract) => {
                     if self.contracts.contains(&contract.contract_id) {
@@ -107,7 +130,7 @@ impl BlackList {
                         return Err(BlacklistedError::BlacklistedMessage(*nonce));
                     }
                     if self.owners.contains(sender) {
-                        return Err(BlacklistedError::BlacklistedOwner(*sender));
+                        return Err(BlacklistedError::BlacklistedSender(*sender))
                     }
                     if self.owners.contains(recipient) {
                         return Err(BlacklistedError::BlacklistedOwner(*recipient));
@@ -116,10 +139,113 @@ impl BlackList {
             }
         }
 
+        for output in tx.outputs() {
+            if let Some(asset_id) = output.asset_id() {
+                if self.assets.contains(asset_id) {
+                    return Err(BlacklistedError::BlacklistedAsset(*asset_id))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The list of addresses allowed to send transactions to the permissioned pool.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct AllowList {
+    /// Allowed addresses. `None` allows all addresses.
+    pub addresses: Option<HashSet<Address>>,
+}
+
+impl AllowList {
+    /// Create a new allow list. `None` allows all addresses.
+    pub fn new(addresses: Option<Vec<Address>>) -> Self {
+        Self {
+            addresses: addresses.map(|addresses| addresses.into_iter().collect()),
+        }
+    }
+
+    /// Check if all inputs of the transaction are spent by the allowed addresses.
+    pub fn check_allowlist(&self, tx: &PoolTransaction) -> Result<(), Error> {
+        let Some(addresses) = &self.addresses else {
+            return Ok(())
+        };
+
+        for input in tx.inputs() {
+            let sender = match input {
+                Input::CoinSigned(CoinSigned { owner, .. })
+                | Input::CoinPredicate(CoinPredicate { owner, .. }) => owner,
+                Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+                | Input::MessageCoinPredicate(MessageCoinPredicate {
+                    recipient, ..
+                })
+                | Input::MessageDataSigned(MessageDataSigned { recipient, .. })
+                | Input::MessageDataPredicate(MessageDataPredicate {
+                    recipient, ..
+                }) => recipient,
+                Input::Contract(_) => continue,
+            };
+
+            if !addresses.contains(sender) {
+                return Err(Error::SenderNotAllowed(*sender))
+            }
+        }
+
         Ok(())
     }
 }
 
+/// The algorithm used to select transactions from the pool for the block.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionAlgorithmKind {
+    /// Selects transactions with the highest tip/gas ratio first.
+    #[default]
+    RatioTipGas,
+    /// Selects transactions in the order they were inserted into the pool.
+    Fifo,
+}
+
+/// The weights of the tip per unit of each resource used to score transactions
+/// during eviction. Transactions with the lowest score are evicted first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvictionWeights {
+    /// The weight of the tip/gas ratio.
+    pub tip_per_gas: u64,
+    /// The weight of the tip/byte ratio.
+    pub tip_per_byte: u64,
+}
+
+impl Default for EvictionWeights {
+    fn default() -> Self {
+        Self {
+            tip_per_gas: 1,
+            tip_per_byte: 0,
+        }
+    }
+}
+
+impl EvictionWeights {
+    /// Returns `tip_per_gas * tip / gas + tip_per_byte * tip / bytes`.
+    pub fn score(&self, tip: u64, gas: u64, bytes: usize) -> Ratio<u128> {
+        let tip = u128::from(tip);
+        let gas = u128::from(gas).max(1);
+        let bytes = (bytes as u128).max(1);
+
+        let tip_per_gas = u128::from(self.tip_per_gas)
+            .saturating_mul(tip)
+            .saturating_mul(bytes);
+        let tip_per_byte = u128::from(self.tip_per_byte)
+            .saturating_mul(tip)
+            .saturating_mul(gas);
+
+        Ratio::new(
+            tip_per_gas.saturating_add(tip_per_byte),
+            gas.saturating_mul(bytes),
+        )
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Config {
     /// Enable UTXO validation (will check if UTXO exists in the database and has correct data).
@@ -128,8 +254,19 @@ pub struct Config {
     pub max_tx_update_subscriptions: usize,
     /// Maximum transactions per dependencies chain.
     pub max_txs_chain_count: usize,
+    /// Maximum number of hops from a transaction to the farthest executable
+    /// transaction it depends on, as returned by `Pool::transaction_dependency_depth`.
+    /// Executable transactions have depth `0`.
+    pub max_dependent_depth: usize,
     /// Pool limits
     pub pool_limits: PoolLimits,
+    /// Maximum size of a blob transaction in bytes.
+    pub max_blob_tx_bytes: usize,
+    /// Allows inserting operator-designated transactions that override the pool limits.
+    pub allow_priority_overrides: bool,
+    /// The minimal ratio between the tip/gas ratios of the replacement and the
+    /// replaced transaction accepted by `Pool::replace_transaction`.
+    pub min_fee_bump_ratio: Ratio<u64>,
     /// Service channel limits
     pub service_channel_limits: ServiceChannelLimits,
     /// Interval for checking the time to live of transactions.
@@ -140,6 +277,14 @@ pub struct Config {
     pub heavy_work: HeavyWorkConfig,
     /// Blacklist. Transactions with blacklisted inputs will not be accepted.
     pub black_list: BlackList,
+    /// Allow list. If set, only transactions of the allowed addresses will be accepted.
+    pub allow_list: AllowList,
+    /// The algorithm used to select transactions for the block.
+    pub selection_algorithm: SelectionAlgorithmKind,
+    /// The weights used to decide which transactions to evict when the pool is full.
+    pub eviction_weights: EvictionWeights,
+    /// Enables prometheus metrics for this fuel-service
+    pub metrics: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -179,14 +324,21 @@ impl Default for Config {
             utxo_validation: true,
             max_tx_update_subscriptions: 1000,
             max_txs_chain_count: 50,
+            max_dependent_depth: 5,
             ttl_check_interval: Duration::from_secs(60),
             max_txs_ttl: Duration::from_secs(60 * 10),
             black_list: BlackList::default(),
+            allow_list: AllowList::default(),
+            selection_algorithm: SelectionAlgorithmKind::default(),
+            eviction_weights: EvictionWeights::default(),
             pool_limits: PoolLimits {
                 max_txs: 10000,
                 max_gas: 100_000_000_000,
                 max_bytes_size: 1_000_000_000,
             },
+            max_blob_tx_bytes: 1024 * 1024,
+            allow_priority_overrides: false,
+            min_fee_bump_ratio: Ratio::new(11, 10),
             heavy_work: HeavyWorkConfig {
                 // It is important for tests to have only one thread for verification
                 // because some of them rely on the ordering of insertion.
@@ -199,6 +351,7 @@ impl Default for Config {
                 max_pending_write_pool_requests: 1000,
                 max_pending_read_pool_requests: 1000,
             },
+            metrics: false,
         }
     }
 }
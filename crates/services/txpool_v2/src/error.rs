@@ -1,6 +1,9 @@
+use std::time::Duration;
+
 use fuel_core_types::{
     fuel_tx::{
         Address,
+        AssetId,
         BlobId,
         ContractId,
         TxId,
@@ -23,6 +26,8 @@ pub enum Error {
     Storage(String),
     #[display(fmt = "Blacklisted error: {_0}")]
     Blacklisted(BlacklistedError),
+    #[display(fmt = "The sender {_0} is not allowed by the TxPool")]
+    SenderNotAllowed(Address),
     #[display(fmt = "Transaction collided: {_0}")]
     Collided(CollisionReason),
     #[display(fmt = "Transaction input validation failed: {_0}")]
@@ -37,6 +42,49 @@ pub enum Error {
     MintIsDisallowed,
     #[display(fmt = "Pool limit is hit, try to increase gas_price")]
     NotInsertedLimitHit,
+    #[display(fmt = "Pool is overloaded, retry after {retry_after:?}")]
+    PoolOverloaded {
+        /// The estimated time after which the pool may have room for the transaction.
+        retry_after: Duration,
+    },
+    #[display(fmt = "Transaction would create a cycle in the dependency graph")]
+    DependencyCycle,
+    #[display(
+        fmt = "Transaction dependency chain is too deep: {depth}, while the max is {max}"
+    )]
+    DependencyChainTooDeep {
+        /// The number of hops from the transaction to the farthest
+        /// executable transaction it depends on.
+        depth: usize,
+        /// The maximum allowed depth.
+        max: usize,
+    },
+    #[display(
+        fmt = "Blob transaction is too large: {size} bytes, while the max is {max}"
+    )]
+    BlobTooLarge {
+        /// The metered size of the blob transaction in bytes.
+        size: usize,
+        /// The maximum allowed size of the blob transaction in bytes.
+        max: usize,
+    },
+    #[display(
+        fmt = "Priority overrides are disabled in the configuration of the TxPool"
+    )]
+    PriorityOverridesDisabled,
+    #[display(
+        fmt = "The tip/gas ratio of the replacement transaction doesn't exceed \
+        the ratio of the replaced transaction by the minimal fee bump"
+    )]
+    ReplacementFeeInsufficient,
+    #[display(fmt = "Invalid replacement: {_0}")]
+    InvalidReplacement(String),
+    #[display(fmt = "Transaction {_0} is not found in the TxPool")]
+    TransactionNotFound(TxId),
+    #[display(
+        fmt = "Transaction {_0} of the batch is evicted by another transaction of the batch"
+    )]
+    EvictedByBatch(TxId),
     #[display(fmt = "Transaction is removed: {_0}")]
     Removed(RemovedReason),
     #[display(fmt = "Transaction has been skipped during block insertion: {_0}")]
@@ -58,6 +106,42 @@ pub enum Error {
     },
 }
 
+impl Error {
+    /// Returns the name of the error variant.
+    /// It is used as a label for the rejection metrics.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Error::GasPriceNotFound(_) => "GasPriceNotFound",
+            Error::Database(_) => "Database",
+            Error::Storage(_) => "Storage",
+            Error::Blacklisted(_) => "Blacklisted",
+            Error::SenderNotAllowed(_) => "SenderNotAllowed",
+            Error::Collided(_) => "Collided",
+            Error::InputValidation(_) => "InputValidation",
+            Error::Dependency(_) => "Dependency",
+            Error::ConsensusValidity(_) => "ConsensusValidity",
+            Error::WasmValidity(_) => "WasmValidity",
+            Error::MintIsDisallowed => "MintIsDisallowed",
+            Error::NotInsertedLimitHit => "NotInsertedLimitHit",
+            Error::PoolOverloaded { .. } => "PoolOverloaded",
+            Error::DependencyCycle => "DependencyCycle",
+            Error::DependencyChainTooDeep { .. } => "DependencyChainTooDeep",
+            Error::BlobTooLarge { .. } => "BlobTooLarge",
+            Error::PriorityOverridesDisabled => "PriorityOverridesDisabled",
+            Error::ReplacementFeeInsufficient => "ReplacementFeeInsufficient",
+            Error::InvalidReplacement(_) => "InvalidReplacement",
+            Error::TransactionNotFound(_) => "TransactionNotFound",
+            Error::EvictedByBatch(_) => "EvictedByBatch",
+            Error::Removed(_) => "Removed",
+            Error::SkippedTransaction(_) => "SkippedTransaction",
+            Error::TooManyQueuedTransactions => "TooManyQueuedTransactions",
+            Error::ServiceCommunicationFailed => "ServiceCommunicationFailed",
+            Error::ServiceQueueFull => "ServiceQueueFull",
+            Error::InsufficientMaxFee { .. } => "InsufficientMaxFee",
+        }
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Display)]
 pub enum RemovedReason {
     #[display(
@@ -76,10 +160,14 @@ pub enum BlacklistedError {
     BlacklistedUTXO(UtxoId),
     #[display(fmt = "The owner `{_0}` is blacklisted")]
     BlacklistedOwner(Address),
+    #[display(fmt = "The sender `{_0}` of the message is blacklisted")]
+    BlacklistedSender(Address),
     #[display(fmt = "The contract `{_0}` is blacklisted")]
     BlacklistedContract(ContractId),
     #[display(fmt = "The message `{_0}` is blacklisted")]
     BlacklistedMessage(Nonce),
+    #[display(fmt = "The asset `{_0}` is blacklisted")]
+    BlacklistedAsset(AssetId),
 }
 
 #[derive(Clone, Debug, derive_more::Display)]
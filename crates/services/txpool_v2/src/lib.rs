@@ -25,6 +25,19 @@ mod tests;
 fuel_core_trace::enable_tracing!();
 
 use fuel_core_types::fuel_asm::Word;
+
+#[cfg(feature = "test-helpers")]
+pub mod selection_algorithm {
+    pub use crate::{
+        selection_algorithms::{
+            ratio_tip_gas::RatioTipGasSelection,
+            SelectionAlgorithm,
+            SelectionAlgorithmStorage,
+        },
+        storage::StorageData,
+    };
+}
+
 pub use selection_algorithms::Constraints;
 pub use service::{
     new_service,
@@ -1,16 +1,39 @@
 mod collisions;
 
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     iter,
-    time::SystemTime,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+        SystemTime,
+    },
 };
 
 use collisions::CollisionsExt;
+use fuel_core_metrics::txpool_metrics::txpool_metrics;
 use fuel_core_types::{
+    fuel_asm::Word,
     fuel_tx::{
-        field::BlobId,
+        field::{
+            BlobId,
+            Inputs,
+        },
+        ConsensusParameters,
+        Transaction,
         TxId,
+        UtxoId,
+    },
+    fuel_vm::{
+        checked_transaction::{
+            CheckPredicateParams,
+            EstimatePredicates,
+        },
+        interpreter::Memory,
     },
     services::txpool::{
         ArcPoolTx,
@@ -24,7 +47,12 @@ use crate::{
         CollisionManager,
         Collisions,
     },
-    config::Config,
+    config::{
+        BlackList,
+        Config,
+        EvictionWeights,
+        PoolLimits,
+    },
     error::{
         DependencyError,
         Error,
@@ -32,11 +60,13 @@ use crate::{
     },
     ports::TxPoolPersistentStorage,
     selection_algorithms::{
+        ratio_tip_gas::RatioTipGas,
         Constraints,
         SelectionAlgorithm,
     },
     storage::{
         CheckedTransaction,
+        RemovedTransactions,
         Storage,
         StorageData,
     },
@@ -98,17 +128,364 @@ where
     /// Returns a list of results for each transaction.
     /// Each result is a list of transactions that were removed from the pool
     /// because of the insertion of the new transaction.
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            tx_id = %tx.id(),
+            gas = tx.max_gas(),
+            bytes_size = tx.metered_bytes_size(),
+        )
+    )]
     pub fn insert(
         &mut self,
         tx: ArcPoolTx,
         persistent_storage: &impl TxPoolPersistentStorage,
     ) -> Result<Vec<ArcPoolTx>, Error> {
+        let result = self.insert_inner(tx, &TxPriority::default(), persistent_storage);
+        self.record_insertion_result(&result);
+        result
+    }
+
+    /// Insert an operator-designated transaction into the pool with the given priority.
+    ///
+    /// The priority allows the transaction to exceed the pool limits instead of
+    /// evicting other transactions or being rejected. It is only accepted when
+    /// [`Config::allow_priority_overrides`] is enabled.
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            tx_id = %tx.id(),
+            gas = tx.max_gas(),
+            bytes_size = tx.metered_bytes_size(),
+        )
+    )]
+    pub fn insert_with_priority(
+        &mut self,
+        tx: ArcPoolTx,
+        priority: TxPriority,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<Vec<ArcPoolTx>, Error> {
+        let result = if self.config.allow_priority_overrides {
+            self.insert_inner(tx, &priority, persistent_storage)
+        } else {
+            Err(Error::PriorityOverridesDisabled)
+        };
+        self.record_insertion_result(&result);
+        result
+    }
+
+    /// Replace the transaction `old_tx_id` with its fee bumped version `new_tx`.
+    ///
+    /// The new transaction must have the same sender, spend at least one coin of the
+    /// old transaction, and its tip/gas ratio must be at least
+    /// [`Config::min_fee_bump_ratio`] times the ratio of the old transaction.
+    /// Dependents of the old transaction stay in the pool if they are still valid
+    /// after the replacement. If the new transaction is rejected, the pool is left
+    /// as it was before the call.
+    ///
+    /// Returns the old transaction along with all other removed transactions.
+    pub fn replace_transaction(
+        &mut self,
+        old_tx_id: TxId,
+        new_tx: ArcPoolTx,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<Vec<ArcPoolTx>, Error> {
+        let result =
+            self.replace_transaction_inner(old_tx_id, new_tx, persistent_storage);
+        self.record_insertion_result(&result);
+        result
+    }
+
+    fn replace_transaction_inner(
+        &mut self,
+        old_tx_id: TxId,
+        new_tx: ArcPoolTx,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<Vec<ArcPoolTx>, Error> {
+        let old_tx = self
+            .find_one(&old_tx_id)
+            .map(|storage_entry| storage_entry.transaction.clone())
+            .ok_or_else(|| {
+                Error::InvalidReplacement(format!(
+                    "The transaction {old_tx_id} is not in the pool"
+                ))
+            })?;
+
+        let sender = |tx: &ArcPoolTx| {
+            tx.inputs()
+                .iter()
+                .find_map(|input| input.input_owner())
+                .copied()
+        };
+        if sender(&old_tx) != sender(&new_tx) {
+            return Err(Error::InvalidReplacement(
+                "The replacement transaction has a different sender".to_string(),
+            ))
+        }
+
+        let coins = |tx: &ArcPoolTx| {
+            tx.inputs()
+                .iter()
+                .filter(|input| input.is_coin())
+                .filter_map(|input| input.utxo_id())
+                .copied()
+                .collect::<HashSet<_>>()
+        };
+        if coins(&old_tx).is_disjoint(&coins(&new_tx)) {
+            return Err(Error::InvalidReplacement(
+                "The replacement transaction doesn't spend any coin of the replaced transaction"
+                    .to_string(),
+            ))
+        }
+
+        if !self.is_sufficient_fee_bump(&old_tx, &new_tx) {
+            return Err(Error::ReplacementFeeInsufficient)
+        }
+
+        // Evicted transactions are returned in the order parent -> dependents,
+        // so restoring them in the same order restores the dependencies.
+        let old_subtree = self.remove_entries_and_dependents(vec![old_tx_id]);
+
+        let evicted = match self.insert_inner(
+            new_tx,
+            &TxPriority::default(),
+            persistent_storage,
+        ) {
+            Ok(evicted) => evicted,
+            Err(err) => {
+                // The rejected transaction changed nothing in the pool,
+                // so the old subtree fits into it again.
+                for entry in old_subtree {
+                    let tx_id = entry.transaction.id();
+                    let result = self.restore(entry, persistent_storage);
+                    debug_assert!(
+                        result.as_ref().is_ok_and(|removed| removed.is_empty()),
+                        "The transaction {tx_id} should be restored without side effects"
+                    );
+                    if let Err(err) = result {
+                        tracing::error!(
+                            "Failed to restore the transaction {} after the rejected replacement: {}",
+                            tx_id,
+                            err
+                        );
+                    }
+                }
+                return Err(err)
+            }
+        };
+
+        let mut removed_transactions = vec![];
+        for entry in old_subtree {
+            if entry.transaction.id() == old_tx_id {
+                removed_transactions.push(entry.transaction);
+                continue
+            }
+
+            // The dependent is kept only if the replacement still satisfies its inputs.
+            let transaction = entry.transaction.clone();
+            if self.restore(entry, persistent_storage).is_err() {
+                removed_transactions.push(transaction);
+            }
+        }
+        removed_transactions.extend(evicted);
+
+        Ok(removed_transactions)
+    }
+
+    /// Checks that the tip/gas ratio of the new transaction is at least
+    /// `min_fee_bump_ratio` times the ratio of the old transaction.
+    /// A transaction without tip can only be replaced by a transaction with some tip.
+    fn is_sufficient_fee_bump(
+        &self,
+        old_tx: &PoolTransaction,
+        new_tx: &PoolTransaction,
+    ) -> bool {
+        let old_tip = u128::from(old_tx.tip());
+        let new_tip = u128::from(new_tx.tip());
+        if old_tip == 0 {
+            // Any ratio is a bump of zero, so at least some tip is required
+            // to not allow free replacements.
+            return new_tip > 0
+        }
+        let old_gas = u128::from(old_tx.max_gas().max(1));
+        let new_gas = u128::from(new_tx.max_gas().max(1));
+
+        // (new_tip / new_gas) / (old_tip / old_gas)
+        let bump = Ratio::new(
+            new_tip.saturating_mul(old_gas),
+            old_tip.saturating_mul(new_gas),
+        );
+        let min_bump = Ratio::new(
+            u128::from(*self.config.min_fee_bump_ratio.numer()),
+            u128::from(*self.config.min_fee_bump_ratio.denom()),
+        );
+
+        bump >= min_bump
+    }
+
+    fn record_insertion_result(&self, result: &Result<Vec<ArcPoolTx>, Error>) {
+        if self.config.metrics {
+            match result {
+                Ok(_) => txpool_metrics().transactions_inserted.inc(),
+                Err(err) => txpool_metrics().transaction_rejected(err.variant_name()),
+            }
+        }
+    }
+
+    /// Insert a batch of transactions into the pool atomically.
+    ///
+    /// Transactions are reordered so that a parent from the batch is inserted
+    /// before its dependents. If any transaction is rejected or evicts another
+    /// transaction of the batch, every transaction inserted by this call is removed
+    /// and the transactions they evicted are restored with their original metadata,
+    /// leaving the pool as it was before the call.
+    ///
+    /// The insertion metrics are recorded only for the committed batch.
+    pub fn insert_batch(
+        &mut self,
+        txs: Vec<ArcPoolTx>,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<BatchInsertResult, Error> {
+        let result = self.insert_batch_inner(txs, persistent_storage);
+        if self.config.metrics {
+            match &result {
+                Ok(result) => {
+                    for _ in &result.inserted {
+                        txpool_metrics().transactions_inserted.inc();
+                    }
+                }
+                Err(err) => txpool_metrics().transaction_rejected(err.variant_name()),
+            }
+        }
+        result
+    }
+
+    fn insert_batch_inner(
+        &mut self,
+        txs: Vec<ArcPoolTx>,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<BatchInsertResult, Error> {
+        let mut inserted = HashSet::with_capacity(txs.len());
+        let mut inserted_in_order = Vec::with_capacity(txs.len());
+        let mut evicted: RemovedTransactions = vec![];
+
+        for tx in order_by_dependencies(txs) {
+            let tx_id = tx.id();
+            let removed = match self.insert_entry(
+                tx,
+                &TxPriority::default(),
+                None,
+                persistent_storage,
+            ) {
+                Ok(removed) => removed,
+                Err(err) => {
+                    self.rollback_batch(inserted_in_order, evicted, persistent_storage);
+                    return Err(err)
+                }
+            };
+
+            let evicted_member = removed
+                .iter()
+                .map(|entry| entry.transaction.id())
+                .find(|removed_tx_id| inserted.contains(removed_tx_id));
+            inserted.insert(tx_id);
+            inserted_in_order.push(tx_id);
+            evicted.extend(removed);
+
+            if let Some(evicted_member) = evicted_member {
+                self.rollback_batch(inserted_in_order, evicted, persistent_storage);
+                return Err(Error::EvictedByBatch(evicted_member))
+            }
+        }
+
+        Ok(BatchInsertResult {
+            inserted: inserted_in_order,
+            evicted: evicted.into_iter().map(|data| data.transaction).collect(),
+        })
+    }
+
+    fn rollback_batch(
+        &mut self,
+        inserted: Vec<TxId>,
+        evicted: RemovedTransactions,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) {
+        let batch_tx_ids = inserted.iter().copied().collect::<HashSet<_>>();
+
+        // Dependents of the batch transactions can only be the batch
+        // transactions themselves, so removing the subtrees is enough.
+        self.remove_transaction_and_dependents(inserted);
+
+        // Evicted transactions are returned in the order parent -> dependents,
+        // so restoring them in the same order restores the dependencies.
+        for entry in evicted {
+            let tx_id = entry.transaction.id();
+            if batch_tx_ids.contains(&tx_id) {
+                // The transaction was a part of the batch.
+                continue
+            }
+
+            // The batch is removed, so the evicted transactions fit into the pool again.
+            let result = self.restore(entry, persistent_storage);
+            debug_assert!(
+                result.as_ref().is_ok_and(|removed| removed.is_empty()),
+                "The transaction {tx_id} should be restored without side effects"
+            );
+            if let Err(err) = result {
+                tracing::error!(
+                    "Failed to restore the transaction {} evicted by a rolled back batch: {}",
+                    tx_id,
+                    err
+                );
+            }
+        }
+    }
+
+    fn insert_inner(
+        &mut self,
+        tx: ArcPoolTx,
+        priority: &TxPriority,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<Vec<ArcPoolTx>, Error> {
+        let removed = self.insert_entry(tx, priority, None, persistent_storage)?;
+        Ok(removed.into_iter().map(|data| data.transaction).collect())
+    }
+
+    /// Puts back the transaction removed from the pool by this call, keeping its
+    /// creation instant and replacement count, so its FIFO position is unchanged.
+    fn restore(
+        &mut self,
+        entry: StorageData,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<RemovedTransactions, Error> {
+        let metadata = (entry.creation_instant, entry.replacement_count);
+        self.insert_entry(
+            entry.transaction,
+            &TxPriority::default(),
+            Some(metadata),
+            persistent_storage,
+        )
+    }
+
+    /// Inserts the transaction and returns the storage entries of the removed transactions.
+    /// The `restored` creation instant and replacement count are used instead of the
+    /// new ones when the transaction was already in the pool before.
+    fn insert_entry(
+        &mut self,
+        tx: ArcPoolTx,
+        priority: &TxPriority,
+        restored: Option<(SystemTime, u32)>,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<RemovedTransactions, Error> {
         let CanStoreTransaction {
             checked_transaction,
             transactions_to_remove,
             collisions,
             _guard,
-        } = self.can_insert_transaction(tx, persistent_storage)?;
+        } =
+            self.can_insert_transaction_with_priority(tx, priority, persistent_storage)?;
 
         let has_dependencies = !checked_transaction.all_dependencies().is_empty();
 
@@ -119,6 +496,14 @@ where
             removed_transactions.extend(removed);
         }
 
+        // Each fee bump continues the count of the most contested replaced transaction.
+        let replacement_count = collisions
+            .keys()
+            .filter_map(|collided_tx| Storage::get(&self.storage, collided_tx))
+            .map(|storage_entry| storage_entry.replacement_count.saturating_add(1))
+            .max()
+            .unwrap_or(0);
+
         for collided_tx in collisions.keys() {
             let removed = self
                 .storage
@@ -131,12 +516,15 @@ where
         let tx = checked_transaction.tx();
         let tx_id = tx.id();
         let gas = tx.max_gas();
-        let creation_instant = SystemTime::now();
+        let (creation_instant, replacement_count) =
+            restored.unwrap_or((SystemTime::now(), replacement_count));
         let bytes_size = tx.metered_bytes_size();
 
-        let storage_id = self
-            .storage
-            .store_transaction(checked_transaction, creation_instant);
+        let storage_id = self.storage.store_transaction(
+            checked_transaction,
+            creation_instant,
+            replacement_count,
+        );
 
         self.current_gas = self.current_gas.saturating_add(gas);
         self.current_bytes_size = self.current_bytes_size.saturating_add(bytes_size);
@@ -153,11 +541,6 @@ where
                 .new_executable_transaction(storage_id, tx);
         }
 
-        let removed_transactions = removed_transactions
-            .into_iter()
-            .map(|data| data.transaction)
-            .collect::<Vec<_>>();
-
         Ok(removed_transactions)
     }
 
@@ -166,13 +549,116 @@ where
         &self,
         tx: ArcPoolTx,
         persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<CanStoreTransaction<S>, Error> {
+        self.can_insert_transaction_with_priority(
+            tx,
+            &TxPriority::default(),
+            persistent_storage,
+        )
+    }
+
+    /// Runs the same checks as [`Pool::insert`] and previews its outcome
+    /// without modifying the pool.
+    pub fn dry_run_insert(
+        &self,
+        tx: ArcPoolTx,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<InsertionPreview, Error> {
+        let CanStoreTransaction {
+            checked_transaction,
+            transactions_to_remove,
+            collisions,
+            _guard,
+        } = self.can_insert_transaction(tx, persistent_storage)?;
+
+        let executable = checked_transaction.all_dependencies().is_empty();
+
+        // `insert` removes the subtrees of the less worth and collided transactions.
+        let mut to_visit = transactions_to_remove;
+        to_visit.extend(collisions.keys().copied());
+        let mut visited = HashSet::new();
+        let mut removed = vec![];
+        while let Some(storage_id) = to_visit.pop() {
+            if !visited.insert(storage_id) {
+                continue
+            }
+            if let Some(storage_entry) = Storage::get(&self.storage, &storage_id) {
+                removed.push(storage_entry.transaction.id());
+            }
+            to_visit.extend(self.storage.get_direct_dependents(storage_id));
+        }
+
+        Ok(InsertionPreview {
+            removed,
+            executable,
+        })
+    }
+
+    /// Returns the transactions that would be evicted to make room for the transaction,
+    /// without modifying the pool. The dependents of the evicted transactions
+    /// are removed along with them, but are not part of the result.
+    pub fn inspect_eviction_candidates(
+        &self,
+        tx: &PoolTransaction,
+    ) -> Result<Vec<TxId>, Error> {
+        let checked_transaction =
+            self.storage.can_store_transaction(Arc::new(tx.clone()))?;
+
+        let SpaceCheckResult::NotEnoughSpace(left) =
+            self.can_fit_into_pool(&checked_transaction, &TxPriority::default())?
+        else {
+            return Ok(vec![])
+        };
+
+        let candidates = self
+            .find_free_space(left, &checked_transaction)?
+            .iter()
+            .filter_map(|storage_id| self.storage.get(storage_id))
+            .map(|storage_data| storage_data.transaction.id())
+            .collect();
+        Ok(candidates)
+    }
+
+    /// Estimates the predicates of the transaction against the persistent storage.
+    /// Returns the predicate gas of each input, zero for inputs without a predicate.
+    pub fn estimate_predicate_gas(
+        &self,
+        tx: &Transaction,
+        consensus_params: &ConsensusParameters,
+        memory: impl Memory,
+        persistent_storage: &impl TxPoolPersistentStorage,
+    ) -> Result<Vec<Word>, Error> {
+        let mut tx = tx.clone();
+        let parameters = CheckPredicateParams::from(consensus_params);
+        tx.estimate_predicates(&parameters, memory, persistent_storage)?;
+
+        let inputs = match &tx {
+            Transaction::Script(tx) => tx.inputs(),
+            Transaction::Create(tx) => tx.inputs(),
+            Transaction::Upgrade(tx) => tx.inputs(),
+            Transaction::Upload(tx) => tx.inputs(),
+            Transaction::Blob(tx) => tx.inputs(),
+            Transaction::Mint(_) => return Ok(vec![]),
+        };
+
+        Ok(inputs
+            .iter()
+            .map(|input| input.predicate_gas_used().unwrap_or_default())
+            .collect())
+    }
+
+    fn can_insert_transaction_with_priority(
+        &self,
+        tx: ArcPoolTx,
+        priority: &TxPriority,
+        persistent_storage: &impl TxPoolPersistentStorage,
     ) -> Result<CanStoreTransaction<S>, Error> {
         if tx.max_gas() == 0 {
             return Err(Error::InputValidation(InputValidationError::MaxGasZero))
         }
 
         let tx_id = tx.id();
-        if self.tx_id_to_storage_id.contains_key(&tx_id) {
+        if self.contains(&tx_id) {
             return Err(Error::InputValidation(InputValidationError::DuplicateTxId(
                 tx_id,
             )))
@@ -182,7 +668,9 @@ where
             .black_list
             .check_blacklisting(&tx)
             .map_err(Error::Blacklisted)?;
+        self.config.allow_list.check_allowlist(&tx)?;
 
+        self.check_blob_size(&tx)?;
         Self::check_blob_does_not_exist(&tx, persistent_storage)?;
         self.storage.validate_inputs(
             &tx,
@@ -193,6 +681,10 @@ where
         let collisions = self.collision_manager.find_collisions(&tx)?;
         let checked_transaction = self.storage.can_store_transaction(tx)?;
 
+        if self.creates_dependency_cycle(&checked_transaction) {
+            return Err(Error::DependencyCycle)
+        }
+
         for collision in collisions.keys() {
             if checked_transaction.all_dependencies().contains(collision) {
                 return Err(Error::Dependency(
@@ -211,7 +703,7 @@ where
             )
             .map_err(Error::Collided)?;
 
-        let can_fit_into_pool = self.can_fit_into_pool(&checked_transaction)?;
+        let can_fit_into_pool = self.can_fit_into_pool(&checked_transaction, priority)?;
 
         let mut transactions_to_remove = vec![];
         if let SpaceCheckResult::NotEnoughSpace(left) = can_fit_into_pool {
@@ -228,33 +720,207 @@ where
         Ok(can_store_transaction)
     }
 
+    /// Checks whether any dependency of the transaction spends one of its coins.
+    /// Adding the edges of the transaction to the dependency graph would close a cycle
+    /// with such a dependency, which breaks the traversal of the dependents.
+    ///
+    /// The spenders are looked up in the collision manager by the id of the transaction,
+    /// so the check doesn't walk the inputs of the dependencies.
+    fn creates_dependency_cycle(
+        &self,
+        checked_transaction: &S::CheckedTransaction,
+    ) -> bool {
+        let all_dependencies = checked_transaction.all_dependencies();
+        if all_dependencies.is_empty() {
+            return false
+        }
+
+        let tx_id = checked_transaction.tx().id();
+        self.collision_manager
+            .get_coins_spenders(&tx_id)
+            .iter()
+            .any(|spender| all_dependencies.contains(spender))
+    }
+
     // TODO: Use block space also (https://github.com/FuelLabs/fuel-core/issues/2133)
     /// Extract transactions for a block.
     /// Returns a list of transactions that were selected for the block
     /// based on the constraints given in the configuration and the selection algorithm used.
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(count = tracing::field::Empty, total_gas = tracing::field::Empty)
+    )]
     pub fn extract_transactions_for_block(
         &mut self,
         constraints: Constraints,
     ) -> Vec<ArcPoolTx> {
-        self.selection_algorithm
-            .gather_best_txs(constraints, &mut self.storage)
+        let selection_start = Instant::now();
+        let selected = self
+            .selection_algorithm
+            .gather_best_txs(constraints, &mut self.storage);
+        if self.config.metrics {
+            txpool_metrics().record_selection(
+                selection_start.elapsed().as_secs_f64(),
+                selected.len(),
+            );
+        }
+
+        let now = SystemTime::now();
+        let txs = selected
             .into_iter()
             .map(|storage_entry| {
                 self.update_components_and_caches_on_removal(iter::once(&storage_entry));
+                if self.config.metrics {
+                    let time_in_pool = now
+                        .duration_since(storage_entry.creation_instant)
+                        .unwrap_or_default();
+                    txpool_metrics()
+                        .time_to_inclusion
+                        .observe(time_in_pool.as_secs_f64());
+                }
 
                 storage_entry.transaction
             })
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>();
+
+        let total_gas = txs
+            .iter()
+            .fold(0u64, |total_gas, tx| total_gas.saturating_add(tx.max_gas()));
+        let span = tracing::Span::current();
+        span.record("count", txs.len());
+        span.record("total_gas", total_gas);
+
+        txs
+    }
+
+    /// Returns the ids of the transactions that [`Self::extract_transactions_for_block`]
+    /// would extract under the constraints, without modifying the pool.
+    pub fn peek_block_txs(&self, constraints: Constraints) -> Vec<TxId> {
+        self.selection_algorithm
+            .peek_best_txs(constraints, &self.storage)
+            .iter()
+            .map(|storage_entry| storage_entry.transaction.id())
+            .collect()
+    }
+
+    /// Returns the ids of up to `k` best transactions that fit into the constraints
+    /// without extracting them from the pool.
+    ///
+    /// Only transactions that are executable right now are considered,
+    /// their dependents are not.
+    pub fn top_k_transactions(&self, k: usize, constraints: &Constraints) -> Vec<TxId> {
+        self.selection_algorithm
+            .top_k(k, constraints, &self.storage)
+            .iter()
+            .filter_map(|storage_id| Storage::get(&self.storage, storage_id))
+            .map(|storage_entry| storage_entry.transaction.id())
+            .collect()
     }
 
     pub fn find_one(&self, tx_id: &TxId) -> Option<&StorageData> {
         Storage::get(&self.storage, self.tx_id_to_storage_id.get(tx_id)?)
     }
 
+    /// Returns `true` if the transaction is in the pool.
+    /// Unlike [`Self::find_one`], it doesn't access the storage.
     pub fn contains(&self, tx_id: &TxId) -> bool {
         self.tx_id_to_storage_id.contains_key(tx_id)
     }
 
+    /// Returns the ids of pending transactions that spend the coin UTXO.
+    pub fn get_pending_for_utxo(&self, utxo_id: &UtxoId) -> Vec<TxId> {
+        self.collision_manager
+            .get_coin_spender(utxo_id)
+            .and_then(|storage_id| Storage::get(&self.storage, &storage_id))
+            .map(|storage_entry| storage_entry.transaction.id())
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns how many fee bumps replaced the previous occupants of the inputs
+    /// of the transaction, or `None` if the transaction is not in the pool.
+    pub fn replacement_count_for(&self, tx_id: &TxId) -> Option<u32> {
+        self.find_one(tx_id)
+            .map(|storage_entry| storage_entry.replacement_count)
+    }
+
+    /// Returns the ids of pending transactions that collide with `tx` and
+    /// would be replaced by it if it is inserted.
+    pub fn collided_tx_ids(&self, tx: &PoolTransaction) -> Vec<TxId> {
+        self.collision_manager
+            .find_collisions(tx)
+            .unwrap_or_default()
+            .keys()
+            .filter_map(|storage_id| Storage::get(&self.storage, storage_id))
+            .map(|storage_entry| storage_entry.transaction.id())
+            .collect()
+    }
+
+    /// Returns a snapshot summarizing the current state of the pool.
+    pub fn stats(&self) -> PoolStats {
+        let tx_count = self.storage.count();
+        let executable_tx_count =
+            self.selection_algorithm.number_of_executable_transactions();
+
+        let now = SystemTime::now();
+        let mut ages = Vec::with_capacity(self.tx_id_to_storage_id.len());
+        let mut min_tip_gas_ratio: Option<RatioTipGas> = None;
+        let mut max_tip_gas_ratio: Option<RatioTipGas> = None;
+        for storage_id in self.tx_id_to_storage_id.values() {
+            let Some(storage_entry) = Storage::get(&self.storage, storage_id) else {
+                continue
+            };
+            ages.push(
+                now.duration_since(storage_entry.creation_instant)
+                    .unwrap_or_default(),
+            );
+            let transaction = &storage_entry.transaction;
+            let ratio = RatioTipGas::new(transaction.tip(), transaction.max_gas());
+            min_tip_gas_ratio =
+                Some(min_tip_gas_ratio.map_or(ratio, |min| min.min(ratio)));
+            max_tip_gas_ratio =
+                Some(max_tip_gas_ratio.map_or(ratio, |max| max.max(ratio)));
+        }
+
+        let p99_age = if ages.is_empty() {
+            Duration::ZERO
+        } else {
+            // The nearest-rank percentile.
+            let rank = ages.len().saturating_mul(99).div_ceil(100);
+            *ages.select_nth_unstable(rank.saturating_sub(1)).1
+        };
+
+        PoolStats {
+            current_gas: self.current_gas,
+            current_bytes_size: self.current_bytes_size,
+            tx_count,
+            executable_tx_count,
+            pending_tx_count: tx_count.saturating_sub(executable_tx_count),
+            min_tip_gas_ratio,
+            max_tip_gas_ratio,
+            p99_age,
+        }
+    }
+
+    /// Returns how long the transaction has been in the pool.
+    pub fn transaction_age(&self, tx_id: &TxId) -> Option<Duration> {
+        let storage_entry = self.find_one(tx_id)?;
+        Some(
+            SystemTime::now()
+                .duration_since(storage_entry.creation_instant)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Returns the number of hops from the transaction to the farthest
+    /// executable transaction it depends on. Executable transactions have depth `0`.
+    /// The insertion is rejected if the depth exceeds `Config::max_dependent_depth`.
+    pub fn transaction_dependency_depth(&self, tx_id: &TxId) -> Option<usize> {
+        let storage_id = self.tx_id_to_storage_id.get(tx_id)?;
+        self.storage.transaction_dependency_depth(storage_id).ok()
+    }
+
     pub fn iter_tx_ids(&self) -> impl Iterator<Item = &TxId> {
         self.tx_id_to_storage_id.keys()
     }
@@ -306,14 +972,24 @@ where
     fn can_fit_into_pool(
         &self,
         checked_transaction: &S::CheckedTransaction,
+        priority: &TxPriority,
     ) -> Result<SpaceCheckResult, Error> {
+        if priority.bypass_pool_limit {
+            return Ok(SpaceCheckResult::EnoughSpace);
+        }
+
         let tx = checked_transaction.tx();
         let tx_gas = tx.max_gas();
         let bytes_size = tx.metered_bytes_size();
         let gas_left = self.current_gas.saturating_add(tx_gas);
         let bytes_left = self.current_bytes_size.saturating_add(bytes_size);
         let txs_left = self.tx_id_to_storage_id.len().saturating_add(1);
-        if gas_left <= self.config.pool_limits.max_gas
+        let max_gas = self
+            .config
+            .pool_limits
+            .max_gas
+            .saturating_add(priority.reserved_gas_slot);
+        if gas_left <= max_gas
             && bytes_left <= self.config.pool_limits.max_bytes_size
             && txs_left <= self.config.pool_limits.max_txs
         {
@@ -331,6 +1007,7 @@ where
             gas_left,
             bytes_left,
             txs_left,
+            max_gas,
         };
 
         Ok(SpaceCheckResult::NotEnoughSpace(left))
@@ -352,20 +1029,22 @@ where
             mut gas_left,
             mut bytes_left,
             mut txs_left,
+            max_gas,
         } = left;
 
         // Here the transaction has no dependencies which means that it's an executable transaction
         // and we want to make space for it
-        let new_tx_ratio = Ratio::new(tx.tip(), tx.max_gas());
+        let weights = self.config.eviction_weights;
+        let new_tx_score = weights.score(tx.tip(), tx.max_gas(), tx.metered_bytes_size());
 
         // We want to go over executable transactions and remove the less profitable ones.
         // It is imported to go over executable transactions, not dependent ones, because we
         // can include the same subtree several times in the calculation if we use dependent txs.
-        let mut sorted_txs = self.selection_algorithm.get_less_worth_txs();
+        let mut sorted_txs = self.less_worth_txs(&weights);
 
         let mut transactions_to_remove = vec![];
 
-        while gas_left > self.config.pool_limits.max_gas
+        while gas_left > max_gas
             || bytes_left > self.config.pool_limits.max_bytes_size
             || txs_left > self.config.pool_limits.max_txs
         {
@@ -388,12 +1067,15 @@ where
                 );
                 continue
             };
-            let ratio = Ratio::new(
-                storage_data.dependents_cumulative_tip,
-                storage_data.dependents_cumulative_gas,
-            );
+            let score = Self::subtree_score(&weights, storage_data);
 
-            if ratio > new_tx_ratio {
+            if score > new_tx_score {
+                // The new transaction is worth less than anything in the pool.
+                if transactions_to_remove.is_empty() {
+                    return Err(Error::PoolOverloaded {
+                        retry_after: self.estimate_retry_after(),
+                    })
+                }
                 return Err(Error::NotInsertedLimitHit);
             }
 
@@ -427,11 +1109,90 @@ where
         Ok(transactions_to_remove)
     }
 
+    /// Estimates the time after which the pool is likely to have room again.
+    ///
+    /// Transactions leave the pool at the latest when their TTL expires, so half of
+    /// the pool is freed when the median transaction is pruned. Since pruning runs
+    /// periodically, the estimate is never lower than the TTL check interval.
+    fn estimate_retry_after(&self) -> Duration {
+        let now = SystemTime::now();
+        let mut ages: Vec<Duration> = self
+            .tx_id_to_storage_id
+            .values()
+            .filter_map(|storage_id| Storage::get(&self.storage, storage_id))
+            .map(|storage_entry| {
+                now.duration_since(storage_entry.creation_instant)
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        let median_age = if ages.is_empty() {
+            Duration::ZERO
+        } else {
+            let middle = ages.len().saturating_div(2);
+            *ages.select_nth_unstable(middle).1
+        };
+
+        self.config
+            .max_txs_ttl
+            .saturating_sub(median_age)
+            .max(self.config.ttl_check_interval)
+    }
+
+    /// Returns executable transactions starting from the less worth ones.
+    ///
+    /// If bytes have no weight, the order of the selection algorithm is used.
+    /// Otherwise, transactions are sorted by the score of their subtree,
+    /// since the selection algorithm doesn't take the size into account.
+    fn less_worth_txs(
+        &self,
+        weights: &EvictionWeights,
+    ) -> Box<dyn Iterator<Item = &S::StorageIndex> + '_> {
+        if weights.tip_per_byte == 0 {
+            return Box::new(self.selection_algorithm.get_less_worth_txs())
+        }
+
+        let mut scored_txs = self
+            .selection_algorithm
+            .get_less_worth_txs()
+            .filter_map(|storage_id| {
+                let storage_data = self.storage.get(storage_id)?;
+                Some((Self::subtree_score(weights, storage_data), storage_id))
+            })
+            .collect::<Vec<_>>();
+        scored_txs.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+        Box::new(scored_txs.into_iter().map(|(_, storage_id)| storage_id))
+    }
+
+    fn subtree_score(
+        weights: &EvictionWeights,
+        storage_data: &StorageData,
+    ) -> Ratio<u128> {
+        weights.score(
+            storage_data.dependents_cumulative_tip,
+            storage_data.dependents_cumulative_gas,
+            storage_data.dependents_cumulative_bytes_size,
+        )
+    }
+
     /// Remove transaction and its dependents.
     pub fn remove_transaction_and_dependents(
         &mut self,
         tx_ids: Vec<TxId>,
     ) -> Vec<ArcPoolTx> {
+        self.remove_entries_and_dependents(tx_ids)
+            .into_iter()
+            .map(|data| data.transaction)
+            .collect()
+    }
+
+    /// Remove transaction and its dependents, returning their storage entries
+    /// in the order parent -> dependents.
+    fn remove_entries_and_dependents(
+        &mut self,
+        tx_ids: Vec<TxId>,
+    ) -> RemovedTransactions {
         let mut removed_transactions = vec![];
         for tx_id in tx_ids {
             if let Some(storage_id) = self.tx_id_to_storage_id.remove(&tx_id) {
@@ -439,13 +1200,135 @@ where
                     .storage
                     .remove_transaction_and_dependents_subtree(storage_id);
                 self.update_components_and_caches_on_removal(removed.iter());
-                removed_transactions
-                    .extend(removed.into_iter().map(|data| data.transaction));
+                removed_transactions.extend(removed);
             }
         }
         removed_transactions
     }
 
+    /// Removes the transaction with the given id along with its dependents,
+    /// e.g. when the user cancels it explicitly.
+    /// Returns an error if the transaction is not in the pool.
+    pub fn remove_by_id(&mut self, tx_id: &TxId) -> Result<Vec<ArcPoolTx>, Error> {
+        if !self.contains(tx_id) {
+            return Err(Error::TransactionNotFound(*tx_id))
+        }
+        Ok(self.remove_transaction_and_dependents(vec![*tx_id]))
+    }
+
+    /// Removes all transactions from the pool, leaving it empty and ready
+    /// for new insertions. Returns the removed transactions.
+    pub fn clear(&mut self) -> Vec<ArcPoolTx> {
+        let tx_ids = self.tx_id_to_storage_id.keys().copied().collect();
+        let removed_transactions = self.remove_transaction_and_dependents(tx_ids);
+        debug_assert!(self.is_empty());
+        self.tx_id_to_storage_id.clear();
+        self.current_gas = 0;
+        self.current_bytes_size = 0;
+        removed_transactions
+    }
+
+    /// Evicts the less worth transactions along with their dependents until
+    /// a transaction with `needed_gas` and `needed_bytes` fits into the gas and
+    /// bytes limits of the pool. Returns the evicted transactions.
+    ///
+    /// Returns an error without evicting anything if the space can't be freed.
+    pub fn evict_least_valuable_to_fit(
+        &mut self,
+        needed_gas: u64,
+        needed_bytes: usize,
+    ) -> Result<Vec<ArcPoolTx>, Error> {
+        let max_gas = self.config.pool_limits.max_gas;
+        let max_bytes_size = self.config.pool_limits.max_bytes_size;
+        let mut gas_left = self.current_gas.saturating_add(needed_gas);
+        let mut bytes_left = self.current_bytes_size.saturating_add(needed_bytes);
+
+        let weights = self.config.eviction_weights;
+        let mut sorted_txs = self.less_worth_txs(&weights);
+        let mut transactions_to_remove = vec![];
+
+        while gas_left > max_gas || bytes_left > max_bytes_size {
+            let storage_id = sorted_txs.next().ok_or(Error::NotInsertedLimitHit)?;
+            let Some(storage_data) = self.storage.get(storage_id) else {
+                debug_assert!(
+                    false,
+                    "Storage data not found for one of the less worth transactions"
+                );
+                tracing::warn!(
+                    "Storage data not found for one of the less \
+                    worth transactions during `evict_least_valuable_to_fit`."
+                );
+                continue
+            };
+            gas_left = gas_left.saturating_sub(storage_data.dependents_cumulative_gas);
+            bytes_left =
+                bytes_left.saturating_sub(storage_data.dependents_cumulative_bytes_size);
+            transactions_to_remove.push(*storage_id);
+        }
+        drop(sorted_txs);
+
+        let mut removed_transactions = vec![];
+        for storage_id in transactions_to_remove {
+            let removed = self
+                .storage
+                .remove_transaction_and_dependents_subtree(storage_id);
+            self.update_components_and_caches_on_removal(removed.iter());
+            removed_transactions.extend(removed.into_iter().map(|data| data.transaction));
+        }
+        Ok(removed_transactions)
+    }
+
+    /// Replaces the limits of the pool.
+    ///
+    /// If the pool exceeds the new limits, the less worth transactions are evicted
+    /// along with their dependents until the limits are satisfied.
+    /// Returns the evicted transactions.
+    pub fn set_pool_limits(&mut self, limits: PoolLimits) -> Vec<ArcPoolTx> {
+        self.config.pool_limits = limits;
+        let weights = self.config.eviction_weights;
+
+        // Executable transactions have no dependencies, so removing the subtree of one
+        // of them doesn't affect the others, and they are sorted only once.
+        let less_worth_txs = self.less_worth_txs(&weights).copied().collect::<Vec<_>>();
+
+        let mut removed_transactions = vec![];
+        for storage_id in less_worth_txs {
+            if self.current_gas <= self.config.pool_limits.max_gas
+                && self.current_bytes_size <= self.config.pool_limits.max_bytes_size
+                && self.tx_id_to_storage_id.len() <= self.config.pool_limits.max_txs
+            {
+                break
+            }
+            let removed = self
+                .storage
+                .remove_transaction_and_dependents_subtree(storage_id);
+            self.update_components_and_caches_on_removal(removed.iter());
+            removed_transactions.extend(removed.into_iter().map(|data| data.transaction));
+        }
+        removed_transactions
+    }
+
+    /// Replaces the blacklist of the pool.
+    ///
+    /// Transactions in the pool forbidden by the new blacklist are removed
+    /// along with their dependents. Returns the removed transactions.
+    pub fn update_blacklist(&mut self, black_list: BlackList) -> Vec<ArcPoolTx> {
+        self.config.black_list = black_list;
+        let blacklisted_tx_ids = self
+            .tx_id_to_storage_id
+            .iter()
+            .filter_map(|(tx_id, storage_id)| {
+                let storage_entry = Storage::get(&self.storage, storage_id)?;
+                self.config
+                    .black_list
+                    .check_blacklisting(&storage_entry.transaction)
+                    .err()
+                    .map(|_| *tx_id)
+            })
+            .collect();
+        self.remove_transaction_and_dependents(blacklisted_tx_ids)
+    }
+
     pub fn remove_coin_dependents(&mut self, tx_id: TxId) -> Vec<ArcPoolTx> {
         let mut txs_removed = vec![];
         let coin_dependents = self.collision_manager.get_coins_spenders(&tx_id);
@@ -459,6 +1342,37 @@ where
         txs_removed
     }
 
+    /// Removes the transactions spending any of the `invalidated_utxos` along with
+    /// their dependents. It is used after a reorg when the UTXOs created by the
+    /// orphaned blocks may have different values in the new chain.
+    ///
+    /// Returns the removed transactions, so they can be re-submitted.
+    pub fn on_reorg(&mut self, invalidated_utxos: &[UtxoId]) -> Vec<ArcPoolTx> {
+        let mut txs_removed = vec![];
+        for utxo_id in invalidated_utxos {
+            // The spender may be already removed as a dependent of previous one.
+            if let Some(spender) = self.collision_manager.get_coin_spender(utxo_id) {
+                let removed = self
+                    .storage
+                    .remove_transaction_and_dependents_subtree(spender);
+                self.update_components_and_caches_on_removal(removed.iter());
+                txs_removed.extend(removed.into_iter().map(|data| data.transaction));
+            }
+        }
+        txs_removed
+    }
+
+    fn check_blob_size(&self, tx: &PoolTransaction) -> Result<(), Error> {
+        if let PoolTransaction::Blob(..) = tx {
+            let size = tx.metered_bytes_size();
+            let max = self.config.max_blob_tx_bytes;
+            if size > max {
+                return Err(Error::BlobTooLarge { size, max })
+            }
+        }
+        Ok(())
+    }
+
     fn check_blob_does_not_exist(
         tx: &PoolTransaction,
         persistent_storage: &impl TxPoolPersistentStorage,
@@ -495,10 +1409,97 @@ where
     }
 }
 
+/// The priority of an operator-designated transaction inserted with
+/// `Pool::insert_with_priority`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TxPriority {
+    /// Inserts the transaction even if the pool limits are hit,
+    /// without evicting other transactions.
+    pub bypass_pool_limit: bool,
+    /// The additional gas above `PoolLimits::max_gas` that the transaction may use.
+    pub reserved_gas_slot: u64,
+}
+
+/// The result of the successful `Pool::insert_batch`.
+#[derive(Debug)]
+pub struct BatchInsertResult {
+    /// Ids of the batch transactions that are now in the pool.
+    pub inserted: Vec<TxId>,
+    /// Transactions removed from the pool to make room for the batch.
+    pub evicted: Vec<ArcPoolTx>,
+}
+
+/// The outcome of the insertion previewed by `Pool::dry_run_insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InsertionPreview {
+    /// Ids of the transactions that the insertion would remove from the pool.
+    pub removed: Vec<TxId>,
+    /// Whether the transaction would be executable right after the insertion.
+    pub executable: bool,
+}
+
+/// Orders the transactions of a batch so that every transaction comes after
+/// the transactions of the batch whose coin outputs it spends. The relative
+/// order of independent transactions is preserved.
+fn order_by_dependencies(txs: Vec<ArcPoolTx>) -> Vec<ArcPoolTx> {
+    let batch_tx_ids = txs.iter().map(|tx| tx.id()).collect::<HashSet<_>>();
+    let mut placed = HashSet::<TxId>::with_capacity(txs.len());
+    let mut ordered = Vec::with_capacity(txs.len());
+    let mut pending = txs;
+
+    while !pending.is_empty() {
+        let (ready, not_ready): (Vec<_>, Vec<_>) = pending.into_iter().partition(|tx| {
+            tx.inputs()
+                .iter()
+                .filter_map(|input| input.utxo_id())
+                .all(|utxo_id| {
+                    !batch_tx_ids.contains(utxo_id.tx_id())
+                        || placed.contains(utxo_id.tx_id())
+                })
+        });
+
+        if ready.is_empty() {
+            // The remaining transactions depend on each other in a cycle,
+            // the pool rejects them on insertion.
+            ordered.extend(not_ready);
+            break
+        }
+
+        placed.extend(ready.iter().map(|tx| tx.id()));
+        ordered.extend(ready);
+        pending = not_ready;
+    }
+
+    ordered
+}
+
+/// The snapshot of the pool state returned by [`Pool::stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Total gas of all transactions in the pool.
+    pub current_gas: u64,
+    /// Total size of all transactions in the pool in bytes.
+    pub current_bytes_size: usize,
+    /// Number of transactions in the pool.
+    pub tx_count: usize,
+    /// Number of transactions without dependencies in the pool, ready for the selection.
+    pub executable_tx_count: usize,
+    /// Number of transactions waiting for their dependencies to be included.
+    pub pending_tx_count: usize,
+    /// The lowest tip/gas ratio among the transactions in the pool.
+    pub min_tip_gas_ratio: Option<RatioTipGas>,
+    /// The highest tip/gas ratio among the transactions in the pool.
+    pub max_tip_gas_ratio: Option<RatioTipGas>,
+    /// The 99th percentile of the time the transactions have spent in the pool.
+    pub p99_age: Duration,
+}
+
 pub struct NotEnoughSpace {
     gas_left: u64,
     bytes_left: usize,
     txs_left: usize,
+    /// The gas limit of the pool, including the reserved gas slot of the transaction.
+    max_gas: u64,
 }
 
 /// The result of the `can_fit_into_pool` check.
@@ -0,0 +1,148 @@
+use std::{
+    collections::BTreeMap,
+    time::SystemTime,
+};
+
+use fuel_core_types::fuel_tx::TxId;
+
+use crate::storage::{
+    RemovedTransactions,
+    StorageData,
+};
+
+use super::{
+    gather_best_txs_in_order,
+    peek_best_txs_in_order,
+    Constraints,
+    PeekStorage,
+    SelectionAlgorithm,
+    SelectionAlgorithmStorage,
+};
+
+/// Key used to sort transactions by the time of insertion.
+/// It first compares the creation instant and then the transaction id.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub struct Key {
+    creation_instant: SystemTime,
+    tx_id: TxId,
+}
+
+/// The selection algorithm that selects transactions in the order they were
+/// inserted into the pool, regardless of the tip they pay.
+pub struct FifoSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    executable_transactions_sorted_creation_instant: BTreeMap<Key, S::StorageIndex>,
+}
+
+impl<S> Default for FifoSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> FifoSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    pub fn new() -> Self {
+        Self {
+            executable_transactions_sorted_creation_instant: BTreeMap::new(),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.executable_transactions_sorted_creation_instant
+            .is_empty()
+    }
+
+    fn key(store_entry: &StorageData) -> Key {
+        Key {
+            creation_instant: store_entry.creation_instant,
+            tx_id: store_entry.transaction.id(),
+        }
+    }
+}
+
+impl<S> SelectionAlgorithm for FifoSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        gather_best_txs_in_order(
+            &mut self.executable_transactions_sorted_creation_instant,
+            Self::key,
+            constraints,
+            storage,
+        )
+    }
+
+    fn top_k(
+        &self,
+        k: usize,
+        constraints: &Constraints,
+        storage: &S,
+    ) -> Vec<Self::StorageIndex> {
+        peek_best_txs_in_order(
+            self.executable_transactions_sorted_creation_instant
+                .values(),
+            k,
+            constraints,
+            storage,
+        )
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        let key = Self::key(store_entry);
+        self.executable_transactions_sorted_creation_instant
+            .insert(key, storage_id);
+    }
+
+    fn peek_best_txs(
+        &self,
+        constraints: Constraints,
+        storage: &S,
+    ) -> RemovedTransactions {
+        let mut executable_transactions =
+            self.executable_transactions_sorted_creation_instant.clone();
+        gather_best_txs_in_order(
+            &mut executable_transactions,
+            Self::key,
+            constraints,
+            &mut PeekStorage::new(storage),
+        )
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        // The most recent transactions are the first to give up their place.
+        self.executable_transactions_sorted_creation_instant
+            .values()
+            .rev()
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        self.executable_transactions_sorted_creation_instant.len()
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        let key = Self::key(storage_entry);
+        self.executable_transactions_sorted_creation_instant
+            .remove(&key);
+    }
+}
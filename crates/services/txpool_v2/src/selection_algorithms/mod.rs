@@ -1,11 +1,56 @@
-use crate::storage::{
-    RemovedTransactions,
-    StorageData,
+//! The selection algorithm used by the pool can be chosen at runtime with
+//! [`SelectionAlgorithmKind`]. The [`SelectionAlgorithm`] trait is not object safe because of its
+//! `impl Iterator` return types, so instead of boxing the algorithm, the pool uses
+//! [`ConfiguredSelection`] - an enum that dispatches each call to the selected algorithm.
+//! Iterators returned by the algorithms are boxed to unify their types.
+
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        HashSet,
+    },
+    fmt::Debug,
+    hash::Hash,
+};
+
+use crate::{
+    config::SelectionAlgorithmKind,
+    error::Error,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::Address,
+    services::txpool::PoolTransaction,
 };
 
+pub mod fifo;
 pub mod ratio_tip_gas;
 
+use fifo::FifoSelection;
+use ratio_tip_gas::RatioTipGasSelection;
+
 /// Constraints that the selection algorithm has to respect.
+///
+/// The constraints can be built field by field, starting from the gas limit:
+///
+/// ```
+/// use fuel_core_txpool::Constraints;
+///
+/// let constraints = Constraints::new(30_000_000)
+///     .with_minimal_gas_price(1)
+///     .with_max_txs(100)
+///     .with_max_bytes(1024 * 1024)
+///     .with_max_gas_per_owner(9_000_000);
+///
+/// assert_eq!(constraints.max_gas, 30_000_000);
+/// assert_eq!(constraints.maximum_txs, 100);
+/// assert_eq!(constraints.maximum_block_size, 1024 * 1024);
+/// assert_eq!(constraints.max_gas_per_owner, Some(9_000_000));
+/// ```
 pub struct Constraints {
     /// Minimum gas price that all transaction must support.
     pub minimal_gas_price: u64,
@@ -15,6 +60,98 @@ pub struct Constraints {
     pub maximum_txs: u16,
     /// Maximum size of the block.
     pub maximum_block_size: u32,
+    /// Maximum gas that transactions of a single owner can use in the block.
+    /// The owner of a transaction is the owner of its first input that has one.
+    pub max_gas_per_owner: Option<u64>,
+}
+
+impl Constraints {
+    /// Creates constraints limited only by the gas.
+    /// The number of transactions and the size of the block are unlimited,
+    /// and transactions with any gas price are accepted.
+    pub fn new(max_gas: u64) -> Self {
+        Self {
+            minimal_gas_price: 0,
+            max_gas,
+            maximum_txs: u16::MAX,
+            maximum_block_size: u32::MAX,
+            max_gas_per_owner: None,
+        }
+    }
+
+    /// Sets the minimum gas price that all transactions must support.
+    pub fn with_minimal_gas_price(self, minimal_gas_price: u64) -> Self {
+        Self {
+            minimal_gas_price,
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of transactions that can be selected.
+    pub fn with_max_txs(self, maximum_txs: u16) -> Self {
+        Self {
+            maximum_txs,
+            ..self
+        }
+    }
+
+    /// Sets the maximum size of the block.
+    pub fn with_max_bytes(self, maximum_block_size: u32) -> Self {
+        Self {
+            maximum_block_size,
+            ..self
+        }
+    }
+
+    /// Sets the maximum gas that transactions of a single owner can use in the block.
+    pub fn with_max_gas_per_owner(self, max_gas_per_owner: u64) -> Self {
+        Self {
+            max_gas_per_owner: Some(max_gas_per_owner),
+            ..self
+        }
+    }
+}
+
+/// Tracks the gas used by the selected transactions of each owner
+/// against the [`Constraints::max_gas_per_owner`] quota.
+struct OwnersGasQuota {
+    quota: Option<u64>,
+    used: HashMap<Address, u64>,
+}
+
+impl OwnersGasQuota {
+    fn new(quota: Option<u64>) -> Self {
+        Self {
+            quota,
+            used: HashMap::new(),
+        }
+    }
+
+    fn owner(tx: &PoolTransaction) -> Option<Address> {
+        tx.inputs()
+            .iter()
+            .find_map(|input| input.input_owner())
+            .copied()
+    }
+
+    /// Returns `true` if the owner of the transaction has enough quota left for it.
+    fn fits(&self, tx: &PoolTransaction) -> bool {
+        let (Some(quota), Some(owner)) = (self.quota, Self::owner(tx)) else {
+            return true
+        };
+        let used = self.used.get(&owner).copied().unwrap_or_default();
+        used.saturating_add(tx.max_gas()) <= quota
+    }
+
+    fn charge(&mut self, tx: &PoolTransaction) {
+        if self.quota.is_none() {
+            return
+        }
+        if let Some(owner) = Self::owner(tx) {
+            let used = self.used.entry(owner).or_default();
+            *used = used.saturating_add(tx.max_gas());
+        }
+    }
 }
 
 /// The selection algorithm is responsible for selecting the best transactions to include in a block.
@@ -37,9 +174,470 @@ pub trait SelectionAlgorithm {
         store_entry: &StorageData,
     );
 
+    /// Update the selection algorithm with a batch of transactions that became executable.
+    /// Fails without modifying the selection algorithm if any of the transactions
+    /// is not present in the storage.
+    ///
+    /// The default implementation calls [`Self::new_executable_transaction`] for each transaction.
+    fn new_executable_transactions(
+        &mut self,
+        ids: Vec<Self::StorageIndex>,
+        storage: &Self::Storage,
+    ) -> Result<(), Error>
+    where
+        Self::Storage: SelectionAlgorithmStorage<StorageIndex = Self::StorageIndex>,
+    {
+        let entries = ids
+            .into_iter()
+            .map(|storage_id| {
+                storage
+                    .get(&storage_id)
+                    .map(|store_entry| (storage_id, store_entry))
+                    .ok_or_else(|| missing_executable_transaction(&storage_id))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for (storage_id, store_entry) in entries {
+            self.new_executable_transaction(storage_id, store_entry);
+        }
+        Ok(())
+    }
+
+    /// Returns up to `k` best transactions that fit into the constraints, without removing
+    /// them from the selection algorithm or the storage.
+    fn top_k(
+        &self,
+        k: usize,
+        constraints: &Constraints,
+        storage: &Self::Storage,
+    ) -> Vec<Self::StorageIndex>;
+
+    /// Simulates [`Self::gather_best_txs`] without modifying the selection algorithm
+    /// or the storage. Returns the transactions that would be selected.
+    fn peek_best_txs(
+        &self,
+        constraints: Constraints,
+        storage: &Self::Storage,
+    ) -> RemovedTransactions;
+
     /// Get less worth transactions iterator
     fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex>;
 
+    /// Returns the number of executable transactions known to the selection algorithm.
+    fn number_of_executable_transactions(&self) -> usize;
+
     /// Inform the selection algorithm that a transaction was removed from the pool.
     fn on_removed_transaction(&mut self, storage_entry: &StorageData);
 }
+
+/// The storage interface required by the selection algorithms.
+pub trait SelectionAlgorithmStorage {
+    type StorageIndex: Copy + Debug + Hash + Eq;
+
+    fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData>;
+
+    fn get_dependents(
+        &self,
+        index: &Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex> {
+        self.dependents_slice(index).iter().copied()
+    }
+
+    /// Returns the direct dependents of the transaction as a slice.
+    fn dependents_slice(&self, index: &Self::StorageIndex) -> &[Self::StorageIndex];
+
+    /// Returns the direct dependencies of the transaction.
+    fn get_dependencies(
+        &self,
+        index: &Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex>;
+
+    fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
+
+    fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData>;
+}
+
+/// The selection algorithm chosen by the [`SelectionAlgorithmKind`] from the configuration.
+pub enum ConfiguredSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    RatioTipGas(RatioTipGasSelection<S>),
+    Fifo(FifoSelection<S>),
+}
+
+impl<S> ConfiguredSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    pub fn new(kind: SelectionAlgorithmKind) -> Self {
+        match kind {
+            SelectionAlgorithmKind::RatioTipGas => {
+                Self::RatioTipGas(RatioTipGasSelection::new())
+            }
+            SelectionAlgorithmKind::Fifo => Self::Fifo(FifoSelection::new()),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::RatioTipGas(selection) => selection.is_empty(),
+            Self::Fifo(selection) => selection.is_empty(),
+        }
+    }
+
+    #[cfg(all(test, debug_assertions))]
+    pub fn verify_invariants(&self) {
+        match self {
+            Self::RatioTipGas(selection) => selection.verify_invariants(),
+            Self::Fifo(_) => {}
+        }
+    }
+}
+
+impl<S> SelectionAlgorithm for ConfiguredSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.gather_best_txs(constraints, storage)
+            }
+            Self::Fifo(selection) => selection.gather_best_txs(constraints, storage),
+        }
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::Fifo(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+        }
+    }
+
+    fn new_executable_transactions(
+        &mut self,
+        ids: Vec<Self::StorageIndex>,
+        storage: &S,
+    ) -> Result<(), Error> {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.new_executable_transactions(ids, storage)
+            }
+            Self::Fifo(selection) => selection.new_executable_transactions(ids, storage),
+        }
+    }
+
+    fn top_k(
+        &self,
+        k: usize,
+        constraints: &Constraints,
+        storage: &S,
+    ) -> Vec<Self::StorageIndex> {
+        match self {
+            Self::RatioTipGas(selection) => selection.top_k(k, constraints, storage),
+            Self::Fifo(selection) => selection.top_k(k, constraints, storage),
+        }
+    }
+
+    fn peek_best_txs(
+        &self,
+        constraints: Constraints,
+        storage: &S,
+    ) -> RemovedTransactions {
+        match self {
+            Self::RatioTipGas(selection) => selection.peek_best_txs(constraints, storage),
+            Self::Fifo(selection) => selection.peek_best_txs(constraints, storage),
+        }
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        let iter: Box<dyn Iterator<Item = &Self::StorageIndex> + '_> = match self {
+            Self::RatioTipGas(selection) => Box::new(selection.get_less_worth_txs()),
+            Self::Fifo(selection) => Box::new(selection.get_less_worth_txs()),
+        };
+        iter
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        match self {
+            Self::RatioTipGas(selection) => selection.number_of_executable_transactions(),
+            Self::Fifo(selection) => selection.number_of_executable_transactions(),
+        }
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.on_removed_transaction(storage_entry)
+            }
+            Self::Fifo(selection) => selection.on_removed_transaction(storage_entry),
+        }
+    }
+}
+
+/// Selects executable transactions in the order of `executable_transactions` keys.
+///
+/// If a transaction fits in the constraints, it is selected and its dependents
+/// that have no other dependencies are promoted into `executable_transactions`
+/// using the `key_of` function.
+pub(crate) fn gather_best_txs_in_order<S, K>(
+    executable_transactions: &mut BTreeMap<K, S::StorageIndex>,
+    key_of: impl Fn(&StorageData) -> K,
+    constraints: Constraints,
+    storage: &mut S,
+) -> RemovedTransactions
+where
+    S: SelectionAlgorithmStorage,
+    K: Ord + Copy,
+{
+    let mut gas_left = constraints.max_gas;
+    let mut space_left = constraints.maximum_block_size as usize;
+    let mut nb_left = constraints.maximum_txs;
+    let mut owners_quota = OwnersGasQuota::new(constraints.max_gas_per_owner);
+    let mut result = Vec::new();
+    let mut dependents = Vec::new();
+
+    // Take iterate over all executable transactions in the order of priority. If transaction
+    // fits in the gas limit select it and mark all its dependents to be promoted.
+    // Do that until end of the list or gas limit is reached. If gas limit is not
+    // reached, but we have promoted transactions we can start again from the beginning.
+    // Otherwise, we can break the loop.
+    // It is done in this way to minimize number of iteration of the list of executable
+    // transactions.
+    while gas_left > 0
+        && nb_left > 0
+        && space_left > 0
+        && !executable_transactions.is_empty()
+    {
+        let mut clean_up_list = Vec::new();
+        let mut transactions_to_remove = Vec::new();
+        let mut transactions_to_promote = Vec::new();
+
+        for (key, storage_id) in executable_transactions.iter() {
+            if nb_left == 0 || gas_left == 0 || space_left == 0 {
+                break
+            }
+
+            let Some(stored_transaction) = storage.get(storage_id) else {
+                debug_assert!(
+                    false,
+                    "Transaction not found in the storage during `gather_best_txs`."
+                );
+                tracing::warn!(
+                    "Transaction not found in the storage during `gather_best_txs`."
+                );
+                transactions_to_remove.push(*key);
+                continue
+            };
+
+            let less_price = stored_transaction.transaction.max_gas_price()
+                < constraints.minimal_gas_price;
+
+            if less_price {
+                continue
+            }
+
+            let not_enough_gas = stored_transaction.transaction.max_gas() > gas_left;
+            let too_big_tx =
+                stored_transaction.transaction.metered_bytes_size() > space_left;
+
+            if not_enough_gas || too_big_tx {
+                continue
+            }
+
+            // The owner has exhausted its quota, move to transactions of other owners
+            if !owners_quota.fits(&stored_transaction.transaction) {
+                continue
+            }
+
+            gas_left = gas_left.saturating_sub(stored_transaction.transaction.max_gas());
+            space_left = space_left
+                .saturating_sub(stored_transaction.transaction.metered_bytes_size());
+            nb_left = nb_left.saturating_sub(1);
+            owners_quota.charge(&stored_transaction.transaction);
+
+            dependents.extend_from_slice(storage.dependents_slice(storage_id));
+            debug_assert!(!storage.has_dependencies(storage_id));
+            let removed = storage.remove(storage_id).expect(
+                "We just get the transaction from the storage above, it should exist.",
+            );
+            clean_up_list.push(*key);
+            result.push(removed);
+
+            for dependent in dependents.drain(..) {
+                if !storage.has_dependencies(&dependent) {
+                    transactions_to_promote.push(dependent);
+                }
+            }
+        }
+
+        for remove in transactions_to_remove {
+            executable_transactions.remove(&remove);
+        }
+
+        // If no transaction fits in the gas limit and no one to promote, we can break the loop
+        if clean_up_list.is_empty() && transactions_to_promote.is_empty() {
+            break
+        }
+
+        for key in clean_up_list {
+            // Remove selected transactions from the sorted list
+            executable_transactions.remove(&key);
+        }
+
+        for promote in transactions_to_promote {
+            let storage = storage
+                .get(&promote)
+                .expect("We just get the dependent from the storage, it should exist.");
+
+            executable_transactions.insert(key_of(storage), promote);
+        }
+    }
+
+    result
+}
+
+pub(crate) fn missing_executable_transaction<I: Debug>(storage_id: &I) -> Error {
+    Error::Storage(format!(
+        "Transaction {storage_id:?} that became executable is not found in the storage"
+    ))
+}
+
+/// The view of the storage that simulates removals without modifying the storage.
+/// It allows to run [`gather_best_txs_in_order`] to peek at the selection result.
+pub(crate) struct PeekStorage<'a, S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    storage: &'a S,
+    removed: HashSet<S::StorageIndex>,
+}
+
+impl<'a, S> PeekStorage<'a, S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    pub(crate) fn new(storage: &'a S) -> Self {
+        Self {
+            storage,
+            removed: HashSet::new(),
+        }
+    }
+}
+
+impl<S> SelectionAlgorithmStorage for PeekStorage<'_, S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    type StorageIndex = S::StorageIndex;
+
+    fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData> {
+        if self.removed.contains(index) {
+            return None
+        }
+        self.storage.get(index)
+    }
+
+    fn dependents_slice(&self, index: &Self::StorageIndex) -> &[Self::StorageIndex] {
+        self.storage.dependents_slice(index)
+    }
+
+    fn get_dependencies(
+        &self,
+        index: &Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex> {
+        self.storage
+            .get_dependencies(index)
+            .filter(|dependency| !self.removed.contains(dependency))
+    }
+
+    fn has_dependencies(&self, index: &Self::StorageIndex) -> bool {
+        self.get_dependencies(index).next().is_some()
+    }
+
+    fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData> {
+        let storage_data = self.get(index)?.clone();
+        self.removed.insert(*index);
+        Some(storage_data)
+    }
+}
+
+/// Peeks at the executable transactions in the order of the `executable_transactions`
+/// iterator, applying the same constraints as [`gather_best_txs_in_order`].
+///
+/// Neither the storage nor the selection algorithm are modified, so dependents of
+/// the peeked transactions are not promoted and only currently executable
+/// transactions are returned.
+pub(crate) fn peek_best_txs_in_order<'a, S>(
+    executable_transactions: impl Iterator<Item = &'a S::StorageIndex>,
+    k: usize,
+    constraints: &Constraints,
+    storage: &S,
+) -> Vec<S::StorageIndex>
+where
+    S: SelectionAlgorithmStorage,
+    S::StorageIndex: 'a,
+{
+    let mut gas_left = constraints.max_gas;
+    let mut space_left = constraints.maximum_block_size as usize;
+    let mut nb_left = k.min(usize::from(constraints.maximum_txs));
+    let mut owners_quota = OwnersGasQuota::new(constraints.max_gas_per_owner);
+    let mut result = Vec::new();
+
+    for storage_id in executable_transactions {
+        if nb_left == 0 || gas_left == 0 || space_left == 0 {
+            break
+        }
+
+        let Some(stored_transaction) = storage.get(storage_id) else {
+            debug_assert!(
+                false,
+                "Transaction not found in the storage during `top_k`."
+            );
+            tracing::warn!("Transaction not found in the storage during `top_k`.");
+            continue
+        };
+
+        let less_price = stored_transaction.transaction.max_gas_price()
+            < constraints.minimal_gas_price;
+
+        if less_price {
+            continue
+        }
+
+        let not_enough_gas = stored_transaction.transaction.max_gas() > gas_left;
+        let too_big_tx = stored_transaction.transaction.metered_bytes_size() > space_left;
+
+        if not_enough_gas
+            || too_big_tx
+            || !owners_quota.fits(&stored_transaction.transaction)
+        {
+            continue
+        }
+
+        gas_left = gas_left.saturating_sub(stored_transaction.transaction.max_gas());
+        space_left = space_left
+            .saturating_sub(stored_transaction.transaction.metered_bytes_size());
+        nb_left = nb_left.saturating_sub(1);
+        owners_quota.charge(&stored_transaction.transaction);
+        result.push(*storage_id);
+    }
+
+    result
+}
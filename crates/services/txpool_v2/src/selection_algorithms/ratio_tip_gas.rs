@@ -3,39 +3,34 @@ use std::{
         Ordering,
         Reverse,
     },
-    collections::BTreeMap,
-    fmt::Debug,
+    collections::{
+        BTreeMap,
+        HashSet,
+    },
     time::SystemTime,
 };
 
 use fuel_core_types::fuel_tx::TxId;
 use num_rational::Ratio;
 
-use crate::storage::{
-    RemovedTransactions,
-    StorageData,
+use crate::{
+    error::Error,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+    },
 };
 
 use super::{
+    gather_best_txs_in_order,
+    missing_executable_transaction,
+    peek_best_txs_in_order,
     Constraints,
+    PeekStorage,
     SelectionAlgorithm,
+    SelectionAlgorithmStorage,
 };
 
-pub trait RatioTipGasSelectionAlgorithmStorage {
-    type StorageIndex: Debug;
-
-    fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData>;
-
-    fn get_dependents(
-        &self,
-        index: &Self::StorageIndex,
-    ) -> impl Iterator<Item = Self::StorageIndex>;
-
-    fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
-
-    fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData>;
-}
-
 pub type RatioTipGas = Ratio<u64>;
 
 /// Key used to sort transactions by tip/gas ratio.
@@ -72,14 +67,14 @@ impl PartialOrd for Key {
 /// The selection algorithm that selects transactions based on the tip/gas ratio.
 pub struct RatioTipGasSelection<S>
 where
-    S: RatioTipGasSelectionAlgorithmStorage,
+    S: SelectionAlgorithmStorage,
 {
     executable_transactions_sorted_tip_gas_ratio: BTreeMap<Reverse<Key>, S::StorageIndex>,
 }
 
 impl<S> Default for RatioTipGasSelection<S>
 where
-    S: RatioTipGasSelectionAlgorithmStorage,
+    S: SelectionAlgorithmStorage,
 {
     fn default() -> Self {
         Self::new()
@@ -88,7 +83,7 @@ where
 
 impl<S> RatioTipGasSelection<S>
 where
-    S: RatioTipGasSelectionAlgorithmStorage,
+    S: SelectionAlgorithmStorage,
 {
     pub fn new() -> Self {
         Self {
@@ -101,6 +96,34 @@ where
         self.executable_transactions_sorted_tip_gas_ratio.is_empty()
     }
 
+    /// Returns the executable transactions with their tip/gas ratio in the order
+    /// in which they are selected.
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn debug_order(&self) -> Vec<(TxId, RatioTipGas)> {
+        self.executable_transactions_sorted_tip_gas_ratio
+            .keys()
+            .map(|Reverse(key)| (key.tx_id, key.ratio))
+            .collect()
+    }
+
+    /// Moves the executable transactions of `other` into `self`,
+    /// f.e. when the shards of the pool are merged.
+    /// If a transaction is known to both selections, the entry of `self` is kept.
+    pub fn merge(&mut self, other: RatioTipGasSelection<S>) {
+        let known_tx_ids: HashSet<TxId> = self
+            .executable_transactions_sorted_tip_gas_ratio
+            .keys()
+            .map(|Reverse(key)| key.tx_id)
+            .collect();
+
+        for (key, storage_id) in other.executable_transactions_sorted_tip_gas_ratio {
+            if !known_tx_ids.contains(&key.0.tx_id) {
+                self.executable_transactions_sorted_tip_gas_ratio
+                    .insert(key, storage_id);
+            }
+        }
+    }
+
     fn key(store_entry: &StorageData) -> Key {
         let transaction = &store_entry.transaction;
         let tip_gas_ratio = RatioTipGas::new(transaction.tip(), transaction.max_gas());
@@ -116,11 +139,33 @@ where
         self.executable_transactions_sorted_tip_gas_ratio
             .remove(&Reverse(key));
     }
+
+    /// Panics if a transaction or a storage index is referenced by several keys.
+    /// The check walks the whole selection, so it only runs in debug builds.
+    #[cfg(debug_assertions)]
+    pub fn verify_invariants(&self) {
+        let mut tx_ids = HashSet::new();
+        let mut storage_ids = HashSet::new();
+        for (Reverse(key), storage_id) in
+            &self.executable_transactions_sorted_tip_gas_ratio
+        {
+            assert!(
+                tx_ids.insert(key.tx_id),
+                "Transaction {} is referenced by several keys",
+                key.tx_id
+            );
+            assert!(
+                storage_ids.insert(*storage_id),
+                "Storage index {:?} is referenced by several keys",
+                storage_id
+            );
+        }
+    }
 }
 
 impl<S> SelectionAlgorithm for RatioTipGasSelection<S>
 where
-    S: RatioTipGasSelectionAlgorithmStorage,
+    S: SelectionAlgorithmStorage,
 {
     type Storage = S;
     type StorageIndex = S::StorageIndex;
@@ -130,108 +175,31 @@ where
         constraints: Constraints,
         storage: &mut S,
     ) -> RemovedTransactions {
-        let mut gas_left = constraints.max_gas;
-        let mut space_left = constraints.maximum_block_size as usize;
-        let mut nb_left = constraints.maximum_txs;
-        let mut result = Vec::new();
-
-        // Take iterate over all transactions with the highest tip/gas ratio. If transaction
-        // fits in the gas limit select it and mark all its dependents to be promoted.
-        // Do that until end of the list or gas limit is reached. If gas limit is not
-        // reached, but we have promoted transactions we can start again from the beginning.
-        // Otherwise, we can break the loop.
-        // It is done in this way to minimize number of iteration of the list of executable
-        // transactions.
-        while gas_left > 0
-            && nb_left > 0
-            && space_left > 0
-            && !self.executable_transactions_sorted_tip_gas_ratio.is_empty()
-        {
-            let mut clean_up_list = Vec::new();
-            let mut transactions_to_remove = Vec::new();
-            let mut transactions_to_promote = Vec::new();
-
-            for (key, storage_id) in &self.executable_transactions_sorted_tip_gas_ratio {
-                if nb_left == 0 || gas_left == 0 || space_left == 0 {
-                    break;
-                }
-
-                let Some(stored_transaction) = storage.get(storage_id) else {
-                    debug_assert!(
-                        false,
-                        "Transaction not found in the storage during `gather_best_txs`."
-                    );
-                    tracing::warn!(
-                        "Transaction not found in the storage during `gather_best_txs`."
-                    );
-                    transactions_to_remove.push(*key);
-                    continue
-                };
-
-                let less_price = stored_transaction.transaction.max_gas_price()
-                    < constraints.minimal_gas_price;
-
-                if less_price {
-                    continue;
-                }
-
-                let not_enough_gas = stored_transaction.transaction.max_gas() > gas_left;
-                let too_big_tx =
-                    stored_transaction.transaction.metered_bytes_size() > space_left;
-
-                if not_enough_gas || too_big_tx {
-                    continue;
-                }
-
-                gas_left =
-                    gas_left.saturating_sub(stored_transaction.transaction.max_gas());
-                space_left = space_left
-                    .saturating_sub(stored_transaction.transaction.metered_bytes_size());
-                nb_left = nb_left.saturating_sub(1);
-
-                let dependents = storage.get_dependents(storage_id).collect::<Vec<_>>();
-                debug_assert!(!storage.has_dependencies(storage_id));
-                let removed = storage.remove(storage_id).expect(
-                    "We just get the transaction from the storage above, it should exist.",
-                );
-                clean_up_list.push(*key);
-                result.push(removed);
-
-                for dependent in dependents {
-                    if !storage.has_dependencies(&dependent) {
-                        transactions_to_promote.push(dependent);
-                    }
-                }
-            }
-
-            for remove in transactions_to_remove {
-                let key = remove.0;
-                self.on_removed_transaction_inner(key);
-            }
-
-            // If no transaction fits in the gas limit and no one to promote, we can break the loop
-            if clean_up_list.is_empty() && transactions_to_promote.is_empty() {
-                break;
-            }
-
-            for key in clean_up_list {
-                let key = key.0;
-                // Remove selected transactions from the sorted list
-                self.on_removed_transaction_inner(key);
-            }
-
-            for promote in transactions_to_promote {
-                let storage = storage.get(&promote).expect(
-                    "We just get the dependent from the storage, it should exist.",
-                );
-
-                self.new_executable_transaction(promote, storage);
-            }
-        }
-
+        let result = gather_best_txs_in_order(
+            &mut self.executable_transactions_sorted_tip_gas_ratio,
+            |store_entry| Reverse(Self::key(store_entry)),
+            constraints,
+            storage,
+        );
+        #[cfg(debug_assertions)]
+        self.verify_invariants();
         result
     }
 
+    fn top_k(
+        &self,
+        k: usize,
+        constraints: &Constraints,
+        storage: &S,
+    ) -> Vec<Self::StorageIndex> {
+        peek_best_txs_in_order(
+            self.executable_transactions_sorted_tip_gas_ratio.values(),
+            k,
+            constraints,
+            storage,
+        )
+    }
+
     fn new_executable_transaction(
         &mut self,
         storage_id: Self::StorageIndex,
@@ -240,6 +208,46 @@ where
         let key = Self::key(store_entry);
         self.executable_transactions_sorted_tip_gas_ratio
             .insert(Reverse(key), storage_id);
+        #[cfg(debug_assertions)]
+        self.verify_invariants();
+    }
+
+    fn new_executable_transactions(
+        &mut self,
+        ids: Vec<Self::StorageIndex>,
+        storage: &S,
+    ) -> Result<(), Error> {
+        // Collecting into a `BTreeMap` sorts the batch once and builds the tree in bulk,
+        // and `append` merges it with the existing transactions in linear time.
+        let mut new_transactions = ids
+            .into_iter()
+            .map(|storage_id| {
+                let store_entry = storage
+                    .get(&storage_id)
+                    .ok_or_else(|| missing_executable_transaction(&storage_id))?;
+                Ok((Reverse(Self::key(store_entry)), storage_id))
+            })
+            .collect::<Result<BTreeMap<_, _>, Error>>()?;
+        self.executable_transactions_sorted_tip_gas_ratio
+            .append(&mut new_transactions);
+        Ok(())
+    }
+
+    fn peek_best_txs(
+        &self,
+        constraints: Constraints,
+        storage: &S,
+    ) -> RemovedTransactions {
+        // The map holds only keys and indexes, so the clone is cheap
+        // compared to the transactions themselves.
+        let mut executable_transactions =
+            self.executable_transactions_sorted_tip_gas_ratio.clone();
+        gather_best_txs_in_order(
+            &mut executable_transactions,
+            |store_entry| Reverse(Self::key(store_entry)),
+            constraints,
+            &mut PeekStorage::new(storage),
+        )
     }
 
     fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
@@ -248,8 +256,14 @@ where
             .rev()
     }
 
+    fn number_of_executable_transactions(&self) -> usize {
+        self.executable_transactions_sorted_tip_gas_ratio.len()
+    }
+
     fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
         let key = Self::key(storage_entry);
-        self.on_removed_transaction_inner(key)
+        self.on_removed_transaction_inner(key);
+        #[cfg(debug_assertions)]
+        self.verify_invariants();
     }
 }
@@ -26,12 +26,16 @@ use fuel_core_txpool::{
         TxPoolPersistentStorage,
         WasmChecker as WasmCheckerTrait,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::ConfiguredSelection,
     service::{
         memory::MemoryPool,
         p2p::P2PExt,
         pruner::TransactionPruner,
         subscriptions::Subscriptions,
+        verification_cache::{
+            VerificationCache,
+            DEFAULT_VERIFICATION_CACHE_SIZE,
+        },
         verifications::Verification,
     },
     shared_state::{
@@ -98,13 +102,14 @@ pub(crate) mod memory;
 mod p2p;
 mod pruner;
 mod subscriptions;
+pub(crate) mod verification_cache;
 pub(crate) mod verifications;
 
 pub type TxPool = Pool<
     GraphStorage,
     <GraphStorage as Storage>::StorageIndex,
     BasicCollisionManager<<GraphStorage as Storage>::StorageIndex>,
-    RatioTipGasSelection<GraphStorage>,
+    ConfiguredSelection<GraphStorage>,
 >;
 
 pub(crate) type Shared<T> = Arc<RwLock<T>>;
@@ -161,6 +166,10 @@ pub enum WritePoolRequest {
     RemoveCoinDependents {
         transactions: Vec<(TxId, String)>,
     },
+    InsertTxsWithPinnedView {
+        transactions: Vec<Arc<Transaction>>,
+        response_channel: oneshot::Sender<Result<Vec<Result<(), Error>>, Error>>,
+    },
 }
 
 pub enum ReadPoolRequest {
@@ -352,6 +361,12 @@ where
             WritePoolRequest::RemoveCoinDependents { transactions } => {
                 self.manage_remove_coin_dependents(transactions);
             }
+            WritePoolRequest::InsertTxsWithPinnedView {
+                transactions,
+                response_channel,
+            } => {
+                self.insert_transactions_with_pinned_view(transactions, response_channel);
+            }
         }
     }
 
@@ -368,13 +383,84 @@ where
         }
     }
 
+    /// Inserts the transactions one after another, verifying all of them
+    /// against the same view of the persistent storage.
+    fn insert_transactions_with_pinned_view(
+        &self,
+        transactions: Vec<Arc<Transaction>>,
+        response_channel: oneshot::Sender<Result<Vec<Result<(), Error>>, Error>>,
+    ) {
+        let Ok(reservation) = self.transaction_verifier_process.reserve() else {
+            tracing::error!("Failed to insert transactions: Out of capacity");
+            let _ = response_channel.send(Err(Error::ServiceQueueFull));
+            return
+        };
+
+        // The view is pinned only for this batch,
+        // other insertions keep using the latest view.
+        let verification = Verification {
+            pinned_view: Default::default(),
+            ..self.verification.clone()
+        };
+        let insertions = transactions
+            .into_iter()
+            .map(|transaction| {
+                let (sender, receiver) = oneshot::channel();
+                let op = self.insert_transaction_with(
+                    verification.clone(),
+                    transaction,
+                    None,
+                    Some(sender),
+                );
+                (op, receiver)
+            })
+            .collect::<Vec<_>>();
+
+        let op = move || {
+            if let Err(err) = verification.pin_view() {
+                let _ = response_channel.send(Err(err));
+                return
+            }
+
+            let results = insertions
+                .into_iter()
+                .map(|(op, mut receiver)| {
+                    op();
+                    receiver
+                        .try_recv()
+                        .unwrap_or(Err(Error::ServiceCommunicationFailed))
+                })
+                .collect();
+
+            verification.unpin_view();
+            let _ = response_channel.send(Ok(results));
+        };
+
+        self.transaction_verifier_process
+            .spawn_reserved(reservation, op);
+    }
+
     fn insert_transaction(
         &self,
         transaction: Arc<Transaction>,
         from_peer_info: Option<GossipsubMessageInfo>,
         response_channel: Option<oneshot::Sender<Result<(), Error>>>,
     ) -> impl FnOnce() + Send + 'static {
-        let verification = self.verification.clone();
+        self.insert_transaction_with(
+            self.verification.clone(),
+            transaction,
+            from_peer_info,
+            response_channel,
+        )
+    }
+
+    fn insert_transaction_with(
+        &self,
+        verification: Verification<View>,
+        transaction: Arc<Transaction>,
+        from_peer_info: Option<GossipsubMessageInfo>,
+        response_channel: Option<oneshot::Sender<Result<(), Error>>>,
+    ) -> impl FnOnce() + Send + 'static {
         let pool = self.pool.clone();
         let p2p = self.p2p.clone();
         let shared_state = self.shared_state.clone();
@@ -414,14 +500,13 @@ where
 
             let tx = Arc::new(checked_tx);
 
-            let result = {
+            let (result, replaced_txs) = {
                 let mut pool = pool.write();
-                let result = verification.persistent_storage_provider.latest_view();
-
-                match result {
-                    Ok(view) => pool.insert(tx, &view),
-                    Err(err) => Err(Error::Database(format!("{:?}", err))),
-                }
+                let replaced_txs = pool.collided_tx_ids(&tx);
+                let result = verification
+                    .latest_view()
+                    .and_then(|view| pool.insert(tx, &view));
+                (result, replaced_txs)
             };
 
             let removed_txs = match result {
@@ -458,10 +543,17 @@ where
             };
 
             for tx in removed_txs {
-                shared_state.tx_status_sender.send_squeezed_out(
-                    tx.id(),
-                    Error::Removed(RemovedReason::LessWorth(tx.id())),
-                );
+                let removed_tx_id = tx.id();
+                if replaced_txs.contains(&removed_tx_id) {
+                    shared_state
+                        .tx_status_sender
+                        .send_replaced(removed_tx_id, tx_id);
+                } else {
+                    shared_state.tx_status_sender.send_squeezed_out(
+                        removed_tx_id,
+                        Error::Removed(RemovedReason::LessWorth(tx_id)),
+                    );
+                }
             }
         }
     }
@@ -729,6 +821,8 @@ where
         gas_price_provider: Arc::new(gas_price_provider),
         wasm_checker: Arc::new(wasm_checker),
         memory_pool: MemoryPool::new(),
+        verification_cache: VerificationCache::new(DEFAULT_VERIFICATION_CACHE_SIZE),
+        pinned_view: Default::default(),
     };
 
     let pruner = TransactionPruner {
@@ -755,9 +849,10 @@ where
     let txpool = Pool::new(
         GraphStorage::new(GraphConfig {
             max_txs_chain_count: config.max_txs_chain_count,
+            max_dependent_depth: config.max_dependent_depth,
         }),
         BasicCollisionManager::new(),
-        RatioTipGasSelection::new(),
+        ConfiguredSelection::new(config.selection_algorithm),
         config,
     );
 
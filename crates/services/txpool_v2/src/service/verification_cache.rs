@@ -0,0 +1,256 @@
+use fuel_core_types::{
+    blockchain::header::ConsensusParametersVersion,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    fuel_vm::checked_transaction::Checked,
+};
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::Arc,
+};
+
+/// The number of recently verified transactions kept by the [`VerificationCache`].
+pub const DEFAULT_VERIFICATION_CACHE_SIZE: usize = 10_000;
+
+/// The least recently used cache of transactions with verified signatures and predicates.
+///
+/// Transactions gossiped by several peers are verified only on the first arrival.
+/// The cache is cleared when the version of the consensus parameters changes,
+/// because the verification depends on them.
+#[derive(Clone)]
+pub struct VerificationCache {
+    inner: Arc<Mutex<Inner>>,
+}
+
+struct Inner {
+    capacity: usize,
+    version: Option<ConsensusParametersVersion>,
+    entries: HashMap<TxId, Checked<Transaction>>,
+    /// The order of usage of the entries, from the least to the most recently used.
+    usage: VecDeque<TxId>,
+}
+
+impl VerificationCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner {
+                capacity,
+                version: None,
+                entries: HashMap::new(),
+                usage: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Returns the verified transaction from the cache, or verifies it with `verify`
+    /// and caches the result on success.
+    pub fn get_or_verify<E>(
+        &self,
+        version: ConsensusParametersVersion,
+        tx_id: TxId,
+        verify: impl FnOnce() -> Result<Checked<Transaction>, E>,
+    ) -> Result<Checked<Transaction>, E> {
+        if let Some(tx) = self.get(version, &tx_id) {
+            return Ok(tx)
+        }
+
+        // The lock is not held during the verification to not block other verifications.
+        let tx = verify()?;
+        self.insert(version, tx_id, tx.clone());
+        Ok(tx)
+    }
+
+    fn get(
+        &self,
+        version: ConsensusParametersVersion,
+        tx_id: &TxId,
+    ) -> Option<Checked<Transaction>> {
+        let mut inner = self.inner.lock();
+        inner.reset_if_outdated(version);
+
+        let tx = inner.entries.get(tx_id).cloned()?;
+        inner.touch(tx_id);
+        Some(tx)
+    }
+
+    fn insert(
+        &self,
+        version: ConsensusParametersVersion,
+        tx_id: TxId,
+        tx: Checked<Transaction>,
+    ) {
+        let mut inner = self.inner.lock();
+        inner.reset_if_outdated(version);
+        if inner.capacity == 0 {
+            return
+        }
+
+        if inner.entries.insert(tx_id, tx).is_some() {
+            inner.touch(&tx_id);
+            return
+        }
+        inner.usage.push_back(tx_id);
+
+        if inner.entries.len() > inner.capacity {
+            if let Some(least_recently_used) = inner.usage.pop_front() {
+                inner.entries.remove(&least_recently_used);
+            }
+        }
+    }
+}
+
+impl Inner {
+    fn reset_if_outdated(&mut self, version: ConsensusParametersVersion) {
+        if self.version != Some(version) {
+            self.version = Some(version);
+            self.entries.clear();
+            self.usage.clear();
+        }
+    }
+
+    fn touch(&mut self, tx_id: &TxId) {
+        if let Some(position) = self.usage.iter().position(|id| id == tx_id) {
+            self.usage.remove(position);
+        }
+        self.usage.push_back(*tx_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_types::{
+        fuel_crypto::SecretKey,
+        fuel_tx::{
+            ConsensusParameters,
+            Finalizable,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_vm::checked_transaction::IntoChecked,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+    use std::cell::Cell;
+
+    fn checked_tx(rng: &mut StdRng) -> (TxId, Checked<Transaction>) {
+        let params = ConsensusParameters::standard();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                1_000,
+                Default::default(),
+                Default::default(),
+            )
+            .finalize_as_transaction();
+        let tx_id = tx.id(&params.chain_id());
+        let checked = tx
+            .into_checked_basic(Default::default(), &params)
+            .expect("Transaction should pass basic checks");
+        (tx_id, checked)
+    }
+
+    fn counting_verify<'a>(
+        verifications: &'a Cell<u32>,
+        tx: &'a Checked<Transaction>,
+    ) -> impl FnOnce() -> Result<Checked<Transaction>, ()> + 'a {
+        move || {
+            verifications.set(verifications.get().saturating_add(1));
+            Ok(tx.clone())
+        }
+    }
+
+    #[test]
+    fn get_or_verify__same_tx_is_verified_once() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2322);
+        let cache = VerificationCache::new(DEFAULT_VERIFICATION_CACHE_SIZE);
+        let (tx_id, tx) = checked_tx(&mut rng);
+        let verifications = Cell::new(0);
+
+        // When
+        cache
+            .get_or_verify(0, tx_id, counting_verify(&verifications, &tx))
+            .unwrap();
+        cache
+            .get_or_verify(0, tx_id, counting_verify(&verifications, &tx))
+            .unwrap();
+
+        // Then
+        assert_eq!(verifications.get(), 1);
+    }
+
+    #[test]
+    fn get_or_verify__consensus_parameters_version_bump_invalidates_cache() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2322);
+        let cache = VerificationCache::new(DEFAULT_VERIFICATION_CACHE_SIZE);
+        let (tx_id, tx) = checked_tx(&mut rng);
+        let verifications = Cell::new(0);
+        cache
+            .get_or_verify(0, tx_id, counting_verify(&verifications, &tx))
+            .unwrap();
+
+        // When
+        cache
+            .get_or_verify(1, tx_id, counting_verify(&verifications, &tx))
+            .unwrap();
+
+        // Then
+        assert_eq!(verifications.get(), 2);
+    }
+
+    #[test]
+    fn get_or_verify__failed_verification_is_not_cached() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2322);
+        let cache = VerificationCache::new(DEFAULT_VERIFICATION_CACHE_SIZE);
+        let (tx_id, tx) = checked_tx(&mut rng);
+        let verifications = Cell::new(0);
+        let _ = cache.get_or_verify(0, tx_id, || Err::<Checked<Transaction>, ()>(()));
+
+        // When
+        cache
+            .get_or_verify(0, tx_id, counting_verify(&verifications, &tx))
+            .unwrap();
+
+        // Then
+        assert_eq!(verifications.get(), 1);
+    }
+
+    #[test]
+    fn get_or_verify__least_recently_used_tx_is_evicted() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2322);
+        let cache = VerificationCache::new(1);
+        let (first_id, first) = checked_tx(&mut rng);
+        let (second_id, second) = checked_tx(&mut rng);
+        let verifications = Cell::new(0);
+        cache
+            .get_or_verify(0, first_id, counting_verify(&verifications, &first))
+            .unwrap();
+        cache
+            .get_or_verify(0, second_id, counting_verify(&verifications, &second))
+            .unwrap();
+
+        // When
+        cache
+            .get_or_verify(0, first_id, counting_verify(&verifications, &first))
+            .unwrap();
+
+        // Then
+        assert_eq!(verifications.get(), 3);
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
     },
     service::{
         memory::MemoryPool,
+        verification_cache::VerificationCache,
         Shared,
         TxPool,
     },
@@ -23,6 +24,7 @@ use fuel_core_types::{
         },
         ConsensusParameters,
         Transaction,
+        UniqueIdentifier,
         UpgradePurpose,
     },
     fuel_types::BlockHeight,
@@ -45,6 +47,7 @@ use fuel_core_types::{
         PoolTransaction,
     },
 };
+use parking_lot::RwLock;
 use std::sync::Arc;
 
 pub(crate) struct Verification<View> {
@@ -53,6 +56,9 @@ pub(crate) struct Verification<View> {
     pub gas_price_provider: Arc<dyn GasPriceProvider>,
     pub wasm_checker: Arc<dyn WasmChecker>,
     pub memory_pool: MemoryPool,
+    pub verification_cache: VerificationCache,
+    /// The view of the persistent storage used instead of the latest one while pinned.
+    pub pinned_view: Arc<RwLock<Option<View>>>,
 }
 
 impl<V> Clone for Verification<V> {
@@ -63,6 +69,8 @@ impl<V> Clone for Verification<V> {
             gas_price_provider: self.gas_price_provider.clone(),
             wasm_checker: self.wasm_checker.clone(),
             memory_pool: self.memory_pool.clone(),
+            verification_cache: self.verification_cache.clone(),
+            pinned_view: self.pinned_view.clone(),
         }
     }
 }
@@ -71,6 +79,35 @@ impl<View> Verification<View>
 where
     View: TxPoolPersistentStorage,
 {
+    /// Captures the latest view of the persistent storage and uses it for all
+    /// verifications and insertions until [`Self::unpin_view`] is called.
+    /// It allows validating a sequence of transactions against the same snapshot,
+    /// f.e. during the import of a block.
+    pub fn pin_view(&self) -> Result<(), Error> {
+        let view = self
+            .persistent_storage_provider
+            .latest_view()
+            .map_err(|e| Error::Database(format!("{:?}", e)))?;
+        *self.pinned_view.write() = Some(view);
+        Ok(())
+    }
+
+    /// Releases the pinned view, so the next verifications use the latest view again.
+    pub fn unpin_view(&self) {
+        *self.pinned_view.write() = None;
+    }
+
+    /// Returns the pinned view of the persistent storage if any, or the latest view.
+    pub fn latest_view(&self) -> Result<View, Error> {
+        if let Some(view) = self.pinned_view.read().as_ref() {
+            return Ok(view.clone())
+        }
+
+        self.persistent_storage_provider
+            .latest_view()
+            .map_err(|e| Error::Database(format!("{:?}", e)))
+    }
+
     pub fn perform_all_verifications(
         &self,
         tx: Transaction,
@@ -96,10 +133,7 @@ where
                 self.gas_price_provider.as_ref(),
             )?;
 
-        let view = self
-            .persistent_storage_provider
-            .latest_view()
-            .map_err(|e| Error::Database(format!("{:?}", e)))?;
+        let view = self.latest_view()?;
 
         let inputs_verified_tx =
             gas_price_verified_tx.perform_inputs_verifications(pool, &view, metadata)?;
@@ -111,6 +145,8 @@ where
                 self.memory_pool.take_raw(),
                 &view,
                 utxo_validation,
+                version,
+                &self.verification_cache,
             )?;
 
         fully_verified_tx.into_pool_transaction(metadata)
@@ -194,6 +230,7 @@ impl GasPriceVerifiedTx {
 }
 
 impl InputDependenciesVerifiedTx {
+    #[allow(clippy::too_many_arguments)]
     pub fn perform_input_computation_verifications<View>(
         self,
         consensus_params: &ConsensusParameters,
@@ -201,6 +238,8 @@ impl InputDependenciesVerifiedTx {
         memory: impl Memory,
         view: &View,
         utxo_validation: bool,
+        version: ConsensusParametersVersion,
+        verification_cache: &VerificationCache,
     ) -> Result<FullyVerifiedTx, Error>
     where
         View: TxPoolPersistentStorage,
@@ -208,12 +247,21 @@ impl InputDependenciesVerifiedTx {
         let mut tx = self.0;
 
         if utxo_validation {
-            tx = tx.check_signatures(&consensus_params.chain_id())?;
-
-            let parameters = CheckPredicateParams::from(consensus_params);
-            tx = tx.check_predicates(&parameters, memory, view)?;
-
-            debug_assert!(tx.checks().contains(Checks::all()));
+            let chain_id = consensus_params.chain_id();
+            let tx_id = tx.transaction().id(&chain_id);
+            tx = verification_cache.get_or_verify(
+                version,
+                tx_id,
+                || -> Result<_, Error> {
+                    let tx = tx.check_signatures(&chain_id)?;
+
+                    let parameters = CheckPredicateParams::from(consensus_params);
+                    let tx = tx.check_predicates(&parameters, memory, view)?;
+
+                    debug_assert!(tx.checks().contains(Checks::all()));
+                    Ok(tx)
+                },
+            )?;
         }
 
         if let Transaction::Upgrade(upgrade) = tx.transaction() {
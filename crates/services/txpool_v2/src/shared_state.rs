@@ -83,6 +83,29 @@ impl SharedState {
             .map_err(|_| Error::ServiceCommunicationFailed)?
     }
 
+    /// Inserts the transactions one after another and verifies all of them against
+    /// the same view of the persistent storage, f.e. to re-insert the transactions
+    /// of a block as one consistent batch. Returns the result of each insertion.
+    pub async fn insert_with_pinned_view(
+        &self,
+        transactions: Vec<Transaction>,
+    ) -> Result<Vec<Result<(), Error>>, Error> {
+        let transactions = transactions.into_iter().map(Arc::new).collect();
+        let (sender, receiver) = oneshot::channel();
+
+        self.write_pool_requests_sender
+            .send(WritePoolRequest::InsertTxsWithPinnedView {
+                transactions,
+                response_channel: sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+
+        receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?
+    }
+
     pub async fn borrow_txpool(&self) -> Result<BorrowedTxPool, Error> {
         let (select_transactions_sender, select_transactions_receiver) =
             oneshot::channel();
@@ -45,7 +45,7 @@ use crate::{
         InputValidationError,
     },
     ports::TxPoolPersistentStorage,
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelectionAlgorithmStorage,
+    selection_algorithms::SelectionAlgorithmStorage,
     storage::checked_collision::CheckedTransaction,
 };
 
@@ -64,11 +64,17 @@ pub struct GraphStorage {
     coins_creators: HashMap<UtxoId, NodeIndex>,
     /// Contract -> Transaction that currently create the contract
     contracts_creators: HashMap<ContractId, NodeIndex>,
+    /// Transaction -> Direct dependents of the transaction.
+    /// It duplicates outgoing edges of the graph to provide them as a slice.
+    dependents: HashMap<NodeIndex, Vec<NodeIndex>>,
 }
 
 pub struct GraphConfig {
     /// The maximum number of transactions per dependency chain
     pub max_txs_chain_count: usize,
+    /// The maximum number of hops from a transaction to the farthest
+    /// executable transaction it depends on
+    pub max_dependent_depth: usize,
 }
 
 impl GraphStorage {
@@ -79,6 +85,7 @@ impl GraphStorage {
             graph: StableDiGraph::new(),
             coins_creators: HashMap::new(),
             contracts_creators: HashMap::new(),
+            dependents: HashMap::new(),
         }
     }
 
@@ -87,6 +94,7 @@ impl GraphStorage {
         self.graph.node_count() == 0
             && self.coins_creators.is_empty()
             && self.contracts_creators.is_empty()
+            && self.dependents.is_empty()
     }
 }
 
@@ -158,11 +166,10 @@ impl GraphStorage {
             let dependents: Vec<_> = self.get_direct_dependents(remove).collect();
             let dependencies: Vec<_> = self.get_direct_dependencies(remove).collect();
 
-            let removed_storage_entry = self.graph.remove_node(remove).expect(
+            let removed_storage_entry = self.remove_node(remove).expect(
                 "The node should be present in the graph \
                     since we iterate over it using bfs",
             );
-            self.clear_cache(&removed_storage_entry);
 
             for dependent in dependents {
                 queue.push_back(dependent);
@@ -283,6 +290,29 @@ impl GraphStorage {
         }
     }
 
+    /// Remove the node from the graph and clear the caches related to it.
+    fn remove_node(&mut self, index: NodeIndex) -> Option<StorageData> {
+        let dependencies: Vec<_> = self.get_direct_dependencies(index).collect();
+        let storage_entry = self.graph.remove_node(index)?;
+
+        for dependency in dependencies {
+            if let Some(dependents) = self.dependents.get_mut(&dependency) {
+                dependents.retain(|dependent| *dependent != index);
+            }
+        }
+        self.dependents.remove(&index);
+        self.clear_cache(&storage_entry);
+
+        Some(storage_entry)
+    }
+
+    fn dependents_slice(&self, index: NodeIndex) -> &[NodeIndex] {
+        self.dependents
+            .get(&index)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
     /// Clear the caches of the storage when a transaction is removed.
     fn clear_cache(&mut self, storage_entry: &StorageData) {
         let outputs = storage_entry.transaction.outputs();
@@ -378,6 +408,7 @@ impl Storage for GraphStorage {
         &mut self,
         checked_transaction: Self::CheckedTransaction,
         creation_instant: SystemTime,
+        replacement_count: u32,
     ) -> Self::StorageIndex {
         let (transaction, direct_dependencies, all_dependencies) =
             checked_transaction.unpack();
@@ -418,6 +449,7 @@ impl Storage for GraphStorage {
             transaction,
             creation_instant,
             number_dependents_in_chain: 1,
+            replacement_count,
         };
 
         // Add the transaction to the graph
@@ -428,6 +460,7 @@ impl Storage for GraphStorage {
                 "Edge already exists"
             );
             self.graph.add_edge(dependency, node_id, ());
+            self.dependents.entry(dependency).or_default().push(node_id);
         }
         debug_assert!(!self.has_dependent(node_id));
 
@@ -444,9 +477,15 @@ impl Storage for GraphStorage {
             self.collect_transaction_direct_dependencies(&transaction)?;
 
         let mut all_dependencies = HashSet::new();
-        let mut to_check = direct_dependencies.iter().cloned().collect::<Vec<_>>();
-
-        while let Some(node_id) = to_check.pop() {
+        // The depth is counted in hops, like in `transaction_dependency_depth`,
+        // so the direct dependencies of the new transaction are one hop away.
+        let mut to_check = direct_dependencies
+            .iter()
+            .map(|node_id| (*node_id, 1usize))
+            .collect::<Vec<_>>();
+        let mut depth = 0;
+
+        while let Some((node_id, level)) = to_check.pop() {
             if all_dependencies.contains(&node_id) {
                 // The graph heavy rely on the property of not having
                 // diamond dependencies. An example of the diamond dependency:
@@ -475,6 +514,7 @@ impl Storage for GraphStorage {
             }
 
             all_dependencies.insert(node_id);
+            depth = depth.max(level);
 
             if all_dependencies.len() >= self.config.max_txs_chain_count {
                 return Err(Error::Dependency(
@@ -497,7 +537,18 @@ impl Storage for GraphStorage {
                 ));
             }
 
-            to_check.extend(self.get_direct_dependencies(node_id));
+            let next_level = level.saturating_add(1);
+            to_check.extend(
+                self.get_direct_dependencies(node_id)
+                    .map(|dependency| (dependency, next_level)),
+            );
+        }
+
+        if depth > self.config.max_dependent_depth {
+            return Err(Error::DependencyChainTooDeep {
+                depth,
+                max: self.config.max_dependent_depth,
+            })
         }
 
         Ok(CheckedTransaction::new(
@@ -511,6 +562,10 @@ impl Storage for GraphStorage {
         self.get_inner(index)
     }
 
+    fn count(&self) -> usize {
+        self.graph.node_count()
+    }
+
     fn get_direct_dependents(
         &self,
         index: Self::StorageIndex,
@@ -522,6 +577,31 @@ impl Storage for GraphStorage {
         self.get_direct_dependencies(*index).next().is_some()
     }
 
+    fn transaction_dependency_depth(
+        &self,
+        storage_id: &Self::StorageIndex,
+    ) -> Result<usize, Error> {
+        if self.graph.node_weight(*storage_id).is_none() {
+            return Err(Error::Storage(format!(
+                "Node with id {:?} not found",
+                storage_id
+            )))
+        }
+
+        let mut to_check = vec![(*storage_id, 0usize)];
+        let mut depth = 0;
+        while let Some((node_id, level)) = to_check.pop() {
+            depth = depth.max(level);
+            let next_level = level.saturating_add(1);
+            to_check.extend(
+                self.get_direct_dependencies(node_id)
+                    .map(|dependency| (dependency, next_level)),
+            );
+        }
+
+        Ok(depth)
+    }
+
     fn validate_inputs(
         &self,
         transaction: &PoolTransaction,
@@ -615,24 +695,26 @@ impl Storage for GraphStorage {
     }
 
     fn remove_transaction(&mut self, index: Self::StorageIndex) -> Option<StorageData> {
-        self.graph.remove_node(index).inspect(|storage_entry| {
-            self.clear_cache(storage_entry);
-        })
+        self.remove_node(index)
     }
 }
 
-impl RatioTipGasSelectionAlgorithmStorage for GraphStorage {
+impl SelectionAlgorithmStorage for GraphStorage {
     type StorageIndex = NodeIndex;
 
     fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData> {
         self.get_inner(index)
     }
 
-    fn get_dependents(
+    fn dependents_slice(&self, index: &Self::StorageIndex) -> &[Self::StorageIndex] {
+        self.dependents_slice(*index)
+    }
+
+    fn get_dependencies(
         &self,
         index: &Self::StorageIndex,
     ) -> impl Iterator<Item = Self::StorageIndex> {
-        self.get_direct_dependents(*index)
+        self.get_direct_dependencies(*index)
     }
 
     fn has_dependencies(&self, index: &Self::StorageIndex) -> bool {
@@ -640,9 +722,7 @@ impl RatioTipGasSelectionAlgorithmStorage for GraphStorage {
     }
 
     fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData> {
-        self.graph.remove_node(*index).inspect(|storage_entry| {
-            self.clear_cache(storage_entry);
-        })
+        self.remove_node(*index)
     }
 }
 
@@ -28,10 +28,22 @@ pub struct StorageData {
     pub dependents_cumulative_gas: u64,
     /// The cumulative of space used by a transaction and all of its children.
     pub dependents_cumulative_bytes_size: usize,
-    /// Number of dependents
+    /// Number of transactions in the chain of the transaction and all of its children.
     pub number_dependents_in_chain: usize,
     /// The instant when the transaction was added to the pool.
     pub creation_instant: SystemTime,
+    /// Number of fee bumps that replaced the previous occupants of the inputs of the transaction.
+    pub replacement_count: u32,
+}
+
+impl StorageData {
+    /// Returns the number of descendants of the transaction, excluding itself.
+    ///
+    /// It is maintained together with the cumulative gas, tip and bytes size,
+    /// so it doesn't require walking the subtree.
+    pub fn dependents_count(&self) -> usize {
+        self.number_dependents_in_chain.saturating_sub(1)
+    }
 }
 
 pub type RemovedTransactions = Vec<StorageData>;
@@ -64,6 +76,7 @@ pub trait Storage {
         &mut self,
         checked_transaction: Self::CheckedTransaction,
         creation_instant: SystemTime,
+        replacement_count: u32,
     ) -> Self::StorageIndex;
 
     /// The function performs checks on the transaction and returns a checked transaction.
@@ -77,6 +90,9 @@ pub trait Storage {
     /// Get the storage data by its index.
     fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData>;
 
+    /// Returns the number of transactions in the storage.
+    fn count(&self) -> usize;
+
     /// Get direct dependents of a transaction.
     fn get_direct_dependents(
         &self,
@@ -86,6 +102,14 @@ pub trait Storage {
     /// Returns `true` if the transaction has dependencies.
     fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
 
+    /// Returns the number of hops from the transaction to the farthest root
+    /// transaction among its dependencies. Executable transactions have depth `0`.
+    /// It is the unit of `Config::max_dependent_depth`.
+    fn transaction_dependency_depth(
+        &self,
+        storage_id: &Self::StorageIndex,
+    ) -> Result<usize, Error>;
+
     /// Validate inputs of a transaction.
     fn validate_inputs(
         &self,
@@ -116,6 +116,7 @@ impl TestPoolUniverse {
             MockDBProvider(self.mock_db.clone()),
             GraphStorage::new(GraphConfig {
                 max_txs_chain_count: self.config.max_txs_chain_count,
+                max_dependent_depth: self.config.max_dependent_depth,
             }),
             BasicCollisionManager::new(),
             RatioTipGasSelection::new(),
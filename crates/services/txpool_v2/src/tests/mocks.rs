@@ -201,6 +201,28 @@ impl AtomicView for MockDBProvider {
     }
 }
 
+/// The provider whose latest view can be replaced, f.e. to emulate a block import.
+#[derive(Default)]
+pub struct SwappableDBProvider(pub Mutex<MockDb>);
+
+impl SwappableDBProvider {
+    pub fn new(db: MockDb) -> Self {
+        Self(Mutex::new(db))
+    }
+
+    pub fn swap(&self, db: MockDb) {
+        *self.0.lock().unwrap() = db;
+    }
+}
+
+impl AtomicView for SwappableDBProvider {
+    type LatestView = MockDb;
+
+    fn latest_view(&self) -> StorageResult<Self::LatestView> {
+        Ok(self.0.lock().unwrap().clone())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MockTxPoolGasPrice {
     pub gas_price: GasPrice,
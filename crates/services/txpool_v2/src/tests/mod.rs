@@ -7,6 +7,7 @@ mod tests_pool;
 mod tests_sending;
 mod tests_service;
 mod tests_subscribe;
+mod tests_tracing;
 mod tests_update_stream_state;
 mod universe;
 mod utils;
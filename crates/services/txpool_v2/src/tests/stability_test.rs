@@ -176,24 +176,25 @@ fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) {
 
         if tip % 10 == 0 {
             txpool.read().storage.check_integrity();
+            #[cfg(debug_assertions)]
+            txpool.read().selection_algorithm.verify_invariants();
         }
     }
 
     assert_ne!(ROUNDS_PER_TXPOOL, errors);
 
     loop {
-        let result = txpool.write().extract_transactions_for_block(Constraints {
-            max_gas: limits.max_block_gas,
-            maximum_txs: u16::MAX,
-            maximum_block_size: u32::MAX,
-            minimal_gas_price: 0,
-        });
+        let result = txpool
+            .write()
+            .extract_transactions_for_block(Constraints::new(limits.max_block_gas));
 
         if result.is_empty() {
             break
         }
 
         txpool.read().storage.check_integrity();
+        #[cfg(debug_assertions)]
+        txpool.read().selection_algorithm.verify_invariants();
     }
 
     {
@@ -2,8 +2,12 @@
 
 use crate::{
     config::{
+        AllowList,
+        BlackList,
         Config,
+        EvictionWeights,
         PoolLimits,
+        SelectionAlgorithmKind,
     },
     error::{
         BlacklistedError,
@@ -12,10 +16,24 @@ use crate::{
         Error,
         InputValidationError,
     },
+    pool::TxPriority,
     ports::WasmValidityError,
-    selection_algorithms::Constraints,
+    selection_algorithms::{
+        ratio_tip_gas::{
+            RatioTipGas,
+            RatioTipGasSelection,
+        },
+        ConfiguredSelection,
+        Constraints,
+        SelectionAlgorithm,
+        SelectionAlgorithmStorage,
+    },
     tests::{
-        mocks::MockWasmChecker,
+        mocks::{
+            MockDb,
+            MockWasmChecker,
+            SwappableDBProvider,
+        },
         universe::{
             create_contract_input,
             create_contract_output,
@@ -27,6 +45,7 @@ use crate::{
         },
     },
 };
+use fuel_core_metrics::txpool_metrics::txpool_metrics;
 use fuel_core_types::{
     fuel_asm::{
         op,
@@ -48,6 +67,7 @@ use fuel_core_types::{
         PanicReason,
         PredicateParameters,
         TransactionBuilder,
+        TxId,
         TxParameters,
         UniqueIdentifier,
         UpgradePurpose,
@@ -61,9 +81,15 @@ use fuel_core_types::{
             CheckedTransaction,
             IntoChecked,
         },
+        interpreter::MemoryInstance,
         PredicateVerificationFailed,
     },
 };
+use num_rational::Ratio;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 #[test]
 fn insert_one_tx_succeeds() {
@@ -172,317 +198,1545 @@ fn insert__tx_with_blacklisted_message() {
 }
 
 #[test]
-fn insert__tx2_succeeds_after_dependent_tx1() {
+fn insert__tx_with_blacklisted_message_sender() {
     let mut universe = TestPoolUniverse::default();
-    universe.build_pool();
 
     // Given
-    let (output, unset_input) = universe.create_output_and_input();
-    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 0);
-
-    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
-    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let (message, input) = create_message_predicate_from_message(5000, 0);
+    let sender = *message.sender();
+    universe.config.black_list.owners.insert(sender);
+    universe.build_pool();
+    let tx = universe.build_script_transaction(Some(vec![input]), None, 0);
 
     // When
-    let result1 = universe.verify_and_insert(tx1);
-    let result2 = universe.verify_and_insert(tx2);
+    let err = universe.verify_and_insert(tx).unwrap_err();
 
     // Then
-    assert!(result1.is_ok());
-    assert!(result2.is_ok());
+    assert!(
+        matches!(err, Error::Blacklisted(BlacklistedError::BlacklistedSender(id)) if id == sender)
+    );
 }
 
 #[test]
-fn insert__tx2_collided_on_contract_id() {
+fn insert__tx_with_blacklisted_asset() {
     let mut universe = TestPoolUniverse::default();
-    universe.build_pool();
-
-    let contract_id = Contract::EMPTY_CONTRACT_ID;
-
-    // contract creation tx
-    let (_, gas_coin) = universe.setup_coin();
-    let tx = TransactionBuilder::create(
-        Default::default(),
-        Default::default(),
-        Default::default(),
-    )
-    .tip(10)
-    .max_fee_limit(10)
-    .add_input(gas_coin)
-    .add_output(create_contract_output(contract_id))
-    .finalize_as_transaction();
-
-    let (_, gas_coin) = universe.setup_coin();
+    let asset_id = AssetId::from([7; 32]);
 
     // Given
-    // attempt to insert a different creation tx with a valid dependency on the first tx,
-    // but with a conflicting output contract id
-    let tx_faulty = TransactionBuilder::create(
-        Default::default(),
-        Default::default(),
-        Default::default(),
-    )
-    .tip(9)
-    .max_fee_limit(9)
-    .add_input(gas_coin)
-    .add_output(create_contract_output(contract_id))
-    .finalize_as_transaction();
-    universe.verify_and_insert(tx).unwrap();
+    universe.config.black_list.assets.insert(asset_id);
+    universe.build_pool();
+    let input = universe.random_predicate(asset_id, TEST_COIN_AMOUNT, None);
+    let blacklisted_tx = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let allowed_tx = universe.build_script_transaction(None, None, 0);
 
     // When
-    let result2 = universe.verify_and_insert(tx_faulty);
+    let err = universe.verify_and_insert(blacklisted_tx).unwrap_err();
+    let result = universe.verify_and_insert(allowed_tx);
 
     // Then
-    let err = result2.unwrap_err();
     assert!(
-        matches!(err, Error::Collided(CollisionReason::ContractCreation(id)) if id == contract_id)
+        matches!(err, Error::Blacklisted(BlacklistedError::BlacklistedAsset(id)) if id == asset_id)
     );
+    assert!(result.is_ok());
 }
 
 #[test]
-fn insert__tx_with_dependency_on_invalid_utxo_type() {
+fn insert__tx_with_blacklisted_owner_increments_rejection_metric() {
     let mut universe = TestPoolUniverse::default();
-    universe.build_pool();
-    let contract_id = Contract::EMPTY_CONTRACT_ID;
-
-    let gas_coin = universe.setup_coin().1;
-    let tx = TransactionBuilder::create(
-        Default::default(),
-        Default::default(),
-        Default::default(),
-    )
-    .add_input(gas_coin)
-    .add_output(create_contract_output(contract_id))
-    .finalize_as_transaction();
-    let utxo_id = UtxoId::new(tx.id(&Default::default()), 0);
 
     // Given
-    // create a second transaction with utxo id referring to
-    // the wrong type of utxo (contract instead of coin)
-    let random_predicate =
-        universe.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, Some(utxo_id));
-    let tx_faulty =
-        universe.build_script_transaction(Some(vec![random_predicate]), None, 0);
-    universe.verify_and_insert(tx).unwrap();
+    let coin = universe.setup_coin().1;
+    let owner_addr = *coin.input_owner().unwrap();
+    universe.config.black_list.owners.insert(owner_addr);
+    universe.config.metrics = true;
+    universe.build_pool();
+    let tx = universe.build_script_transaction(Some(vec![coin]), None, 0);
+    let rejected_before = txpool_metrics().rejected_count("Blacklisted");
 
     // When
-    let result2 = universe.verify_and_insert(tx_faulty);
+    let err = universe.verify_and_insert(tx).unwrap_err();
 
     // Then
-    let err = result2.unwrap_err();
-
-    assert!(
-        matches!(err, Error::InputValidation(InputValidationError::UtxoNotFound(id)) if id == utxo_id)
-    );
+    assert!(matches!(err, Error::Blacklisted(_)));
+    let rejected_after = txpool_metrics().rejected_count("Blacklisted");
+    assert!(rejected_after > rejected_before);
 }
 
 #[test]
-fn insert__already_known_tx_returns_error() {
-    let mut universe = TestPoolUniverse::default().config(Config {
-        utxo_validation: false,
-        ..Default::default()
-    });
+fn insert__any_sender_passes_when_allow_list_is_not_set() {
+    let mut universe = TestPoolUniverse::default();
     universe.build_pool();
 
     // Given
     let tx = universe.build_script_transaction(None, None, 0);
-    universe.verify_and_insert(tx.clone()).unwrap();
 
     // When
-    let result2 = universe.verify_and_insert(tx.clone());
+    let result = universe.verify_and_insert(tx);
 
     // Then
-    let err = result2.unwrap_err();
-    assert!(
-        matches!(err, Error::InputValidation(InputValidationError::DuplicateTxId(id)) if id == tx.id(&ChainId::default()))
-    );
+    assert!(result.is_ok());
 }
 
 #[test]
-fn insert__unknown_utxo_returns_error() {
+fn insert__only_tx_of_allowed_senders_passes() {
     let mut universe = TestPoolUniverse::default();
-    universe.build_pool();
 
     // Given
-    let input = universe.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
-    let utxo_id = input.utxo_id().cloned().unwrap();
-    let tx = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let allowed_tx = universe.build_script_transaction(None, None, 0);
+    let allowed_tx = universe.check_basic(allowed_tx);
+    let not_allowed_tx = universe.build_script_transaction(None, None, 0);
+    let not_allowed_tx = universe.check_basic(not_allowed_tx);
+    let not_allowed_sender = *not_allowed_tx.inputs()[0].input_owner().unwrap();
+    let allowed_senders = allowed_tx
+        .inputs()
+        .iter()
+        .filter_map(|input| input.input_owner().copied())
+        .collect();
+    universe.config.allow_list = AllowList::new(Some(allowed_senders));
+    universe.build_pool();
 
     // When
-    let result = universe.verify_and_insert(tx);
+    let allowed_result = universe
+        .get_pool()
+        .write()
+        .insert(allowed_tx, universe.database());
+    let not_allowed_result = universe
+        .get_pool()
+        .write()
+        .insert(not_allowed_tx, universe.database());
 
     // Then
-    let err = result.unwrap_err();
+    assert!(allowed_result.is_ok());
+    let err = not_allowed_result.unwrap_err();
     assert!(
-        matches!(err, Error::InputValidation(InputValidationError::UtxoNotFound(id)) if id == utxo_id)
+        matches!(err, Error::SenderNotAllowed(sender) if sender == not_allowed_sender)
     );
 }
 
 #[test]
-fn insert__higher_priced_tx_removes_lower_priced_tx() {
+fn insert__tx2_succeeds_after_dependent_tx1() {
     let mut universe = TestPoolUniverse::default();
     universe.build_pool();
 
     // Given
-    let common_coin = universe.setup_coin().1;
-    let tx1 =
-        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
-    let tx_id = tx1.id(&ChainId::default());
-    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 20);
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 0);
+
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
 
     // When
-    universe.verify_and_insert(tx1).unwrap();
-    let result = universe.verify_and_insert(tx2).unwrap();
+    let result1 = universe.verify_and_insert(tx1);
+    let result2 = universe.verify_and_insert(tx2);
 
     // Then
-    assert_eq!(result[0].id(), tx_id);
+    assert!(result1.is_ok());
+    assert!(result2.is_ok());
 }
 
 #[test]
-fn insert__colliding_dependent_and_underpriced_returns_error() {
+fn insert_batch__chained_txs_succeeds() {
     let mut universe = TestPoolUniverse::default();
     universe.build_pool();
 
-    let (output, unset_input) = universe.create_output_and_input();
-    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 20);
-    let utxo_id = UtxoId::new(tx1.id(&ChainId::default()), 0);
-    let input = unset_input.into_input(utxo_id);
-
     // Given
-    let tx2 = universe.build_script_transaction(Some(vec![input.clone()]), None, 20);
-    let tx3 = universe.build_script_transaction(Some(vec![input]), None, 10);
-    universe.verify_and_insert(tx1).unwrap();
-    universe.verify_and_insert(tx2).unwrap();
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let tx2_id = tx2.id(&ChainId::default());
+    let batch = vec![universe.check_basic(tx1), universe.check_basic(tx2)];
 
     // When
-    let result3 = universe.verify_and_insert(tx3);
+    let result = universe
+        .get_pool()
+        .write()
+        .insert_batch(batch, universe.database());
 
     // Then
-    let err = result3.unwrap_err();
-    assert!(matches!(err, Error::Collided(CollisionReason::Utxo(id)) if id == utxo_id));
+    let result = result.unwrap();
+    assert_eq!(result.inserted, vec![tx1_id, tx2_id]);
+    assert!(result.evicted.is_empty());
 }
 
 #[test]
-fn insert_dependent_contract_creation() {
+fn insert_batch__invalid_second_chained_tx_rolls_back_first_tx() {
     let mut universe = TestPoolUniverse::default();
     universe.build_pool();
-    let contract_id = Contract::EMPTY_CONTRACT_ID;
 
     // Given
-    let (_, gas_funds) = universe.setup_coin();
-    let tx1 = TransactionBuilder::create(
-        Default::default(),
-        Default::default(),
-        Default::default(),
-    )
-    .tip(10)
-    .max_fee_limit(10)
-    .add_input(gas_funds)
-    .add_output(create_contract_output(contract_id))
-    .finalize_as_transaction();
-
-    let tx2 = universe.build_script_transaction(
-        Some(vec![create_contract_input(
-            Default::default(),
-            Default::default(),
-            contract_id,
-        )]),
-        Some(vec![Output::contract(
-            0,
-            Default::default(),
-            Default::default(),
-        )]),
-        10,
-    );
+    let (output, unset_input) = universe.create_output_and_input();
+    // The output holds more than the dependent input expects
+    let output = Output::coin(*output.to().unwrap(), 2, AssetId::BASE);
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let batch = vec![universe.check_basic(tx1), universe.check_basic(tx2)];
 
     // When
-    let result1 = universe.verify_and_insert(tx1);
-    let result2 = universe.verify_and_insert(tx2);
+    let result = universe
+        .get_pool()
+        .write()
+        .insert_batch(batch, universe.database());
 
     // Then
-    assert!(result1.is_ok());
-    assert!(result2.is_ok());
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::InputValidation(InputValidationError::NotInsertedIoWrongAmount)
+    ));
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert!(!pool.contains(&tx1_id));
+    assert!(pool.is_empty());
 }
 
 #[test]
-fn insert_more_priced_tx3_removes_tx1_and_dependent_tx2() {
+fn insert_batch__rollback_restores_evicted_tx() {
     let mut universe = TestPoolUniverse::default();
     universe.build_pool();
 
     // Given
     let common_coin = universe.setup_coin().1;
-    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
 
-    let tx1 = universe.build_script_transaction(
-        Some(vec![common_coin.clone()]),
+    let (output, unset_input) = universe.create_output_and_input();
+    let output = Output::coin(*output.to().unwrap(), 2, AssetId::BASE);
+    // Evicts `tx1` thanks to the higher tip
+    let tx2 = universe.build_script_transaction(
+        Some(vec![common_coin]),
         Some(vec![output]),
-        10,
+        20,
     );
-    let tx1_id = tx1.id(&ChainId::default());
-    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
-
-    let tx2 = universe.build_script_transaction(Some(vec![input.clone()]), None, 10);
     let tx2_id = tx2.id(&ChainId::default());
-    universe.verify_and_insert(tx1).unwrap();
-    universe.verify_and_insert(tx2).unwrap();
-
-    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 20);
+    let input = unset_input.into_input(UtxoId::new(tx2_id, 0));
+    let tx3 = universe.build_script_transaction(Some(vec![input]), None, 20);
+    let batch = vec![universe.check_basic(tx2), universe.check_basic(tx3)];
 
     // When
-    let result3 = universe.verify_and_insert(tx3);
+    let result = universe
+        .get_pool()
+        .write()
+        .insert_batch(batch, universe.database());
 
     // Then
-    let removed_txs = result3.unwrap();
-    assert_eq!(removed_txs.len(), 2);
-    assert_eq!(removed_txs[0].id(), tx1_id);
-    assert_eq!(removed_txs[1].id(), tx2_id);
+    assert!(result.is_err());
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert!(pool.contains(&tx1_id));
+    assert!(!pool.contains(&tx2_id));
+    assert_eq!(pool.iter_tx_ids().count(), 1);
 }
 
 #[test]
-fn insert_more_priced_tx2_removes_tx1_and_more_priced_tx3_removes_tx2() {
+fn insert_batch__tx_evicting_another_batch_tx_rejects_batch() {
     let mut universe = TestPoolUniverse::default();
     universe.build_pool();
 
     // Given
+    let existing_tx = universe.build_script_transaction(None, None, 10);
+    let existing_tx_id = existing_tx.id(&ChainId::default());
+    universe.verify_and_insert(existing_tx).unwrap();
     let common_coin = universe.setup_coin().1;
-
     let tx1 =
         universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
     let tx1_id = tx1.id(&ChainId::default());
-
-    universe.verify_and_insert(tx1).unwrap();
-
-    let tx2 =
-        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 11);
+    // Evicts `tx1` thanks to the higher tip
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 20);
     let tx2_id = tx2.id(&ChainId::default());
-
-    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 12);
+    let batch = vec![universe.check_basic(tx1), universe.check_basic(tx2)];
 
     // When
-    let result2 = universe.verify_and_insert(tx2);
-    let result3 = universe.verify_and_insert(tx3);
+    let result = universe
+        .get_pool()
+        .write()
+        .insert_batch(batch, universe.database());
 
     // Then
-    assert!(result2.is_ok());
-    let removed_txs = result2.unwrap();
-    assert_eq!(removed_txs.len(), 1);
-    assert_eq!(removed_txs[0].id(), tx1_id);
-    assert!(result3.is_ok());
-    let removed_txs = result3.unwrap();
-    assert_eq!(removed_txs.len(), 1);
-    assert_eq!(removed_txs[0].id(), tx2_id);
+    assert!(matches!(result, Err(Error::EvictedByBatch(id)) if id == tx1_id));
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert!(pool.contains(&existing_tx_id));
+    assert!(!pool.contains(&tx1_id));
+    assert!(!pool.contains(&tx2_id));
+    assert_eq!(pool.iter_tx_ids().count(), 1);
 }
 
 #[test]
-fn insert__tx_limit_hit() {
-    let mut universe = TestPoolUniverse::default().config(Config {
-        pool_limits: PoolLimits {
-            max_txs: 1,
+fn insert_batch__rollback_keeps_creation_instant_of_evicted_tx() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    let creation_instant = universe
+        .get_pool()
+        .read()
+        .find_one(&tx1_id)
+        .unwrap()
+        .creation_instant;
+    // Evicts `tx1` thanks to the higher tip
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 20);
+    // Spends the coin that is not known to the pool or the database
+    let (_, unset_input) = universe.create_output_and_input();
+    let unknown_coin = unset_input.into_input(UtxoId::new([1u8; 32].into(), 0));
+    let tx3 = universe.build_script_transaction(Some(vec![unknown_coin]), None, 20);
+    let batch = vec![universe.check_basic(tx2), universe.check_basic(tx3)];
+
+    // When
+    let result = universe
+        .get_pool()
+        .write()
+        .insert_batch(batch, universe.database());
+
+    // Then
+    assert!(result.is_err());
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let entry = pool.find_one(&tx1_id).unwrap();
+    assert_eq!(entry.creation_instant, creation_instant);
+}
+
+#[test]
+fn insert_batch__invalid_third_chained_tx_leaves_pool_unchanged() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let existing_tx = universe.build_script_transaction(None, None, 10);
+    let existing_tx_id = existing_tx.id(&ChainId::default());
+    universe.verify_and_insert(existing_tx).unwrap();
+    let stats_before = universe.get_pool().read().stats();
+
+    let (output1, unset_input1) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output1]), 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    let input1 = unset_input1.into_input(UtxoId::new(tx1_id, 0));
+    let (output2, unset_input2) = universe.create_output_and_input();
+    // The output holds more than the dependent input expects
+    let output2 = Output::coin(*output2.to().unwrap(), 2, AssetId::BASE);
+    let tx2 =
+        universe.build_script_transaction(Some(vec![input1]), Some(vec![output2]), 0);
+    let tx2_id = tx2.id(&ChainId::default());
+    let input2 = unset_input2.into_input(UtxoId::new(tx2_id, 0));
+    let tx3 = universe.build_script_transaction(Some(vec![input2]), None, 0);
+    let tx3_id = tx3.id(&ChainId::default());
+    // Dependents are given first, the pool orders the batch by dependencies
+    let batch = vec![
+        universe.check_basic(tx3),
+        universe.check_basic(tx2),
+        universe.check_basic(tx1),
+    ];
+
+    // When
+    let result = universe
+        .get_pool()
+        .write()
+        .insert_batch(batch, universe.database());
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::InputValidation(InputValidationError::NotInsertedIoWrongAmount)
+    ));
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert!(!pool.contains(&tx1_id));
+    assert!(!pool.contains(&tx2_id));
+    assert!(!pool.contains(&tx3_id));
+    assert!(pool.contains(&existing_tx_id));
+    assert_eq!(pool.stats(), stats_before);
+}
+
+#[test]
+fn replacement_count_for__counts_successive_fee_bumps_of_the_same_coin() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (_, gas_coin) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(Some(vec![gas_coin.clone()]), None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(Some(vec![gas_coin.clone()]), None, 20);
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3 = universe.build_script_transaction(Some(vec![gas_coin]), None, 30);
+    let tx3_id = tx3.id(&ChainId::default());
+
+    // When
+    universe.verify_and_insert(tx1).unwrap();
+    let count_after_first = universe.get_pool().read().replacement_count_for(&tx1_id);
+    universe.verify_and_insert(tx2).unwrap();
+    let count_after_second = universe.get_pool().read().replacement_count_for(&tx2_id);
+    universe.verify_and_insert(tx3).unwrap();
+
+    // Then
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert_eq!(count_after_first, Some(0));
+    assert_eq!(count_after_second, Some(1));
+    assert_eq!(pool.replacement_count_for(&tx3_id), Some(2));
+    assert_eq!(pool.replacement_count_for(&tx1_id), None);
+    assert_eq!(pool.replacement_count_for(&tx2_id), None);
+}
+
+#[test]
+fn dependents_count__three_deep_chain_counts_two_descendants_for_root() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output1, unset_input1) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output1]), 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    let input1 = unset_input1.into_input(UtxoId::new(tx1_id, 0));
+    let (output2, unset_input2) = universe.create_output_and_input();
+    let tx2 =
+        universe.build_script_transaction(Some(vec![input1]), Some(vec![output2]), 0);
+    let tx2_id = tx2.id(&ChainId::default());
+    let input2 = unset_input2.into_input(UtxoId::new(tx2_id, 0));
+    let tx3 = universe.build_script_transaction(Some(vec![input2]), None, 0);
+    let tx3_id = tx3.id(&ChainId::default());
+
+    // When
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+
+    // Then
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert_eq!(pool.find_one(&tx1_id).unwrap().dependents_count(), 2);
+    assert_eq!(pool.find_one(&tx2_id).unwrap().dependents_count(), 1);
+    assert_eq!(pool.find_one(&tx3_id).unwrap().dependents_count(), 0);
+}
+
+#[test]
+fn dependents_slice__matches_get_dependents() {
+    const DEPENDENTS: usize = 10;
+
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+    let contract_id = Contract::EMPTY_CONTRACT_ID;
+
+    // Given
+    let (_, gas_coin) = universe.setup_coin();
+    let parent = TransactionBuilder::create(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .add_input(gas_coin)
+    .add_output(create_contract_output(contract_id))
+    .finalize_as_transaction();
+    let parent_id = parent.id(&ChainId::default());
+    let pool = universe.get_pool();
+    pool.write()
+        .insert(universe.check_basic(parent), universe.database())
+        .unwrap();
+    for _ in 0..DEPENDENTS {
+        let tx = universe.build_script_transaction(
+            Some(vec![create_contract_input(
+                Default::default(),
+                Default::default(),
+                contract_id,
+            )]),
+            Some(vec![Output::contract(
+                0,
+                Default::default(),
+                Default::default(),
+            )]),
+            0,
+        );
+        pool.write()
+            .insert(universe.check_basic(tx), universe.database())
+            .unwrap();
+    }
+    let pool = pool.read();
+    let storage_id = pool.tx_id_to_storage_id.get(&parent_id).unwrap();
+
+    // When
+    let slice = SelectionAlgorithmStorage::dependents_slice(&pool.storage, storage_id);
+
+    // Then
+    assert_eq!(slice.len(), DEPENDENTS);
+    assert_eq!(
+        slice.to_vec(),
+        SelectionAlgorithmStorage::get_dependents(&pool.storage, storage_id)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn insert__tx_spending_output_of_its_dependent_is_rejected_as_cycle() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.utxo_validation = false;
+    universe.build_pool();
+
+    // Given
+    let tx2_id = TxId::from([7; 32]);
+    let (output1, unset_input1) = universe.create_output_and_input();
+    let (output2, unset_input2) = universe.create_output_and_input();
+    // tx1 spends an output of tx2 that is not known to the pool yet
+    let input = unset_input2.into_input(UtxoId::new(tx2_id, 0));
+    let tx1 =
+        universe.build_script_transaction(Some(vec![input]), Some(vec![output1]), 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    universe
+        .get_pool()
+        .write()
+        .insert(universe.check_basic(tx1), universe.database())
+        .unwrap();
+    // tx2 depends on tx1, while tx1 depends on tx2
+    let input = unset_input1.into_input(UtxoId::new(tx1_id, 0));
+    let tx2 =
+        universe.build_script_transaction(Some(vec![input]), Some(vec![output2]), 0);
+    let tx2 = universe.check_basic_with_id(tx2, tx2_id);
+
+    // When
+    let result = universe.get_pool().write().insert(tx2, universe.database());
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(err, Error::DependencyCycle));
+}
+
+#[test]
+fn insert__tx2_collided_on_contract_id() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    let contract_id = Contract::EMPTY_CONTRACT_ID;
+
+    // contract creation tx
+    let (_, gas_coin) = universe.setup_coin();
+    let tx = TransactionBuilder::create(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .tip(10)
+    .max_fee_limit(10)
+    .add_input(gas_coin)
+    .add_output(create_contract_output(contract_id))
+    .finalize_as_transaction();
+
+    let (_, gas_coin) = universe.setup_coin();
+
+    // Given
+    // attempt to insert a different creation tx with a valid dependency on the first tx,
+    // but with a conflicting output contract id
+    let tx_faulty = TransactionBuilder::create(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .tip(9)
+    .max_fee_limit(9)
+    .add_input(gas_coin)
+    .add_output(create_contract_output(contract_id))
+    .finalize_as_transaction();
+    universe.verify_and_insert(tx).unwrap();
+
+    // When
+    let result2 = universe.verify_and_insert(tx_faulty);
+
+    // Then
+    let err = result2.unwrap_err();
+    assert!(
+        matches!(err, Error::Collided(CollisionReason::ContractCreation(id)) if id == contract_id)
+    );
+}
+
+#[test]
+fn insert__tx_with_dependency_on_invalid_utxo_type() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+    let contract_id = Contract::EMPTY_CONTRACT_ID;
+
+    let gas_coin = universe.setup_coin().1;
+    let tx = TransactionBuilder::create(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .add_input(gas_coin)
+    .add_output(create_contract_output(contract_id))
+    .finalize_as_transaction();
+    let utxo_id = UtxoId::new(tx.id(&Default::default()), 0);
+
+    // Given
+    // create a second transaction with utxo id referring to
+    // the wrong type of utxo (contract instead of coin)
+    let random_predicate =
+        universe.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, Some(utxo_id));
+    let tx_faulty =
+        universe.build_script_transaction(Some(vec![random_predicate]), None, 0);
+    universe.verify_and_insert(tx).unwrap();
+
+    // When
+    let result2 = universe.verify_and_insert(tx_faulty);
+
+    // Then
+    let err = result2.unwrap_err();
+
+    assert!(
+        matches!(err, Error::InputValidation(InputValidationError::UtxoNotFound(id)) if id == utxo_id)
+    );
+}
+
+#[test]
+fn insert__already_known_tx_returns_error() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        utxo_validation: false,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 0);
+    universe.verify_and_insert(tx.clone()).unwrap();
+
+    // When
+    let result2 = universe.verify_and_insert(tx.clone());
+
+    // Then
+    let err = result2.unwrap_err();
+    assert!(
+        matches!(err, Error::InputValidation(InputValidationError::DuplicateTxId(id)) if id == tx.id(&ChainId::default()))
+    );
+}
+
+#[test]
+fn insert__unknown_utxo_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let input = universe.random_predicate(AssetId::BASE, TEST_COIN_AMOUNT, None);
+    let utxo_id = input.utxo_id().cloned().unwrap();
+    let tx = universe.build_script_transaction(Some(vec![input]), None, 0);
+
+    // When
+    let result = universe.verify_and_insert(tx);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(
+        matches!(err, Error::InputValidation(InputValidationError::UtxoNotFound(id)) if id == utxo_id)
+    );
+}
+
+#[test]
+fn insert__higher_priced_tx_removes_lower_priced_tx() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let tx_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 20);
+
+    // When
+    universe.verify_and_insert(tx1).unwrap();
+    let result = universe.verify_and_insert(tx2).unwrap();
+
+    // Then
+    assert_eq!(result[0].id(), tx_id);
+}
+
+#[test]
+fn get_pending_for_utxo__returns_tx_that_replaced_previous_spender() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let utxo_id = *common_coin.utxo_id().unwrap();
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 20);
+    let tx2_id = tx2.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    let spenders_before_replacement =
+        universe.get_pool().read().get_pending_for_utxo(&utxo_id);
+
+    // When
+    universe.verify_and_insert(tx2).unwrap();
+
+    // Then
+    let spenders = universe.get_pool().read().get_pending_for_utxo(&utxo_id);
+    assert_eq!(spenders_before_replacement, vec![tx1_id]);
+    assert_eq!(spenders, vec![tx2_id]);
+}
+
+#[test]
+fn get_pending_for_utxo__returns_nothing_after_spender_is_removed() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let coin = universe.setup_coin().1;
+    let utxo_id = *coin.utxo_id().unwrap();
+    let tx = universe.build_script_transaction(Some(vec![coin]), None, 10);
+    let tx_id = tx.id(&ChainId::default());
+    universe.verify_and_insert(tx).unwrap();
+
+    // When
+    universe
+        .get_pool()
+        .write()
+        .remove_transaction_and_dependents(vec![tx_id]);
+
+    // Then
+    let spenders = universe.get_pool().read().get_pending_for_utxo(&utxo_id);
+    assert!(spenders.is_empty());
+}
+
+#[test]
+fn remove_by_id__removes_transaction_with_its_dependents() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let tx2_id = tx2.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let removed = universe.get_pool().write().remove_by_id(&tx1_id);
+
+    // Then
+    let mut removed_ids: Vec<_> = removed.unwrap().iter().map(|tx| tx.id()).collect();
+    removed_ids.sort();
+    let mut expected_ids = vec![tx1_id, tx2_id];
+    expected_ids.sort();
+    assert_eq!(removed_ids, expected_ids);
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert!(!pool.contains(&tx1_id));
+    assert!(!pool.contains(&tx2_id));
+}
+
+#[test]
+fn remove_by_id__unknown_transaction_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 0);
+    let tx_id = tx.id(&ChainId::default());
+
+    // When
+    let result = universe.get_pool().write().remove_by_id(&tx_id);
+
+    // Then
+    assert!(matches!(result, Err(Error::TransactionNotFound(id)) if id == tx_id));
+}
+
+#[test]
+fn pin_view__verification_uses_pinned_snapshot_after_provider_changes() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (_, coin) = universe.setup_coin();
+    let provider = Arc::new(SwappableDBProvider::new(universe.database().clone()));
+    let verification = universe.verification(provider.clone());
+    verification.pin_view().unwrap();
+    provider.swap(MockDb::default());
+    let tx = universe.build_script_transaction(Some(vec![coin]), None, 0);
+
+    // When
+    let result = verification.perform_all_verifications(
+        tx,
+        &universe.get_pool(),
+        Default::default(),
+        true,
+    );
+
+    // Then
+    assert!(result.is_ok());
+}
+
+#[test]
+fn unpin_view__verification_uses_latest_view_again() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (_, coin) = universe.setup_coin();
+    let utxo_id = *coin.utxo_id().unwrap();
+    let provider = Arc::new(SwappableDBProvider::new(universe.database().clone()));
+    let verification = universe.verification(provider.clone());
+    verification.pin_view().unwrap();
+    provider.swap(MockDb::default());
+    let tx = universe.build_script_transaction(Some(vec![coin]), None, 0);
+
+    // When
+    verification.unpin_view();
+    let result = verification.perform_all_verifications(
+        tx,
+        &universe.get_pool(),
+        Default::default(),
+        true,
+    );
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(
+        matches!(err, Error::InputValidation(InputValidationError::UtxoNotFound(id)) if id == utxo_id)
+    );
+}
+
+#[test]
+fn update_blacklist__evicts_pooled_tx_of_blacklisted_owner() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (_, coin) = universe.setup_coin();
+    let owner = *coin.input_owner().unwrap();
+    let tx = universe.build_script_transaction(Some(vec![coin]), None, 10);
+    let tx_id = tx.id(&ChainId::default());
+    universe.verify_and_insert(tx).unwrap();
+
+    // When
+    let removed = universe.get_pool().write().update_blacklist(BlackList::new(
+        vec![owner],
+        vec![],
+        vec![],
+        vec![],
+        vec![],
+    ));
+
+    // Then
+    let removed_ids: Vec<_> = removed.iter().map(|tx| tx.id()).collect();
+    assert_eq!(removed_ids, vec![tx_id]);
+    assert!(!universe.get_pool().read().contains(&tx_id));
+}
+
+#[test]
+fn stats__returns_summary_of_pool_state() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 10);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 20);
+    let tx3 = universe.build_script_transaction(None, None, 30);
+    let tx_ids = [&tx1, &tx2, &tx3].map(|tx| tx.id(&ChainId::default()));
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+
+    // When
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let stats = pool.stats();
+
+    // Then
+    let stored_txs = tx_ids
+        .iter()
+        .map(|tx_id| pool.find_one(tx_id).unwrap().transaction.clone())
+        .collect::<Vec<_>>();
+    let ratios = stored_txs
+        .iter()
+        .map(|tx| RatioTipGas::new(tx.tip(), tx.max_gas()))
+        .collect::<Vec<_>>();
+    assert_eq!(stats.tx_count, 3);
+    assert_eq!(stats.executable_tx_count, 2);
+    assert_eq!(stats.pending_tx_count, 1);
+    assert_eq!(
+        stats.current_gas,
+        stored_txs.iter().map(|tx| tx.max_gas()).sum::<u64>()
+    );
+    assert_eq!(
+        stats.current_bytes_size,
+        stored_txs
+            .iter()
+            .map(|tx| tx.metered_bytes_size())
+            .sum::<usize>()
+    );
+    assert_eq!(stats.min_tip_gas_ratio, ratios.iter().min().copied());
+    assert_eq!(stats.max_tip_gas_ratio, ratios.iter().max().copied());
+}
+
+#[test]
+fn stats__empty_pool_has_no_tip_gas_ratio() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // When
+    let stats = universe.get_pool().read().stats();
+
+    // Then
+    assert_eq!(stats.tx_count, 0);
+    assert_eq!(stats.current_gas, 0);
+    assert_eq!(stats.min_tip_gas_ratio, None);
+    assert_eq!(stats.max_tip_gas_ratio, None);
+}
+
+#[test]
+fn insert__colliding_dependent_and_underpriced_returns_error() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 20);
+    let utxo_id = UtxoId::new(tx1.id(&ChainId::default()), 0);
+    let input = unset_input.into_input(utxo_id);
+
+    // Given
+    let tx2 = universe.build_script_transaction(Some(vec![input.clone()]), None, 20);
+    let tx3 = universe.build_script_transaction(Some(vec![input]), None, 10);
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let result3 = universe.verify_and_insert(tx3);
+
+    // Then
+    let err = result3.unwrap_err();
+    assert!(matches!(err, Error::Collided(CollisionReason::Utxo(id)) if id == utxo_id));
+}
+
+#[test]
+fn insert_dependent_contract_creation() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+    let contract_id = Contract::EMPTY_CONTRACT_ID;
+
+    // Given
+    let (_, gas_funds) = universe.setup_coin();
+    let tx1 = TransactionBuilder::create(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .tip(10)
+    .max_fee_limit(10)
+    .add_input(gas_funds)
+    .add_output(create_contract_output(contract_id))
+    .finalize_as_transaction();
+
+    let tx2 = universe.build_script_transaction(
+        Some(vec![create_contract_input(
+            Default::default(),
+            Default::default(),
+            contract_id,
+        )]),
+        Some(vec![Output::contract(
+            0,
+            Default::default(),
+            Default::default(),
+        )]),
+        10,
+    );
+
+    // When
+    let result1 = universe.verify_and_insert(tx1);
+    let result2 = universe.verify_and_insert(tx2);
+
+    // Then
+    assert!(result1.is_ok());
+    assert!(result2.is_ok());
+}
+
+#[test]
+fn replace_transaction__requires_min_fee_bump_ratio() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        min_fee_bump_ratio: Ratio::new(11, 10),
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let old_tx =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 100);
+    let old_tx_id = old_tx.id(&ChainId::default());
+    universe.verify_and_insert(old_tx).unwrap();
+    let below_min_bump_tx =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 109);
+    let below_min_bump_tx = universe.check_basic(below_min_bump_tx);
+    let min_bump_tx =
+        universe.build_script_transaction(Some(vec![common_coin]), None, 110);
+    let min_bump_tx = universe.check_basic(min_bump_tx);
+    let min_bump_tx_id = min_bump_tx.id();
+
+    // When
+    let below_min_bump_result = universe.get_pool().write().replace_transaction(
+        old_tx_id,
+        below_min_bump_tx,
+        universe.database(),
+    );
+    let min_bump_result = universe.get_pool().write().replace_transaction(
+        old_tx_id,
+        min_bump_tx,
+        universe.database(),
+    );
+
+    // Then
+    assert!(matches!(
+        below_min_bump_result,
+        Err(Error::ReplacementFeeInsufficient)
+    ));
+    let removed = min_bump_result.unwrap();
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].id(), old_tx_id);
+    let pool = universe.get_pool();
+    assert!(!pool.read().contains(&old_tx_id));
+    assert!(pool.read().contains(&min_bump_tx_id));
+}
+
+#[test]
+fn replace_transaction__zero_tip_tx_requires_some_tip() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let old_tx =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 0);
+    let old_tx_id = old_tx.id(&ChainId::default());
+    universe.verify_and_insert(old_tx).unwrap();
+    let other_coin = universe.setup_coin().1;
+    let zero_tip_tx = universe.build_script_transaction(
+        Some(vec![common_coin.clone(), other_coin]),
+        None,
+        0,
+    );
+    let zero_tip_tx = universe.check_basic(zero_tip_tx);
+    let tip_tx = universe.build_script_transaction(Some(vec![common_coin]), None, 1);
+    let tip_tx = universe.check_basic(tip_tx);
+
+    // When
+    let zero_tip_result = universe.get_pool().write().replace_transaction(
+        old_tx_id,
+        zero_tip_tx,
+        universe.database(),
+    );
+    let tip_result = universe.get_pool().write().replace_transaction(
+        old_tx_id,
+        tip_tx,
+        universe.database(),
+    );
+
+    // Then
+    assert!(matches!(
+        zero_tip_result,
+        Err(Error::ReplacementFeeInsufficient)
+    ));
+    assert!(tip_result.is_ok());
+}
+
+#[test]
+fn replace_transaction__rejected_replacement_keeps_old_tx_unchanged() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let old_tx =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let old_tx_id = old_tx.id(&ChainId::default());
+    universe.verify_and_insert(old_tx).unwrap();
+    let creation_instant = universe
+        .get_pool()
+        .read()
+        .find_one(&old_tx_id)
+        .unwrap()
+        .creation_instant;
+    // The coin is neither in the database nor created by a pending transaction
+    let (_, unset_input) = universe.create_output_and_input();
+    let unknown_coin = unset_input.into_input(UtxoId::new([1u8; 32].into(), 0));
+    let new_tx = universe.build_script_transaction(
+        Some(vec![common_coin, unknown_coin]),
+        None,
+        1_000,
+    );
+    let new_tx = universe.check_basic(new_tx);
+
+    // When
+    let result = universe.get_pool().write().replace_transaction(
+        old_tx_id,
+        new_tx,
+        universe.database(),
+    );
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(Error::InputValidation(InputValidationError::UtxoNotFound(
+            _
+        )))
+    ));
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let old_entry = pool.find_one(&old_tx_id).unwrap();
+    assert_eq!(old_entry.creation_instant, creation_instant);
+}
+
+#[test]
+fn replace_transaction__keeps_dependents_of_replaced_tx() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+    let contract_id = Contract::EMPTY_CONTRACT_ID;
+
+    // Given
+    let (_, gas_funds) = universe.setup_coin();
+    let create_tx = |tip| {
+        TransactionBuilder::create(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .tip(tip)
+        .max_fee_limit(1000)
+        .add_input(gas_funds.clone())
+        .add_output(create_contract_output(contract_id))
+        .finalize_as_transaction()
+    };
+    let old_tx = create_tx(10);
+    let old_tx_id = old_tx.id(&ChainId::default());
+    let dependent_tx = universe.build_script_transaction(
+        Some(vec![create_contract_input(
+            Default::default(),
+            Default::default(),
+            contract_id,
+        )]),
+        Some(vec![Output::contract(
+            0,
+            Default::default(),
+            Default::default(),
+        )]),
+        10,
+    );
+    let dependent_tx_id = dependent_tx.id(&ChainId::default());
+    universe.verify_and_insert(old_tx).unwrap();
+    universe.verify_and_insert(dependent_tx).unwrap();
+    let new_tx = universe.check_basic(create_tx(20));
+    let new_tx_id = new_tx.id();
+
+    // When
+    let removed = universe
+        .get_pool()
+        .write()
+        .replace_transaction(old_tx_id, new_tx, universe.database())
+        .unwrap();
+
+    // Then
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].id(), old_tx_id);
+    let pool = universe.get_pool();
+    assert!(pool.read().contains(&new_tx_id));
+    assert!(pool.read().contains(&dependent_tx_id));
+}
+
+#[test]
+fn insert_more_priced_tx3_removes_tx1_and_dependent_tx2() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+    let (output, unset_input) = universe.create_output_and_input();
+
+    let tx1 = universe.build_script_transaction(
+        Some(vec![common_coin.clone()]),
+        Some(vec![output]),
+        10,
+    );
+    let tx1_id = tx1.id(&ChainId::default());
+    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
+
+    let tx2 = universe.build_script_transaction(Some(vec![input.clone()]), None, 10);
+    let tx2_id = tx2.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+
+    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 20);
+
+    // When
+    let result3 = universe.verify_and_insert(tx3);
+
+    // Then
+    let removed_txs = result3.unwrap();
+    assert_eq!(removed_txs.len(), 2);
+    assert_eq!(removed_txs[0].id(), tx1_id);
+    assert_eq!(removed_txs[1].id(), tx2_id);
+}
+
+#[test]
+fn insert_more_priced_tx2_removes_tx1_and_more_priced_tx3_removes_tx2() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let common_coin = universe.setup_coin().1;
+
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).unwrap();
+
+    let tx2 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 11);
+    let tx2_id = tx2.id(&ChainId::default());
+
+    let tx3 = universe.build_script_transaction(Some(vec![common_coin]), None, 12);
+
+    // When
+    let result2 = universe.verify_and_insert(tx2);
+    let result3 = universe.verify_and_insert(tx3);
+
+    // Then
+    assert!(result2.is_ok());
+    let removed_txs = result2.unwrap();
+    assert_eq!(removed_txs.len(), 1);
+    assert_eq!(removed_txs[0].id(), tx1_id);
+    assert!(result3.is_ok());
+    let removed_txs = result3.unwrap();
+    assert_eq!(removed_txs.len(), 1);
+    assert_eq!(removed_txs[0].id(), tx2_id);
+}
+
+fn evicted_txs_with_eviction_weights(
+    weights: EvictionWeights,
+) -> (Vec<TxId>, TxId, TxId) {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 2,
+            max_bytes_size: 1000000000,
+            max_gas: 100_000_000_000,
+        },
+        eviction_weights: weights,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // The big transaction pays the best tip per gas, but the worst tip per byte
+    let (_, gas_coin) = universe.setup_coin();
+    let big_tx = TransactionBuilder::script(vec![], vec![0; 2_000])
+        .script_gas_limit(GAS_LIMIT)
+        .tip(300)
+        .max_fee_limit(10000)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let small_tx = universe.build_script_transaction(None, None, 100);
+    let new_tx = universe.build_script_transaction(None, None, 110);
+
+    let big_tx_id = big_tx.id(&ChainId::default());
+    let small_tx_id = small_tx.id(&ChainId::default());
+
+    universe.verify_and_insert(big_tx).unwrap();
+    universe.verify_and_insert(small_tx).unwrap();
+    let evicted = universe
+        .verify_and_insert(new_tx)
+        .unwrap()
+        .iter()
+        .map(|tx| tx.id())
+        .collect();
+
+    (evicted, big_tx_id, small_tx_id)
+}
+
+#[test]
+fn insert__byte_weighted_eviction_changes_evicted_tx() {
+    // Given
+    let default_weights = EvictionWeights::default();
+    let byte_weights = EvictionWeights {
+        tip_per_gas: 0,
+        tip_per_byte: 1,
+    };
+
+    // When
+    let (evicted_by_gas, _, small_tx_id) =
+        evicted_txs_with_eviction_weights(default_weights);
+    let (evicted_by_bytes, big_tx_id, _) =
+        evicted_txs_with_eviction_weights(byte_weights);
+
+    // Then
+    assert_eq!(evicted_by_gas, vec![small_tx_id]);
+    assert_eq!(evicted_by_bytes, vec![big_tx_id]);
+}
+
+#[test]
+fn insert__tx_limit_hit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 1,
+            max_bytes_size: 1000000000,
+            max_gas: 100_000_000_000,
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 0);
+    universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let result2 = universe.verify_and_insert(tx2);
+
+    // Then
+    let err = result2.unwrap_err();
+    assert!(matches!(err, Error::PoolOverloaded { .. }));
+}
+
+#[test]
+fn dry_run_insert__preview_matches_txs_removed_by_insert() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 3,
+            max_bytes_size: 1000000000,
+            max_gas: 100_000_000_000,
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 10);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 10);
+    let common_coin = universe.setup_coin().1;
+    let tx3 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 50);
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+    let tx4 = universe.build_script_transaction(Some(vec![common_coin]), None, 100);
+    let tx4 = universe.check_basic(tx4);
+    let tx_count_before = universe.get_pool().read().stats().tx_count;
+
+    // When
+    let preview = universe
+        .get_pool()
+        .read()
+        .dry_run_insert(tx4.clone(), universe.database())
+        .unwrap();
+
+    // Then
+    assert_eq!(universe.get_pool().read().stats().tx_count, tx_count_before);
+    assert!(preview.executable);
+    let mut previewed = preview.removed;
+    previewed.sort();
+    let mut removed = universe
+        .get_pool()
+        .write()
+        .insert(tx4, universe.database())
+        .unwrap()
+        .iter()
+        .map(|tx| tx.id())
+        .collect::<Vec<_>>();
+    removed.sort();
+    assert_eq!(removed.len(), 3);
+    assert_eq!(previewed, removed);
+}
+
+#[test]
+fn insert_with_priority__bypasses_pool_limit_of_full_pool() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 1,
+            max_bytes_size: 1000000000,
+            max_gas: 100_000_000_000,
+        },
+        allow_priority_overrides: true,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(None, None, 0);
+    let tx2_id = tx2.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    let tx2 = universe.check_basic(tx2);
+    let priority = TxPriority {
+        bypass_pool_limit: true,
+        reserved_gas_slot: 0,
+    };
+
+    // When
+    let removed = universe
+        .get_pool()
+        .write()
+        .insert_with_priority(tx2, priority, universe.database())
+        .unwrap();
+
+    // Then
+    assert!(removed.is_empty());
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert!(pool.contains(&tx1_id));
+    assert!(pool.contains(&tx2_id));
+}
+
+#[test]
+fn insert_with_priority__reserved_gas_slot_extends_gas_limit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        allow_priority_overrides: true,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx1 = universe.check_basic(tx1);
+    let tx1_id = tx1.id();
+    let tx2 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.check_basic(tx2);
+    let tx2_id = tx2.id();
+    universe.get_pool().write().set_pool_limits(PoolLimits {
+        max_txs: 10,
+        max_bytes_size: 1000000000,
+        max_gas: tx1.max_gas(),
+    });
+    universe
+        .get_pool()
+        .write()
+        .insert(tx1, universe.database())
+        .unwrap();
+    let priority = TxPriority {
+        bypass_pool_limit: false,
+        reserved_gas_slot: tx2.max_gas(),
+    };
+
+    // When
+    let removed = universe
+        .get_pool()
+        .write()
+        .insert_with_priority(tx2, priority, universe.database())
+        .unwrap();
+
+    // Then
+    assert!(removed.is_empty());
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert!(pool.contains(&tx1_id));
+    assert!(pool.contains(&tx2_id));
+}
+
+#[test]
+fn insert_with_priority__reserved_gas_slot_is_kept_when_evicting() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        allow_priority_overrides: true,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx1 = universe.check_basic(tx1);
+    let tx1_id = tx1.id();
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let tx2 = universe.check_basic(tx2);
+    let tx2_id = tx2.id();
+    let tx3 = universe.build_script_transaction(None, None, 30);
+    let tx3 = universe.check_basic(tx3);
+    let tx3_id = tx3.id();
+    let priority = TxPriority {
+        bypass_pool_limit: false,
+        reserved_gas_slot: tx2.max_gas(),
+    };
+    universe.get_pool().write().set_pool_limits(PoolLimits {
+        max_txs: 10,
+        max_bytes_size: 1000000000,
+        max_gas: tx1.max_gas(),
+    });
+    universe
+        .get_pool()
+        .write()
+        .insert(tx1, universe.database())
+        .unwrap();
+    universe
+        .get_pool()
+        .write()
+        .insert_with_priority(tx2, priority, universe.database())
+        .unwrap();
+
+    // When
+    let removed = universe
+        .get_pool()
+        .write()
+        .insert_with_priority(tx3, priority, universe.database())
+        .unwrap();
+
+    // Then
+    let removed_ids: Vec<_> = removed.iter().map(|tx| tx.id()).collect();
+    assert_eq!(removed_ids, vec![tx1_id]);
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert!(pool.contains(&tx2_id));
+    assert!(pool.contains(&tx3_id));
+}
+
+#[test]
+fn insert_with_priority__rejected_when_overrides_are_disabled() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 10);
+    let tx = universe.check_basic(tx);
+    let tx_id = tx.id();
+    let priority = TxPriority {
+        bypass_pool_limit: true,
+        reserved_gas_slot: 0,
+    };
+
+    // When
+    let result = universe.get_pool().write().insert_with_priority(
+        tx,
+        priority,
+        universe.database(),
+    );
+
+    // Then
+    assert!(matches!(result, Err(Error::PriorityOverridesDisabled)));
+    assert!(!universe.get_pool().read().contains(&tx_id));
+}
+
+#[test]
+fn insert__pool_overloaded_estimates_retry_after_from_ttl() {
+    let config = Config {
+        pool_limits: PoolLimits {
+            max_txs: 1,
             max_bytes_size: 1000000000,
             max_gas: 100_000_000_000,
         },
+        max_txs_ttl: Duration::from_secs(600),
+        ttl_check_interval: Duration::from_secs(60),
         ..Default::default()
-    });
+    };
+    let mut universe = TestPoolUniverse::default().config(config);
     universe.build_pool();
 
     // Given
@@ -494,8 +1748,11 @@ fn insert__tx_limit_hit() {
     let result2 = universe.verify_and_insert(tx2);
 
     // Then
-    let err = result2.unwrap_err();
-    assert!(matches!(err, Error::NotInsertedLimitHit));
+    let Error::PoolOverloaded { retry_after } = result2.unwrap_err() else {
+        panic!("Expected `PoolOverloaded` error");
+    };
+    assert!(retry_after <= Duration::from_secs(600));
+    assert!(retry_after > Duration::from_secs(590));
 }
 
 #[test]
@@ -529,7 +1786,73 @@ fn insert__tx_gas_limit() {
 
     // Then
     let err = result2.unwrap_err();
-    assert!(matches!(err, Error::NotInsertedLimitHit));
+    assert!(matches!(err, Error::PoolOverloaded { .. }));
+}
+
+#[test]
+fn evict_least_valuable_to_fit__makes_room_for_insert_without_further_eviction() {
+    let mut universe = TestPoolUniverse::default();
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let tx3 = universe.build_script_transaction(None, None, 30);
+    let tx1 = universe.check_basic(tx1);
+    let tx2 = universe.check_basic(tx2);
+    let tx3 = universe.check_basic(tx3);
+    let (needed_gas, needed_bytes) = (tx3.max_gas(), tx3.metered_bytes_size());
+    universe = universe.config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 10000,
+            max_bytes_size: 1000000000,
+            max_gas: tx1.max_gas() + tx2.max_gas(),
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1_id = tx1.id();
+    let pool = universe.get_pool();
+    let mut pool = pool.write();
+    pool.insert(tx1, universe.database()).unwrap();
+    pool.insert(tx2, universe.database()).unwrap();
+
+    // When
+    let evicted = pool
+        .evict_least_valuable_to_fit(needed_gas, needed_bytes)
+        .unwrap();
+
+    // Then
+    let evicted: Vec<_> = evicted.iter().map(|tx| tx.id()).collect();
+    assert_eq!(evicted, vec![tx1_id]);
+    let removed = pool.insert(tx3, universe.database()).unwrap();
+    assert!(removed.is_empty());
+}
+
+#[test]
+fn evict_least_valuable_to_fit__returns_error_and_evicts_nothing_if_cannot_fit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 10000,
+            max_bytes_size: 1000000000,
+            max_gas: 100_000_000_000,
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 10);
+    universe.verify_and_insert(tx).unwrap();
+
+    // When
+    let result = universe
+        .get_pool()
+        .write()
+        .evict_least_valuable_to_fit(100_000_000_001, 0);
+
+    // Then
+    assert!(matches!(result, Err(Error::NotInsertedLimitHit)));
+    assert_eq!(universe.get_pool().read().stats().tx_count, 1);
 }
 
 #[test]
@@ -563,7 +1886,7 @@ fn insert__tx_bytes_limit() {
 
     // Then
     let err = result2.unwrap_err();
-    assert!(matches!(err, Error::NotInsertedLimitHit));
+    assert!(matches!(err, Error::PoolOverloaded { .. }));
 }
 
 #[test]
@@ -588,14 +1911,339 @@ fn insert__dependency_chain_length_hit() {
     universe.verify_and_insert(tx2).unwrap();
 
     // When
-    let result3 = universe.verify_and_insert(tx3);
+    let result3 = universe.verify_and_insert(tx3);
+
+    // Then
+    let err = result3.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Dependency(DependencyError::NotInsertedChainDependencyTooBig)
+    ));
+}
+
+#[test]
+fn insert__dependency_chain_too_deep() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        max_dependent_depth: 4,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let mut transactions = Vec::new();
+    let mut input = None;
+    for _ in 0..6 {
+        let (output, unset_input) = universe.create_output_and_input();
+        let tx = universe.build_script_transaction(
+            input.take().map(|input| vec![input]),
+            Some(vec![output]),
+            0,
+        );
+        input = Some(unset_input.into_input(UtxoId::new(tx.id(&Default::default()), 0)));
+        transactions.push(tx);
+    }
+    // The sixth transaction is five hops away from the first one.
+    let sixth = transactions.pop().unwrap();
+    for tx in transactions {
+        universe.verify_and_insert(tx).unwrap();
+    }
+
+    // When
+    let result = universe.verify_and_insert(sixth);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::DependencyChainTooDeep { depth: 5, max: 4 }
+    ));
+}
+
+fn extract_tips_with_selection_algorithm(kind: SelectionAlgorithmKind) -> Vec<u64> {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.selection_algorithm = kind;
+    universe.build_pool();
+
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+
+    universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(Constraints::new(u64::MAX))
+        .iter()
+        .map(|tx| tx.tip())
+        .collect()
+}
+
+fn encoded_metric_value(name: &str) -> u64 {
+    fuel_core_metrics::encode_metrics()
+        .unwrap()
+        .lines()
+        .find_map(|line| line.strip_prefix(name)?.strip_prefix(' ')?.parse().ok())
+        .unwrap_or_default()
+}
+
+#[test]
+fn extract_transactions_for_block__records_selection_metrics() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.metrics = true;
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    let durations_before = encoded_metric_value("txpool_selection_seconds_count");
+    let counts_before = encoded_metric_value("txpool_selected_transactions_count_count");
+    let inclusions_before =
+        encoded_metric_value("txpool_time_to_inclusion_seconds_count");
+    let fast_inclusions_before =
+        encoded_metric_value("txpool_time_to_inclusion_seconds_bucket{le=\"0.005\"}");
+    std::thread::sleep(Duration::from_millis(10));
+
+    // When
+    let extracted = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(Constraints::new(u64::MAX));
+
+    // Then
+    assert_eq!(extracted.len(), 2);
+    assert_eq!(
+        encoded_metric_value("txpool_selection_seconds_count"),
+        durations_before.saturating_add(1)
+    );
+    assert_eq!(
+        encoded_metric_value("txpool_selected_transactions_count_count"),
+        counts_before.saturating_add(1)
+    );
+    assert_eq!(
+        encoded_metric_value("txpool_time_to_inclusion_seconds_count"),
+        inclusions_before.saturating_add(2)
+    );
+    assert_eq!(
+        encoded_metric_value("txpool_time_to_inclusion_seconds_bucket{le=\"0.005\"}"),
+        fast_inclusions_before
+    );
+}
+
+#[test]
+fn extract_transactions_for_block__order_depends_on_selection_algorithm() {
+    // Given
+    let ratio_tip_gas = SelectionAlgorithmKind::RatioTipGas;
+    let fifo = SelectionAlgorithmKind::Fifo;
+
+    // When
+    let ratio_tip_gas_tips = extract_tips_with_selection_algorithm(ratio_tip_gas);
+    let fifo_tips = extract_tips_with_selection_algorithm(fifo);
+
+    // Then
+    assert_eq!(ratio_tip_gas_tips, vec![20, 10]);
+    assert_eq!(fifo_tips, vec![10, 20]);
+}
+
+#[test]
+fn extract_transactions_for_block__respects_max_gas_per_owner() {
+    const TXS_PER_OWNER: u64 = 10;
+
+    let mut universe = TestPoolUniverse::default().config(Config {
+        utxo_validation: false,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let owner_code = |salt: u8| -> Vec<u8> {
+        let mut code: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+        code.push(salt);
+        code
+    };
+    let owners = [
+        Input::predicate_owner(&owner_code(1)),
+        Input::predicate_owner(&owner_code(2)),
+    ];
+    let mut tx_gas = 0;
+    for (salt, base_tip) in [(1, 1_000), (2, 500)] {
+        for i in 0..TXS_PER_OWNER {
+            let coin = universe
+                .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, owner_code(salt), None)
+                .into_default_estimated();
+            let tx = universe.build_script_transaction(
+                Some(vec![coin]),
+                None,
+                base_tip.saturating_add(i),
+            );
+            tx_gas = universe.check_basic(tx.clone()).max_gas();
+            universe.verify_and_insert(tx).unwrap();
+        }
+    }
+    let max_gas = tx_gas.saturating_mul(TXS_PER_OWNER);
+    let quota = max_gas.saturating_mul(3).saturating_div(10);
+    let constraints = Constraints::new(max_gas).with_max_gas_per_owner(quota);
+
+    // When
+    let extracted = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints);
+
+    // Then
+    for owner in owners {
+        let owner_gas: u64 = extracted
+            .iter()
+            .filter(|tx| tx.inputs()[0].input_owner() == Some(&owner))
+            .map(|tx| tx.max_gas())
+            .sum();
+        assert!(owner_gas > 0);
+        assert!(owner_gas <= quota);
+    }
+}
+
+#[test]
+fn peek_block_txs__returns_same_txs_as_extract() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 10);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 20);
+    let tx3 = universe.build_script_transaction(None, None, 5);
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+    let constraints = || Constraints::new(u64::MAX);
+
+    // When
+    let peeked = universe.get_pool().read().peek_block_txs(constraints());
+
+    // Then
+    assert_eq!(peeked.len(), 3);
+    assert_eq!(universe.get_pool().read().stats().tx_count, 3);
+    let extracted: Vec<_> = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints())
+        .iter()
+        .map(|tx| tx.id())
+        .collect();
+    assert_eq!(peeked, extracted);
+}
+
+#[test]
+fn extract_transactions_for_block__raised_minimal_gas_price_excludes_cheaper_txs() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let cheap_coin = universe.setup_coin().1;
+    let cheap_tx = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10_000)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(cheap_coin)
+        .finalize_as_transaction();
+    let expensive_coin = universe.setup_coin().1;
+    let expensive_tx = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(1_000_000)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(expensive_coin)
+        .finalize_as_transaction();
+    let cheap_tx_id = cheap_tx.id(&ChainId::default());
+    let expensive_tx_id = expensive_tx.id(&ChainId::default());
+    universe.verify_and_insert(cheap_tx).unwrap();
+    universe.verify_and_insert(expensive_tx).unwrap();
+    let gas_price_of = |tx_id| {
+        universe
+            .get_pool()
+            .read()
+            .find_one(&tx_id)
+            .unwrap()
+            .transaction
+            .max_gas_price()
+    };
+    let cheap_gas_price = gas_price_of(cheap_tx_id);
+    let expensive_gas_price = gas_price_of(expensive_tx_id);
+    assert!(cheap_gas_price < expensive_gas_price);
+    let selectable = universe.get_pool().read().peek_block_txs(
+        Constraints::new(u64::MAX).with_minimal_gas_price(cheap_gas_price),
+    );
+    assert_eq!(selectable.len(), 2);
+
+    // When
+    let extracted = universe.get_pool().write().extract_transactions_for_block(
+        Constraints::new(u64::MAX).with_minimal_gas_price(expensive_gas_price),
+    );
+
+    // Then
+    assert_eq!(extracted.len(), 1);
+    assert_eq!(extracted[0].id(), expensive_tx_id);
+    assert!(universe.get_pool().read().contains(&cheap_tx_id));
+}
+
+#[test]
+fn top_k_transactions__returns_best_txs_without_extracting_them() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 9);
+    let tx3 = universe.build_script_transaction(None, None, 20);
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+    let constraints = Constraints::new(u64::MAX);
+
+    // When
+    let top = universe
+        .get_pool()
+        .read()
+        .top_k_transactions(2, &constraints);
+
+    // Then
+    assert_eq!(top, vec![tx3_id, tx1_id]);
+    let extracted = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints.with_max_txs(2))
+        .iter()
+        .map(|tx| tx.id())
+        .collect::<Vec<_>>();
+    assert_eq!(extracted, top);
+}
+
+#[test]
+fn extract_transactions_for_block__stops_at_max_txs_even_if_gas_left() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    for tip in 0..30 {
+        let tx = universe.build_script_transaction(None, None, tip);
+        universe.verify_and_insert(tx).unwrap();
+    }
+    let constraints = Constraints::new(u64::MAX).with_max_txs(10);
+
+    // When
+    let extracted = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints);
 
     // Then
-    let err = result3.unwrap_err();
-    assert!(matches!(
-        err,
-        Error::Dependency(DependencyError::NotInsertedChainDependencyTooBig)
-    ));
+    assert_eq!(extracted.len(), 10);
+    assert_eq!(universe.get_pool().read().tx_id_to_storage_id.len(), 20);
 }
 
 #[test]
@@ -620,12 +2268,7 @@ fn get_sorted_out_tx1_2_3() {
     let txs = universe
         .get_pool()
         .write()
-        .extract_transactions_for_block(Constraints {
-            minimal_gas_price: 0,
-            max_gas: u64::MAX,
-            maximum_txs: u16::MAX,
-            maximum_block_size: u32::MAX,
-        });
+        .extract_transactions_for_block(Constraints::new(u64::MAX));
 
     // Then
     assert_eq!(txs.len(), 3, "Should have 3 txs");
@@ -676,12 +2319,7 @@ fn get_sorted_out_tx_same_tips() {
     let txs = universe
         .get_pool()
         .write()
-        .extract_transactions_for_block(Constraints {
-            minimal_gas_price: 0,
-            max_gas: u64::MAX,
-            maximum_txs: u16::MAX,
-            maximum_block_size: u32::MAX,
-        });
+        .extract_transactions_for_block(Constraints::new(u64::MAX));
 
     // Then
     assert_eq!(txs.len(), 3, "Should have 3 txs");
@@ -732,12 +2370,7 @@ fn get_sorted_out_tx_profitable_ratios() {
     let txs = universe
         .get_pool()
         .write()
-        .extract_transactions_for_block(Constraints {
-            minimal_gas_price: 0,
-            max_gas: u64::MAX,
-            maximum_txs: u16::MAX,
-            maximum_block_size: u32::MAX,
-        });
+        .extract_transactions_for_block(Constraints::new(u64::MAX));
 
     // Then
     assert_eq!(txs.len(), 3, "Should have 3 txs");
@@ -770,12 +2403,7 @@ fn get_sorted_out_tx_by_creation_instant() {
     let txs = universe
         .get_pool()
         .write()
-        .extract_transactions_for_block(Constraints {
-            minimal_gas_price: 0,
-            max_gas: u64::MAX,
-            maximum_txs: u16::MAX,
-            maximum_block_size: u32::MAX,
-        });
+        .extract_transactions_for_block(Constraints::new(u64::MAX));
 
     // Then
     // This order doesn't match the lexicographical order of the tx ids
@@ -788,6 +2416,40 @@ fn get_sorted_out_tx_by_creation_instant() {
     assert_eq!(txs[3].id(), tx4_id, "Fourth should be tx4");
 }
 
+#[test]
+fn debug_order__sorted_by_descending_ratio_then_by_creation_instant() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let tx3 = universe.build_script_transaction(None, None, 0);
+    let tx4 = universe.build_script_transaction(None, None, 50);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+    let tx4_id = tx4.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+    universe.verify_and_insert(tx4).unwrap();
+
+    // When
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let ConfiguredSelection::RatioTipGas(selection) = &pool.selection_algorithm else {
+        panic!("The default selection algorithm should be RatioTipGas");
+    };
+    let order = selection.debug_order();
+
+    // Then
+    let ids: Vec<_> = order.iter().map(|(tx_id, _)| *tx_id).collect();
+    assert_eq!(ids, vec![tx4_id, tx2_id, tx1_id, tx3_id]);
+    assert!(order.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+}
+
 #[test]
 fn insert_tx_at_least_min_gas_price() {
     // Given
@@ -1094,6 +2756,59 @@ fn insert_tx_with_blob() {
     .unwrap();
 }
 
+fn blob_transaction() -> Transaction {
+    let program = vec![123; 123];
+    TransactionBuilder::blob(BlobBody {
+        id: BlobId::compute(program.as_slice()),
+        witness_index: 0,
+    })
+    .add_witness(program.into())
+    .add_fee_input()
+    .finalize_as_transaction()
+}
+
+#[test]
+fn insert__blob_tx_over_max_blob_tx_bytes_is_rejected() {
+    let tx = blob_transaction();
+    let size = TestPoolUniverse::default()
+        .check_basic(tx.clone())
+        .metered_bytes_size();
+    let mut universe = TestPoolUniverse::default().config(Config {
+        utxo_validation: false,
+        max_blob_tx_bytes: size - 1,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // When
+    let err = universe.verify_and_insert(tx).unwrap_err();
+
+    // Then
+    assert!(
+        matches!(err, Error::BlobTooLarge { size: s, max } if s == size && max == size - 1)
+    );
+}
+
+#[test]
+fn insert__blob_tx_within_max_blob_tx_bytes_is_accepted() {
+    let tx = blob_transaction();
+    let size = TestPoolUniverse::default()
+        .check_basic(tx.clone())
+        .metered_bytes_size();
+    let mut universe = TestPoolUniverse::default().config(Config {
+        utxo_validation: false,
+        max_blob_tx_bytes: size,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // When
+    let result = universe.verify_and_insert(tx);
+
+    // Then
+    assert!(result.is_ok());
+}
+
 #[test]
 fn insert__tx_with_blob_already_inserted_at_higher_tip() {
     let mut universe = TestPoolUniverse::default().config(Config {
@@ -1267,3 +2982,418 @@ fn insert__tx_upgrade_with_invalid_wasm() {
         Error::WasmValidity(WasmValidityError::NotEnabled)
     ));
 }
+
+#[test]
+fn new_executable_transactions__bulk_insert_matches_single_inserts() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    for tip in [10, 30, 20] {
+        let tx = universe.build_script_transaction(None, None, tip);
+        universe.verify_and_insert(tx).unwrap();
+    }
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let ids: Vec<_> = pool.tx_id_to_storage_id.values().copied().collect();
+    let mut selection = RatioTipGasSelection::new();
+
+    // When
+    let result = selection.new_executable_transactions(ids, &pool.storage);
+
+    // Then
+    assert!(result.is_ok());
+    assert_eq!(selection.number_of_executable_transactions(), 3);
+    assert!(selection
+        .get_less_worth_txs()
+        .eq(pool.selection_algorithm.get_less_worth_txs()));
+}
+
+#[test]
+fn new_executable_transactions__returns_error_and_inserts_nothing_for_unknown_id() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    let pool = universe.get_pool();
+    let mut pool = pool.write();
+    let ids: Vec<_> = pool.tx_id_to_storage_id.values().copied().collect();
+    let removed = pool.remove_transaction_and_dependents(vec![tx1_id]);
+    assert_eq!(removed.len(), 1);
+    let mut selection = RatioTipGasSelection::new();
+
+    // When
+    let result = selection.new_executable_transactions(ids, &pool.storage);
+
+    // Then
+    assert!(matches!(result, Err(Error::Storage(_))));
+    assert_eq!(selection.number_of_executable_transactions(), 0);
+}
+
+#[test]
+fn set_pool_limits__evicts_less_worth_txs_until_new_limits_are_satisfied() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tips = [10, 50, 20, 40, 30];
+    let tx_ids: Vec<_> = tips
+        .into_iter()
+        .map(|tip| {
+            let tx = universe.build_script_transaction(None, None, tip);
+            let tx_id = tx.id(&ChainId::default());
+            universe.verify_and_insert(tx).unwrap();
+            tx_id
+        })
+        .collect();
+
+    // When
+    let removed = universe.get_pool().write().set_pool_limits(PoolLimits {
+        max_txs: 2,
+        max_gas: u64::MAX,
+        max_bytes_size: usize::MAX,
+    });
+
+    // Then
+    let mut removed_ids: Vec<_> = removed.iter().map(|tx| tx.id()).collect();
+    removed_ids.sort();
+    let mut expected_removed = vec![tx_ids[0], tx_ids[2], tx_ids[4]];
+    expected_removed.sort();
+    assert_eq!(removed_ids, expected_removed);
+
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert_eq!(pool.iter_tx_ids().count(), 2);
+    assert!(pool.contains(&tx_ids[1]));
+    assert!(pool.contains(&tx_ids[3]));
+}
+
+#[test]
+fn set_pool_limits__byte_weighted_eviction_evicts_less_worth_txs() {
+    let mut universe = TestPoolUniverse::default();
+    universe.config.eviction_weights = EvictionWeights {
+        tip_per_gas: 0,
+        tip_per_byte: 1,
+    };
+    universe.build_pool();
+
+    // Given
+    let tips = [10, 50, 20, 40, 30];
+    let tx_ids: Vec<_> = tips
+        .into_iter()
+        .map(|tip| {
+            let tx = universe.build_script_transaction(None, None, tip);
+            let tx_id = tx.id(&ChainId::default());
+            universe.verify_and_insert(tx).unwrap();
+            tx_id
+        })
+        .collect();
+
+    // When
+    let removed = universe.get_pool().write().set_pool_limits(PoolLimits {
+        max_txs: 2,
+        max_gas: u64::MAX,
+        max_bytes_size: usize::MAX,
+    });
+
+    // Then
+    let mut removed_ids: Vec<_> = removed.iter().map(|tx| tx.id()).collect();
+    removed_ids.sort();
+    let mut expected_removed = vec![tx_ids[0], tx_ids[2], tx_ids[4]];
+    expected_removed.sort();
+    assert_eq!(removed_ids, expected_removed);
+}
+
+#[test]
+fn on_reorg__removes_spenders_of_invalidated_utxo_and_their_dependents() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (_, coin) = universe.setup_coin();
+    let invalidated_utxo = *coin.utxo_id().unwrap();
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(Some(vec![coin]), Some(vec![output]), 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 20);
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3 = universe.build_script_transaction(None, None, 30);
+    let tx3_id = tx3.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+
+    // When
+    let removed = universe.get_pool().write().on_reorg(&[invalidated_utxo]);
+
+    // Then
+    let mut removed_ids: Vec<_> = removed.iter().map(|tx| tx.id()).collect();
+    removed_ids.sort();
+    let mut expected_removed = vec![tx1_id, tx2_id];
+    expected_removed.sort();
+    assert_eq!(removed_ids, expected_removed);
+
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    assert!(!pool.contains(&tx1_id));
+    assert!(!pool.contains(&tx2_id));
+    assert!(pool.contains(&tx3_id));
+}
+
+#[test]
+fn transaction_age__returns_time_since_insertion() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 10);
+    let tx_id = tx.id(&ChainId::default());
+    universe.verify_and_insert(tx).unwrap();
+
+    // When
+    std::thread::sleep(Duration::from_millis(10));
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let age = pool.transaction_age(&tx_id);
+
+    // Then
+    let age = age.unwrap();
+    assert!(age >= Duration::from_millis(10), "{age:?}");
+    assert!(pool.stats().p99_age >= age);
+    assert_eq!(pool.transaction_age(&TxId::default()), None);
+}
+
+#[test]
+fn transaction_dependency_depth__counts_hops_to_the_root() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let mut tx_ids = Vec::new();
+    let mut input = None;
+    for _ in 0..5 {
+        let (output, unset_input) = universe.create_output_and_input();
+        let tx = universe.build_script_transaction(
+            input.take().map(|input| vec![input]),
+            Some(vec![output]),
+            0,
+        );
+        let tx_id = tx.id(&ChainId::default());
+        input = Some(unset_input.into_input(UtxoId::new(tx_id, 0)));
+        universe.verify_and_insert(tx).unwrap();
+        tx_ids.push(tx_id);
+    }
+
+    // When
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let depths: Vec<_> = tx_ids
+        .iter()
+        .map(|tx_id| pool.transaction_dependency_depth(tx_id))
+        .collect();
+
+    // Then
+    assert_eq!(depths, vec![Some(0), Some(1), Some(2), Some(3), Some(4)]);
+    assert_eq!(pool.transaction_dependency_depth(&TxId::default()), None);
+}
+
+#[test]
+fn clear__removes_all_transactions_and_allows_new_insertions() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let parent = universe.build_script_transaction(None, Some(vec![output]), 10);
+    let input = unset_input.into_input(UtxoId::new(parent.id(&ChainId::default()), 0));
+    let child = universe.build_script_transaction(Some(vec![input]), None, 10);
+    let independent = universe.build_script_transaction(None, None, 20);
+    universe.verify_and_insert(parent).unwrap();
+    universe.verify_and_insert(child).unwrap();
+    universe.verify_and_insert(independent).unwrap();
+
+    // When
+    let removed = universe.get_pool().write().clear();
+
+    // Then
+    assert_eq!(removed.len(), 3);
+    assert!(universe.get_pool().read().is_empty());
+    let fresh = universe.build_script_transaction(None, None, 30);
+    let fresh_id = fresh.id(&ChainId::default());
+    universe.verify_and_insert(fresh).unwrap();
+    assert!(universe.get_pool().read().contains(&fresh_id));
+}
+
+#[test]
+fn estimate_predicate_gas__returns_gas_used_by_predicate_input() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let predicate = universe.custom_predicate(
+        AssetId::BASE,
+        TEST_COIN_AMOUNT,
+        vec![op::ret(1)].into_iter().collect(),
+        None,
+    );
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(predicate)
+        .finalize_as_transaction();
+
+    // When
+    let result = universe.get_pool().read().estimate_predicate_gas(
+        &tx,
+        &ConsensusParameters::standard(),
+        MemoryInstance::new(),
+        universe.database(),
+    );
+
+    // Then
+    let predicate_gas = result.expect("Predicate should be estimated");
+    assert!(matches!(predicate_gas.as_slice(), [gas] if *gas > 0));
+}
+
+#[test]
+fn on_removed_transaction__unknown_transaction_keeps_selection_consistent() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let tx2_id = tx2.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let tx1_storage_id = pool.tx_id_to_storage_id[&tx1_id];
+    let tx2_storage_id = pool.tx_id_to_storage_id[&tx2_id];
+    let mut selection = RatioTipGasSelection::new();
+    selection
+        .new_executable_transactions(vec![tx2_storage_id], &pool.storage)
+        .unwrap();
+    let unknown_entry =
+        SelectionAlgorithmStorage::get(&pool.storage, &tx1_storage_id).unwrap();
+
+    // When
+    selection.on_removed_transaction(unknown_entry);
+
+    // Then
+    assert_eq!(selection.number_of_executable_transactions(), 1);
+    #[cfg(debug_assertions)]
+    selection.verify_invariants();
+}
+
+#[test]
+fn inspect_eviction_candidates__matches_txs_evicted_by_insert() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 3,
+            max_bytes_size: 1000000000,
+            max_gas: 100_000_000_000,
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    for tip in [10, 20, 30] {
+        let tx = universe.build_script_transaction(None, None, tip);
+        universe.verify_and_insert(tx).unwrap();
+    }
+    let new_tx = universe.build_script_transaction(None, None, 100);
+    let new_tx = universe.check_basic(new_tx);
+
+    // When
+    let candidates = universe
+        .get_pool()
+        .read()
+        .inspect_eviction_candidates(&new_tx)
+        .unwrap();
+
+    // Then
+    assert_eq!(universe.get_pool().read().stats().tx_count, 3);
+    let evicted = universe
+        .get_pool()
+        .write()
+        .insert(new_tx, universe.database())
+        .unwrap()
+        .iter()
+        .map(|tx| tx.id())
+        .collect::<Vec<_>>();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates, evicted);
+}
+
+#[test]
+fn inspect_eviction_candidates__returns_nothing_when_pool_has_space() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 10);
+    universe.verify_and_insert(tx).unwrap();
+    let new_tx = universe.build_script_transaction(None, None, 100);
+    let new_tx = universe.check_basic(new_tx);
+
+    // When
+    let candidates = universe
+        .get_pool()
+        .read()
+        .inspect_eviction_candidates(&new_tx)
+        .unwrap();
+
+    // Then
+    assert!(candidates.is_empty());
+}
+
+#[test]
+fn merge__combines_selections_without_duplicates() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let tx_ids: Vec<_> = [10, 40, 20, 30]
+        .into_iter()
+        .map(|tip| {
+            let tx = universe.build_script_transaction(None, None, tip);
+            let tx_id = tx.id(&ChainId::default());
+            universe.verify_and_insert(tx).unwrap();
+            tx_id
+        })
+        .collect();
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let storage_ids: Vec<_> = tx_ids
+        .iter()
+        .map(|tx_id| pool.tx_id_to_storage_id[tx_id])
+        .collect();
+    let mut selection = RatioTipGasSelection::new();
+    selection
+        .new_executable_transactions(storage_ids[..3].to_vec(), &pool.storage)
+        .unwrap();
+    let mut other = RatioTipGasSelection::new();
+    other
+        .new_executable_transactions(storage_ids[1..].to_vec(), &pool.storage)
+        .unwrap();
+
+    // When
+    selection.merge(other);
+
+    // Then
+    let merged: Vec<_> = selection
+        .debug_order()
+        .into_iter()
+        .map(|(tx_id, _)| tx_id)
+        .collect();
+    assert_eq!(merged, vec![tx_ids[1], tx_ids[3], tx_ids[2], tx_ids[0]]);
+    assert_eq!(selection.number_of_executable_transactions(), 4);
+}
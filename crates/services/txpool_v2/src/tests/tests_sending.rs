@@ -133,7 +133,10 @@ fn test_send_inner(
             .filter(|(_, state)| {
                 !matches!(
                     state,
-                    State::Closed | State::EarlySuccess(_) | State::Failed
+                    State::Closed
+                        | State::EarlySuccess(_)
+                        | State::Failed
+                        | State::Replaced(_)
                 )
             })
             .collect::<Vec<_>>()
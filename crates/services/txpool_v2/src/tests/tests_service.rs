@@ -1,6 +1,9 @@
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    fuel_tx::{
+        UniqueIdentifier,
+        UtxoId,
+    },
     fuel_types::ChainId,
     services::txpool::TransactionStatus,
 };
@@ -8,7 +11,15 @@ use std::time::Duration;
 use tokio_stream::StreamExt;
 
 use crate::{
-    config::Config,
+    config::{
+        Config,
+        PoolLimits,
+    },
+    error::{
+        Error,
+        InputValidationError,
+        RemovedReason,
+    },
     tests::universe::TestPoolUniverse,
     tx_status_stream::TxStatusMessage,
 };
@@ -319,3 +330,148 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn fee_bump_replacement_notifies_replaced_subscription() {
+    let mut universe = TestPoolUniverse::default();
+
+    let (_, gas_coin) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(Some(vec![gas_coin.clone()]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![gas_coin]), None, 20);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    let mut tx1_subscribe_updates = service.shared.tx_update_subscribe(tx1_id).unwrap();
+    let mut tx2_subscribe_updates = service.shared.tx_update_subscribe(tx2_id).unwrap();
+
+    service.shared.try_insert(vec![tx1]).unwrap();
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert!(
+        matches!(
+            update,
+            TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+        ),
+        "First message in tx1 stream should be Submitted"
+    );
+
+    service.shared.try_insert(vec![tx2]).unwrap();
+    let update = tx2_subscribe_updates.next().await.unwrap();
+    assert!(
+        matches!(
+            update,
+            TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+        ),
+        "First message in tx2 stream should be Submitted"
+    );
+
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Replaced { by: tx2_id },
+        "Second message in tx1 stream should be Replaced by tx2"
+    );
+    assert!(
+        service.shared.find_one(tx1_id).await.unwrap().is_none(),
+        "tx1 should no longer be in the pool"
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn less_worth_eviction_reports_the_inserted_tx_id() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 1,
+            max_bytes_size: 1000000000,
+            max_gas: 100_000_000_000,
+        },
+        ..Default::default()
+    });
+
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    let mut tx1_subscribe_updates = service.shared.tx_update_subscribe(tx1_id).unwrap();
+    let mut tx2_subscribe_updates = service.shared.tx_update_subscribe(tx2_id).unwrap();
+
+    service.shared.try_insert(vec![tx1]).unwrap();
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert!(
+        matches!(
+            update,
+            TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+        ),
+        "First message in tx1 stream should be Submitted"
+    );
+
+    service.shared.try_insert(vec![tx2]).unwrap();
+    let update = tx2_subscribe_updates.next().await.unwrap();
+    assert!(
+        matches!(
+            update,
+            TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+        ),
+        "First message in tx2 stream should be Submitted"
+    );
+
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: Error::Removed(RemovedReason::LessWorth(tx2_id)).to_string(),
+        }),
+        "Second message in tx1 stream should name tx2 as the less worth replacement"
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert_with_pinned_view__returns_result_of_each_insertion() {
+    let mut universe = TestPoolUniverse::default();
+
+    // Given
+    let (_, coin1) = universe.setup_coin();
+    let (_, coin2) = universe.setup_coin();
+    let tx1 = universe.build_script_transaction(Some(vec![coin1]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![coin2]), None, 20);
+    // The coin is neither in the database nor created by a pending transaction
+    let (_, unset_input) = universe.create_output_and_input();
+    let unknown_utxo_id = UtxoId::new([1u8; 32].into(), 0);
+    let unknown_coin = unset_input.into_input(unknown_utxo_id);
+    let tx3 = universe.build_script_transaction(Some(vec![unknown_coin]), None, 30);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // When
+    let results = service
+        .shared
+        .insert_with_pinned_view(vec![tx1, tx2, tx3])
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert!(matches!(
+        &results[2],
+        Err(Error::InputValidation(InputValidationError::UtxoNotFound(id))) if *id == unknown_utxo_id
+    ));
+    assert!(service.shared.find_one(tx1_id).await.unwrap().is_some());
+    assert!(service.shared.find_one(tx2_id).await.unwrap().is_some());
+
+    service.stop_and_await().await.unwrap();
+}
@@ -0,0 +1,127 @@
+#![allow(non_snake_case)]
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::Arc,
+};
+
+use parking_lot::Mutex;
+use tracing::{
+    field::{
+        Field,
+        Visit,
+    },
+    span::{
+        Attributes,
+        Id,
+        Record,
+    },
+    Event,
+    Metadata,
+    Subscriber,
+};
+
+use crate::{
+    selection_algorithms::Constraints,
+    tests::universe::TestPoolUniverse,
+};
+
+/// Records the fields of all spans into a single map.
+#[derive(Clone, Default)]
+struct SpanFieldsRecorder {
+    fields: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl SpanFieldsRecorder {
+    fn field(&self, name: &str) -> Option<String> {
+        self.fields.lock().get(name).cloned()
+    }
+}
+
+struct FieldsVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+impl Subscriber for SpanFieldsRecorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        span.record(&mut FieldsVisitor(&mut self.fields.lock()));
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        values.record(&mut FieldsVisitor(&mut self.fields.lock()));
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn insert__span_records_tx_id_in_hex() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+    let recorder = SpanFieldsRecorder::default();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 10);
+    let tx = universe.check_basic(tx);
+    let tx_id = tx.id();
+    let gas = tx.max_gas();
+
+    // When
+    tracing::subscriber::with_default(recorder.clone(), || {
+        universe
+            .get_pool()
+            .write()
+            .insert(tx, universe.database())
+            .unwrap();
+    });
+
+    // Then
+    let recorded_tx_id = recorder.field("tx_id").unwrap();
+    assert_eq!(recorded_tx_id, tx_id.to_string());
+    assert!(recorded_tx_id.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(recorder.field("gas"), Some(gas.to_string()));
+    assert!(recorder.field("bytes_size").is_some());
+}
+
+#[test]
+fn extract_transactions_for_block__span_records_count_and_total_gas() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+    let recorder = SpanFieldsRecorder::default();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let extracted = tracing::subscriber::with_default(recorder.clone(), || {
+        universe
+            .get_pool()
+            .write()
+            .extract_transactions_for_block(Constraints::new(u64::MAX))
+    });
+
+    // Then
+    let total_gas: u64 = extracted.iter().map(|tx| tx.max_gas()).sum();
+    assert_eq!(recorder.field("count"), Some("2".to_string()));
+    assert_eq!(recorder.field("total_gas"), Some(total_gas.to_string()));
+}
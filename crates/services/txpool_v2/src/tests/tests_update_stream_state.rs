@@ -41,14 +41,17 @@ pub(crate) fn validate_tx_update_stream_state(
         // If not Submitted, it's an early success.
         (Empty, AddMsg(TxStatusMessage::Status(s))) => EarlySuccess(s),
         (Empty, AddMsg(TxStatusMessage::FailedStatus)) => Failed,
+        (Empty, AddMsg(TxStatusMessage::Replaced { by })) => Replaced(by),
         (Empty, AddFailure) => Failed,
         (Empty | Initial(_), Next) => Empty,
         (Initial(s1), AddMsg(TxStatusMessage::Status(s2))) => Success(s1, s2),
         (Initial(s1), AddMsg(TxStatusMessage::FailedStatus)) => LateFailed(s1),
+        (Initial(s1), AddMsg(TxStatusMessage::Replaced { by })) => LateReplaced(s1, by),
         (Initial(s), AddFailure) => LateFailed(s),
         (_, CloseRecv) => Closed,
-        (EarlySuccess(_) | Failed | SenderClosed(_), Next) => Closed,
+        (EarlySuccess(_) | Failed | Replaced(_) | SenderClosed(_), Next) => Closed,
         (LateFailed(_), Next) => Failed,
+        (LateReplaced(_, by), Next) => Replaced(by),
         (Success(_, s2), Next) => SenderClosed(s2),
         // Final states.
         (Closed, _) => Closed,
@@ -56,6 +59,8 @@ pub(crate) fn validate_tx_update_stream_state(
         (Success(s1, s2), _) => Success(s1, s2),
         (Failed, _) => Failed,
         (LateFailed(s), _) => LateFailed(s),
+        (Replaced(by), _) => Replaced(by),
+        (LateReplaced(s, by), _) => LateReplaced(s, by),
         (SenderClosed(s), _) => SenderClosed(s),
     }
 }
@@ -44,11 +44,18 @@ use fuel_core_types::{
         Word,
     },
     fuel_vm::{
-        checked_transaction::EstimatePredicates,
+        checked_transaction::{
+            CheckedTransaction,
+            EstimatePredicates,
+            IntoChecked,
+        },
         interpreter::MemoryInstance,
         predicate::EmptyStorage,
     },
-    services::txpool::ArcPoolTx,
+    services::txpool::{
+        ArcPoolTx,
+        Metadata,
+    },
 };
 use parking_lot::RwLock;
 use tokio::sync::broadcast::Receiver;
@@ -59,10 +66,18 @@ use crate::{
     error::Error,
     new_service,
     pool::Pool,
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    ports::AtomicView,
+    selection_algorithms::ConfiguredSelection,
     service::{
         memory::MemoryPool,
-        verifications::Verification,
+        verification_cache::{
+            VerificationCache,
+            DEFAULT_VERIFICATION_CACHE_SIZE,
+        },
+        verifications::{
+            checked_tx_into_pool,
+            Verification,
+        },
         Shared,
         TxPool,
     },
@@ -129,9 +144,10 @@ impl TestPoolUniverse {
         let pool = Arc::new(RwLock::new(Pool::new(
             GraphStorage::new(GraphConfig {
                 max_txs_chain_count: self.config.max_txs_chain_count,
+                max_dependent_depth: self.config.max_dependent_depth,
             }),
             BasicCollisionManager::new(),
-            RatioTipGasSelection::new(),
+            ConfiguredSelection::new(self.config.selection_algorithm),
             self.config.clone(),
         )));
         self.pool = Some(pool.clone());
@@ -215,6 +231,10 @@ impl TestPoolUniverse {
                 consensus_parameters_provider: Arc::new(mock_consensus_params_provider),
                 wasm_checker: Arc::new(MockWasmChecker::new(Ok(()))),
                 memory_pool: MemoryPool::new(),
+                verification_cache: VerificationCache::new(
+                    DEFAULT_VERIFICATION_CACHE_SIZE,
+                ),
+                pinned_view: Default::default(),
             };
             let tx = verification.perform_all_verifications(
                 tx,
@@ -228,6 +248,26 @@ impl TestPoolUniverse {
         }
     }
 
+    pub fn verification(
+        &self,
+        persistent_storage_provider: Arc<dyn AtomicView<LatestView = MockDb>>,
+    ) -> Verification<MockDb> {
+        let mut mock_consensus_params_provider =
+            MockConsensusParametersProvider::default();
+        mock_consensus_params_provider
+            .expect_latest_consensus_parameters()
+            .returning(|| (0, Arc::new(ConsensusParameters::standard())));
+        Verification {
+            persistent_storage_provider,
+            gas_price_provider: Arc::new(MockTxPoolGasPrice::new(0)),
+            consensus_parameters_provider: Arc::new(mock_consensus_params_provider),
+            wasm_checker: Arc::new(MockWasmChecker::new(Ok(()))),
+            memory_pool: MemoryPool::new(),
+            verification_cache: VerificationCache::new(DEFAULT_VERIFICATION_CACHE_SIZE),
+            pinned_view: Default::default(),
+        }
+    }
+
     pub fn verify_and_insert_with_gas_price(
         &mut self,
         tx: Transaction,
@@ -247,6 +287,10 @@ impl TestPoolUniverse {
                 consensus_parameters_provider: Arc::new(mock_consensus_params_provider),
                 wasm_checker: Arc::new(MockWasmChecker::new(Ok(()))),
                 memory_pool: MemoryPool::new(),
+                verification_cache: VerificationCache::new(
+                    DEFAULT_VERIFICATION_CACHE_SIZE,
+                ),
+                pinned_view: Default::default(),
             };
             let tx = verification.perform_all_verifications(
                 tx,
@@ -280,6 +324,10 @@ impl TestPoolUniverse {
                 consensus_parameters_provider: Arc::new(mock_consensus_params_provider),
                 wasm_checker: Arc::new(wasm_checker),
                 memory_pool: MemoryPool::new(),
+                verification_cache: VerificationCache::new(
+                    DEFAULT_VERIFICATION_CACHE_SIZE,
+                ),
+                pinned_view: Default::default(),
             };
             let tx = verification.perform_all_verifications(
                 tx,
@@ -293,6 +341,29 @@ impl TestPoolUniverse {
         }
     }
 
+    /// Performs only the basic checks of the transaction without touching the pool,
+    /// which allows preparing chained transactions before inserting any of them.
+    pub fn check_basic(&self, tx: Transaction) -> ArcPoolTx {
+        self.check_basic_with_metadata(tx, Metadata::new_test(0, None, None))
+    }
+
+    /// Same as [`Self::check_basic`], but the pool sees the transaction under `tx_id`.
+    pub fn check_basic_with_id(&self, tx: Transaction, tx_id: TxId) -> ArcPoolTx {
+        self.check_basic_with_metadata(tx, Metadata::new_test(0, None, Some(tx_id)))
+    }
+
+    fn check_basic_with_metadata(
+        &self,
+        tx: Transaction,
+        metadata: Metadata,
+    ) -> ArcPoolTx {
+        let checked: CheckedTransaction = tx
+            .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+            .unwrap()
+            .into();
+        Arc::new(checked_tx_into_pool(checked, metadata).unwrap())
+    }
+
     pub fn get_pool(&self) -> Shared<TxPool> {
         self.pool.clone().unwrap()
     }
@@ -74,9 +74,14 @@ pub fn tx_status_message_strategy() -> impl Strategy<Value = TxStatusMessage> {
     prop_oneof![
         transaction_status_strategy().prop_map(TxStatusMessage::Status),
         Just(TxStatusMessage::FailedStatus),
+        tx_id_strategy().prop_map(|by| TxStatusMessage::Replaced { by }),
     ]
 }
 
+fn tx_id_strategy() -> impl Strategy<Value = Bytes32> {
+    (0..10u8).prop_map(|i| Bytes32::from([i; 32]))
+}
+
 pub(super) fn state_strategy() -> impl Strategy<Value = State> {
     prop_oneof![
         Just(State::Empty),
@@ -85,6 +90,9 @@ pub(super) fn state_strategy() -> impl Strategy<Value = State> {
         (transaction_status_strategy(), transaction_status_strategy())
             .prop_map(|(s1, s2)| State::Success(s1, s2)),
         transaction_status_strategy().prop_map(State::LateFailed),
+        tx_id_strategy().prop_map(State::Replaced),
+        (transaction_status_strategy(), tx_id_strategy())
+            .prop_map(|(s, by)| State::LateReplaced(s, by)),
         transaction_status_strategy().prop_map(State::SenderClosed),
         Just(State::Failed),
         Just(State::Closed),
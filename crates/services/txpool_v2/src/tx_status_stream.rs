@@ -29,6 +29,9 @@ impl TxUpdate {
 pub enum TxStatusMessage {
     Status(TransactionStatus),
     FailedStatus,
+    /// The transaction was removed from the pool by a transaction
+    /// that spends the same inputs and pays more.
+    Replaced { by: Bytes32 },
 }
 
 impl<E> From<Result<TransactionStatus, E>> for TxStatusMessage {
@@ -48,6 +51,8 @@ pub(super) enum State {
     Success(TransactionStatus, TransactionStatus),
     Failed,
     LateFailed(TransactionStatus),
+    Replaced(Bytes32),
+    LateReplaced(TransactionStatus, Bytes32),
     SenderClosed(TransactionStatus),
     Closed,
 }
@@ -83,14 +88,13 @@ impl TxUpdateStream {
                 }
                 TxStatusMessage::Status(s) => State::EarlySuccess(s),
                 TxStatusMessage::FailedStatus => State::Failed,
+                TxStatusMessage::Replaced { by } => State::Replaced(by),
+            },
+            State::Initial(s1) => match msg {
+                TxStatusMessage::Status(s2) => State::Success(s1, s2),
+                TxStatusMessage::FailedStatus => State::LateFailed(s1),
+                TxStatusMessage::Replaced { by } => State::LateReplaced(s1, by),
             },
-            State::Initial(s1) => {
-                if let TxStatusMessage::Status(s2) = msg {
-                    State::Success(s1, s2)
-                } else {
-                    State::LateFailed(s1)
-                }
-            }
             s => s,
         };
     }
@@ -125,6 +129,14 @@ impl TxUpdateStream {
                 self.state = State::Failed;
                 Some(TxStatusMessage::Status(s))
             }
+            State::Replaced(by) => {
+                self.state = State::Closed;
+                Some(TxStatusMessage::Replaced { by })
+            }
+            State::LateReplaced(s, by) => {
+                self.state = State::Replaced(by);
+                Some(TxStatusMessage::Status(s))
+            }
             State::Success(s1, s2) => {
                 self.state = State::SenderClosed(s2);
                 Some(TxStatusMessage::Status(s1))
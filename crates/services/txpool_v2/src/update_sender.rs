@@ -78,6 +78,12 @@ impl TxStatusChange {
         ));
     }
 
+    pub fn send_replaced(&self, id: Bytes32, by: Bytes32) {
+        tracing::info!("Transaction {id} replaced by {by}");
+        self.update_sender
+            .send(TxUpdate::new(id, TxStatusMessage::Replaced { by }));
+    }
+
     pub fn send_squeezed_out(&self, id: Bytes32, reason: Error) {
         tracing::info!("Transaction {id} squeezed out because {reason}");
         self.update_sender.send(TxUpdate::new(
@@ -289,6 +289,13 @@ impl MessageStatus {
         }
     }
 
+    /// Constructor for `MessageStatus` that fills with `UnspentUnfinalized` state
+    pub fn unspent_unfinalized() -> Self {
+        Self {
+            state: MessageState::UnspentUnfinalized,
+        }
+    }
+
     /// Constructor for `MessageStatus` that fills with `Spent` state
     pub fn spent() -> Self {
         Self {
@@ -302,14 +309,25 @@ impl MessageStatus {
             state: MessageState::NotFound,
         }
     }
+
+    /// Constructor for `MessageStatus` that fills with `RelayPending` state
+    pub fn relay_pending() -> Self {
+        Self {
+            state: MessageState::RelayPending,
+        }
+    }
 }
 
 /// The possible states a Message can be in
 pub enum MessageState {
     /// Message is still unspent
     Unspent,
+    /// Message is unspent, but the DA block that emitted it is not finalized yet
+    UnspentUnfinalized,
     /// Message has already been spent
     Spent,
     /// There is no record of this Message
     NotFound,
+    /// Message was observed on the DA layer, but is not relayed to the chain yet
+    RelayPending,
 }
@@ -2,6 +2,7 @@ use crate::helpers::TestContext;
 use fuel_core::{
     chain_config::{
         CoinConfig,
+        CoinConfigGenerator,
         MessageConfig,
         StateConfig,
     },
@@ -12,6 +13,10 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
     types::CoinType,
     FuelClient,
 };
@@ -751,6 +756,63 @@ async fn coins_to_spend_error_duplicate_asset_query(
     );
 }
 
+#[tokio::test]
+async fn coins_filtered_by_asset_returns_only_coins_of_the_asset() {
+    let owner = Address::from([5; 32]);
+    let asset_id_a = AssetId::new([1; 32]);
+    let asset_id_b = AssetId::new([2; 32]);
+
+    // Given
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state = StateConfig {
+        coins: vec![(50, asset_id_a), (100, asset_id_b), (150, asset_id_a)]
+            .into_iter()
+            .map(|(amount, asset_id)| CoinConfig {
+                owner,
+                amount,
+                asset_id,
+                ..coin_generator.generate()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+
+    // When
+    let coins_a = client
+        .coins(&owner, Some(&asset_id_a), request.clone())
+        .await
+        .unwrap()
+        .results;
+    let coins_b = client
+        .coins(&owner, Some(&asset_id_b), request)
+        .await
+        .unwrap()
+        .results;
+
+    // Then
+    let mut amounts_a: Vec<_> = coins_a
+        .iter()
+        .inspect(|coin| assert_eq!(coin.asset_id, asset_id_a))
+        .map(|coin| coin.amount)
+        .collect();
+    amounts_a.sort();
+    assert_eq!(amounts_a, vec![50, 150]);
+    let amounts_b: Vec<_> = coins_b
+        .iter()
+        .inspect(|coin| assert_eq!(coin.asset_id, asset_id_b))
+        .map(|coin| coin.amount)
+        .collect();
+    assert_eq!(amounts_b, vec![100]);
+}
+
 trait ToStdErrorString {
     fn to_str_error_string(self) -> String;
 }
@@ -43,6 +43,7 @@ use fuel_core_types::{
         Finalizable,
         Transaction,
         TransactionBuilder,
+        TransactionFee,
     },
     services::executor::TransactionExecutionResult,
 };
@@ -424,3 +425,55 @@ async fn startup__can_override_gas_price_values_by_changing_config() {
     assert_eq!(min_exec_gas_price, 33);
     assert_eq!(l2_block_height, new_height);
 }
+
+#[tokio::test]
+async fn estimated_fee__is_fee_of_transaction_at_next_block_gas_price() {
+    // given
+    let gas_price = 1000;
+    let mut node_config = Config::local_node();
+    node_config.starting_gas_price = gas_price;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let consensus_params = client.chain_info().await.unwrap().consensus_parameters;
+
+    let script = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .add_fee_input()
+    .script_gas_limit(1000)
+    .max_fee_limit(u32::MAX as u64)
+    .finalize();
+    let expected_fee = TransactionFee::checked_from_tx(
+        consensus_params.gas_costs(),
+        consensus_params.fee_params(),
+        &script,
+        gas_price,
+    )
+    .unwrap();
+    let tx: Transaction = script.into();
+
+    // when
+    let estimated_fee = client.estimated_fee(&tx).await.unwrap();
+
+    // then
+    let within_one_percent =
+        |actual: u64, expected: u64| actual.abs_diff(expected) <= expected / 100;
+    assert!(within_one_percent(
+        estimated_fee.min_fee,
+        expected_fee.min_fee()
+    ));
+    assert!(within_one_percent(
+        estimated_fee.max_fee,
+        expected_fee.max_fee()
+    ));
+    let dry_run_gas = *client
+        .dry_run_opt(&[tx], None, Some(gas_price))
+        .await
+        .unwrap()
+        .pop()
+        .unwrap()
+        .result
+        .total_gas();
+    assert_eq!(estimated_fee.gas_used, dry_run_gas);
+}
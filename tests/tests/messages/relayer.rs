@@ -1,5 +1,14 @@
 use super::*;
-use fuel_core_types::blockchain::primitives::DaBlockHeight;
+use fuel_core_relayer::storage::EventsHistory;
+use fuel_core_storage::StorageAsMut;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    entities::relayer::message::{
+        Message,
+        MessageV1,
+    },
+    services::relayer::Event,
+};
 use rand::{
     rngs::StdRng,
     Rng,
@@ -51,3 +60,32 @@ async fn can_submit_genesis_message() {
         "expected success, received {status:?}",
     )
 }
+
+#[tokio::test]
+async fn message_status__can_get_relay_pending() {
+    // Given
+    let nonce = 1.into();
+    let message: Message = MessageV1 {
+        nonce,
+        amount: 1_000,
+        da_height: DaBlockHeight(1),
+        ..Default::default()
+    }
+    .into();
+
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The relayer observed the message, but no block included it yet.
+    let mut relayer_database = srv.shared.database.relayer().clone();
+    relayer_database
+        .storage_as_mut::<EventsHistory>()
+        .insert(&DaBlockHeight(1), &[Event::Message(message)])
+        .unwrap();
+
+    // When
+    let status = client.message_status(&nonce).await.unwrap();
+
+    // Then
+    assert_eq!(status, MessageStatus::RelayPending);
+}